@@ -1,9 +1,13 @@
+pub mod random;
 pub mod semantics;
+pub mod sound_change;
 
+use crate::semantics::registry::Registry;
 use std::{fmt, hash::Hash, str};
 use thiserror::Error;
 use wfts_pedia_ssg::{
     component::{Component, Context, InlineComponent},
+    i18n::UiLang,
     location::{Fragment, Id, InternalPath},
     site::Directory,
 };
@@ -107,7 +111,11 @@ impl Component for LangCode {
 pub trait Lang: Sized {
     fn code(&self) -> LangCode;
 
-    fn subsite(&self) -> Directory;
+    /// Registers the meanings this language's words are defined with, and
+    /// any relations between them besides the standard ones.
+    fn register_meanings(&self, registry: &mut Registry) -> anyhow::Result<()>;
+
+    fn subsite(&self, ui_lang: UiLang) -> Directory;
 
     fn path(&self) -> InternalPath {
         InternalPath::parse(format!("langs/{}", self.code())).unwrap()
@@ -0,0 +1,82 @@
+/// Small deterministic pseudo-random generator (SplitMix64). The same seed
+/// always yields the same sequence, on every platform, so coined words and
+/// generated test data are reproducible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut bits = self.state;
+        bits = (bits ^ (bits >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        bits = (bits ^ (bits >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        bits ^ (bits >> 31)
+    }
+
+    /// Uniform number in `0 .. bound`. Panics if `bound` is zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+
+    /// Returns true with probability `percent`/100.
+    pub fn percent(&mut self, percent: u32) -> bool {
+        self.below(100) < u64::from(percent)
+    }
+
+    /// Picks one of the choices with probability proportional to its weight.
+    /// Returns `None` if there is no choice with a positive weight.
+    pub fn weighted<'choices, T>(
+        &mut self,
+        choices: &'choices [(T, u32)],
+    ) -> Option<&'choices T> {
+        let total = choices.iter().map(|&(_, weight)| u64::from(weight)).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut target = self.below(total);
+        for (choice, weight) in choices {
+            let weight = u64::from(*weight);
+            if target < weight {
+                return Some(choice);
+            }
+            target -= weight;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut first = Rng::new(42);
+        let mut second = Rng::new(42);
+        for _ in 0 .. 100 {
+            assert_eq!(first.next_u64(), second.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn weighted_skips_zero_weights() {
+        let mut rng = Rng::new(7);
+        let choices = [('a', 0), ('b', 3), ('c', 0), ('d', 1)];
+        for _ in 0 .. 1000 {
+            let &choice = rng.weighted(&choices).unwrap();
+            assert!(choice == 'b' || choice == 'd');
+        }
+        assert_eq!(rng.weighted(&[('a', 0)]), None);
+        assert_eq!(rng.weighted::<char>(&[]), None);
+    }
+}
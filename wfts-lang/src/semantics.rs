@@ -1,3 +1,11 @@
+pub mod network;
+pub mod shared;
+pub mod registry;
+
+use self::network::SemanticNetwork;
+use std::{cmp::Ordering, fmt, hash};
+use wfts_pedia_ssg::{i18n::UiLang, location::Id};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum SynLevel {
     L1,
@@ -27,131 +35,198 @@ impl Relation {
     }
 }
 
+impl fmt::Display for Relation {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Relation::Unrelated => fmt.pad("unrelated"),
+            Relation::Synonym(SynLevel::L5) => fmt.pad("synonym"),
+            Relation::Synonym(level) => {
+                let level = match level {
+                    SynLevel::L1 => 1,
+                    SynLevel::L2 => 2,
+                    SynLevel::L3 => 3,
+                    SynLevel::L4 => 4,
+                    SynLevel::L5 => 5,
+                };
+                write!(fmt, "synonym (level {})", level)
+            },
+            Relation::Antonym => fmt.pad("antonym"),
+            Relation::Hiponym => fmt.pad("hyponym"),
+            Relation::Hyperonym => fmt.pad("hyperonym"),
+            Relation::Coordinate => fmt.pad("coordinate"),
+        }
+    }
+}
+
+/// Broad area of experience a meaning belongs to.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Meaning {
-    Star,
-    NightStar,
-    Sun,
-    Eye,
-    Wind,
-    Fire,
-    Tree,
-    Big,
-    ThisNear,
-    ThatFar,
-    ThatVeryFar,
-    InformalPersonal,
-    FormalPersonal,
-    What,
-    ThatRelative,
-    To,
-    Of,
-    And,
-    CompoundConj,
-    ToWalk,
+pub enum Domain {
+    Astronomy,
+    Body,
+    Nature,
+    Size,
+    Deixis,
+    Person,
+    Reference,
+    Function,
+    Motion,
+}
+
+impl fmt::Display for Domain {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Domain::Astronomy => "astronomy",
+            Domain::Body => "body",
+            Domain::Nature => "nature",
+            Domain::Size => "size",
+            Domain::Deixis => "deixis",
+            Domain::Person => "person",
+            Domain::Reference => "reference",
+            Domain::Function => "function",
+            Domain::Motion => "motion",
+        })
+    }
+}
+
+/// Kind of word a meaning is usually expressed with, independently of the
+/// parts of speech of any particular language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MeaningClass {
+    Noun,
+    Adjective,
+    Pronoun,
+    Adposition,
+    Conjunction,
+    Verb,
+}
+
+impl fmt::Display for MeaningClass {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            MeaningClass::Noun => "noun",
+            MeaningClass::Adjective => "adjective",
+            MeaningClass::Pronoun => "pronoun",
+            MeaningClass::Adposition => "adposition",
+            MeaningClass::Conjunction => "conjunction",
+            MeaningClass::Verb => "verb",
+        })
+    }
+}
+
+/// Definition of a meaning. The `id` must be stable, since it ends up in
+/// URLs and exported files, and unique across all languages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeaningDef {
+    pub id: &'static str,
+    pub domain: Domain,
+    pub class: MeaningClass,
+    pub identifiers: &'static [(UiLang, &'static str)],
+    pub descriptions: &'static [(UiLang, &'static str)],
+}
+
+/// A handle to a meaning definition. Meanings shared by every language are
+/// associated constants of this type; languages may define their own and
+/// register them in a [`registry::Registry`]. Two meanings are equal when
+/// their ids are.
+#[derive(Clone, Copy)]
+pub struct Meaning {
+    def: &'static MeaningDef,
 }
 
 impl Meaning {
+    pub const fn new(def: &'static MeaningDef) -> Self {
+        Self { def }
+    }
+
+    pub fn def(self) -> &'static MeaningDef {
+        self.def
+    }
+
+    pub fn id(self) -> &'static str {
+        self.def.id
+    }
+
+    pub fn domain(self) -> Domain {
+        self.def.domain
+    }
+
+    pub fn class(self) -> MeaningClass {
+        self.def.class
+    }
+
+    /// Relation to another meaning in the network of shared meanings. Use
+    /// [`registry::Registry::network`] to take into account the relations
+    /// registered by languages.
     pub fn relation(self, other: Self) -> Relation {
-        use Relation::*;
-        use SynLevel::*;
-
-        if self == other {
-            Synonym(L5)
-        } else {
-            self.relation_raw(other)
-                .or_else(|| other.relation_raw(self).map(Relation::flip))
-                .unwrap_or(Unrelated)
-        }
+        SemanticNetwork::standard().relation(self, other)
+    }
+
+    pub fn to_id(self) -> Id {
+        Id::new(format!("meaning-{}", self.id())).unwrap()
     }
 
-    fn relation_raw(self, other: Self) -> Option<Relation> {
-        use Meaning::*;
-        use Relation::*;
+    /// Identifier in the given UI language, following its fallback chain.
+    pub fn identifier_in(self, ui_lang: UiLang) -> &'static str {
+        localize(self.def.identifiers, ui_lang).unwrap_or(self.def.id)
+    }
 
-        match (self, other) {
-            (Star, NightStar) => Some(Hyperonym),
-            (Star, Sun) => Some(Hyperonym),
-            (NightStar, Sun) => Some(Coordinate),
-            (ThisNear, ThatFar) => Some(Coordinate),
-            (ThisNear, ThatVeryFar) => Some(Coordinate),
-            (ThatFar, ThatVeryFar) => Some(Coordinate),
-            _ => None,
-        }
+    /// Description in the given UI language, following its fallback chain.
+    pub fn description_in(self, ui_lang: UiLang) -> &'static str {
+        localize(self.def.descriptions, ui_lang).unwrap_or("")
     }
 
     pub fn identifier(self) -> &'static str {
-        use Meaning::*;
-
-        match self {
-            Star => "star",
-            Sun => "sun",
-            NightStar => "night star",
-            Eye => "eye",
-            Wind => "wind",
-            Fire => "fire",
-            Tree => "tree",
-            Big => "big",
-            ThisNear => "near this",
-            ThatFar => "far this",
-            ThatVeryFar => "emphatic far this",
-            InformalPersonal => "informal personal pronoun",
-            FormalPersonal => "formal personal pronoun",
-            ThatRelative => "relative that",
-            What => "what",
-            To => "to",
-            Of => "of",
-            And => "and",
-            CompoundConj => "equals",
-            ToWalk => "to walk",
-        }
+        self.identifier_in(UiLang::English)
     }
 
     pub fn description(self) -> &'static str {
-        use Meaning::*;
+        self.description_in(UiLang::English)
+    }
+}
 
-        match self {
-            Star => "A star.",
-            Sun => "One of the suns; a star seen during the day.",
-            NightStar => "A star seen at night.",
-            Eye => "An eye.",
-            Wind => "The wind; the air that blows naturally.",
-            Fire => "Fire; the light and heat emitted by burning something.",
-            Tree => "A tree.",
-            Big => "Big; the quality of having a big size, but not huge.",
-            ThisNear => "Demonstrative pronoun used for near things; this.",
-            ThatFar => {
-                "Demonstrative pronoun used far but not so far things; that."
-            },
-            ThatVeryFar => {
-                "Demonstrative pronoun used for very far things, in an \
-                 emphatic manner; that."
-            },
-            InformalPersonal => {
-                "Informal personal pronoun, informal I, you, he, she, they, \
-                 it, we, etc."
-            },
-            FormalPersonal => {
-                "Formal personal pronoun, informal I, you, he, she, they, it, \
-                 we, etc."
-            },
-            ThatRelative => {
-                "Relative pronoun used to refer to things in general; that."
-            },
-            What => {
-                "Question pronoun used to refer to things in general; what."
-            },
-            To => "Particle used to indicate destination; to",
-            Of => {
-                "Particle used to express a relationship between two things, \
-                 usually possession or pertinence; of."
-            },
-            And => {
-                "Conjunction used to express that all the clauses are true; \
-                 and."
-            },
-            CompoundConj => "Conjunction used to compound phrases.",
-            ToWalk => "To walk.",
-        }
+fn localize(
+    strings: &'static [(UiLang, &'static str)],
+    ui_lang: UiLang,
+) -> Option<&'static str> {
+    ui_lang.select(|ui_lang| {
+        strings
+            .iter()
+            .find(|&&(lang, _)| lang == ui_lang)
+            .map(|&(_, string)| string)
+    })
+}
+
+impl fmt::Debug for Meaning {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Meaning({:?})", self.id())
+    }
+}
+
+impl PartialEq for Meaning {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Meaning {}
+
+impl PartialOrd for Meaning {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Meaning {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id().cmp(other.id())
+    }
+}
+
+impl hash::Hash for Meaning {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: hash::Hasher,
+    {
+        self.id().hash(state)
     }
 }
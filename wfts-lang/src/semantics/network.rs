@@ -0,0 +1,367 @@
+use super::{Meaning, Relation, SynLevel};
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fmt::Write,
+    sync::OnceLock,
+};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error)]
+#[error(
+    "Relation {declared} between {first:?} and {second:?} contradicts \
+     {existing}"
+)]
+pub struct InconsistentRelation {
+    pub first: Meaning,
+    pub second: Meaning,
+    pub declared: Relation,
+    pub existing: Relation,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Meaning {meaning:?} cannot be related to itself")]
+pub struct SelfRelation {
+    pub meaning: Meaning,
+}
+
+/// A graph of meanings whose edges are relations. Every relation is declared
+/// once and stored in both directions, the reverse edge being the flipped
+/// relation, so the graph is always symmetric.
+#[derive(Debug, Clone, Default)]
+pub struct SemanticNetwork {
+    edges: BTreeMap<Meaning, BTreeMap<Meaning, Relation>>,
+}
+
+impl SemanticNetwork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The network of meanings shared by every language.
+    pub fn standard() -> &'static Self {
+        static NETWORK: OnceLock<SemanticNetwork> = OnceLock::new();
+
+        NETWORK.get_or_init(|| {
+            let mut network = Self::new();
+            network.declare_standard().unwrap();
+            network
+        })
+    }
+
+    fn declare_standard(&mut self) -> anyhow::Result<()> {
+        use Relation::*;
+
+        self.declare(Meaning::STAR, Meaning::NIGHT_STAR, Hyperonym)?;
+        self.declare(Meaning::STAR, Meaning::SUN, Hyperonym)?;
+        self.declare(Meaning::THIS_NEAR, Meaning::THAT_FAR, Coordinate)?;
+        self.declare(Meaning::THIS_NEAR, Meaning::THAT_VERY_FAR, Coordinate)?;
+        self.declare(Meaning::THAT_FAR, Meaning::THAT_VERY_FAR, Coordinate)?;
+        Ok(())
+    }
+
+    /// Declares that `first` has the given relation to `second`. The reverse
+    /// edge is added automatically. Declaring a relation that contradicts an
+    /// existing one is an error; redeclaring the same relation is not.
+    pub fn declare(
+        &mut self,
+        first: Meaning,
+        second: Meaning,
+        relation: Relation,
+    ) -> anyhow::Result<()> {
+        if first == second {
+            Err(SelfRelation { meaning: first })?;
+        }
+
+        if relation == Relation::Unrelated {
+            return Ok(());
+        }
+
+        match self.direct_relation(first, second) {
+            Relation::Unrelated => (),
+            existing if existing == relation => return Ok(()),
+            existing => Err(InconsistentRelation {
+                first,
+                second,
+                declared: relation,
+                existing,
+            })?,
+        }
+
+        let hyponym_cycle = match relation {
+            Relation::Hyperonym => self.hyperonyms(first).contains(&second),
+            Relation::Hiponym => self.hyponyms(first).contains(&second),
+            _ => false,
+        };
+        if hyponym_cycle {
+            Err(InconsistentRelation {
+                first,
+                second,
+                declared: relation,
+                existing: relation.flip(),
+            })?;
+        }
+
+        match relation {
+            Relation::Coordinate => {
+                let existing = self.relation(first, second);
+                let hyponymy =
+                    matches!(existing, Relation::Hyperonym | Relation::Hiponym);
+                if hyponymy {
+                    Err(InconsistentRelation {
+                        first,
+                        second,
+                        declared: relation,
+                        existing,
+                    })?;
+                }
+            },
+            Relation::Hyperonym => self.check_coordinates(first, second)?,
+            Relation::Hiponym => self.check_coordinates(second, first)?,
+            _ => (),
+        }
+
+        self.edges.entry(first).or_default().insert(second, relation);
+        self.edges.entry(second).or_default().insert(first, relation.flip());
+        Ok(())
+    }
+
+    /// Fails if making `hyperonym` a hyperonym of `hyponym` would relate by
+    /// hyponymy two meanings declared to be coordinates.
+    fn check_coordinates(
+        &self,
+        hyperonym: Meaning,
+        hyponym: Meaning,
+    ) -> anyhow::Result<()> {
+        let mut above = self.hyperonyms(hyperonym);
+        above.insert(hyperonym);
+        let mut below = self.hyponyms(hyponym);
+        below.insert(hyponym);
+
+        for &upper in &above {
+            for lower in self.neighbours(upper, Relation::Coordinate) {
+                if below.contains(&lower) {
+                    Err(InconsistentRelation {
+                        first: upper,
+                        second: lower,
+                        declared: Relation::Hyperonym,
+                        existing: Relation::Coordinate,
+                    })?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The relation declared between the two meanings, without inference.
+    pub fn direct_relation(&self, first: Meaning, second: Meaning) -> Relation {
+        self.edges
+            .get(&first)
+            .and_then(|edges| edges.get(&second))
+            .copied()
+            .unwrap_or(Relation::Unrelated)
+    }
+
+    /// The relation between the two meanings, taking into account the
+    /// transitivity of hyponymy and coordinates inferred from shared
+    /// hyperonyms.
+    pub fn relation(&self, first: Meaning, second: Meaning) -> Relation {
+        if first == second {
+            return Relation::Synonym(SynLevel::L5);
+        }
+
+        match self.direct_relation(first, second) {
+            Relation::Unrelated => (),
+            relation => return relation,
+        }
+
+        if self.hyponyms(first).contains(&second) {
+            Relation::Hyperonym
+        } else if self.hyperonyms(first).contains(&second) {
+            Relation::Hiponym
+        } else if self.coordinates(first).contains(&second) {
+            Relation::Coordinate
+        } else {
+            Relation::Unrelated
+        }
+    }
+
+    fn neighbours(
+        &self,
+        meaning: Meaning,
+        relation: Relation,
+    ) -> impl Iterator<Item = Meaning> + '_ {
+        self.edges
+            .get(&meaning)
+            .into_iter()
+            .flat_map(|edges| edges.iter())
+            .filter(move |(_, &edge)| edge == relation)
+            .map(|(&other, _)| other)
+    }
+
+    fn closure(
+        &self,
+        meaning: Meaning,
+        relation: Relation,
+    ) -> BTreeSet<Meaning> {
+        let mut visited = BTreeSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(meaning);
+
+        while let Some(current) = queue.pop_front() {
+            for next in self.neighbours(current, relation) {
+                if next != meaning && visited.insert(next) {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        visited
+    }
+
+    /// All hyponyms of the meaning, at any depth.
+    pub fn hyponyms(&self, meaning: Meaning) -> BTreeSet<Meaning> {
+        self.closure(meaning, Relation::Hyperonym)
+    }
+
+    /// All hyperonyms of the meaning, at any depth.
+    pub fn hyperonyms(&self, meaning: Meaning) -> BTreeSet<Meaning> {
+        self.closure(meaning, Relation::Hiponym)
+    }
+
+    /// Declared coordinates plus meanings sharing a direct hyperonym with
+    /// the given meaning.
+    pub fn coordinates(&self, meaning: Meaning) -> BTreeSet<Meaning> {
+        let mut coordinates = self
+            .neighbours(meaning, Relation::Coordinate)
+            .collect::<BTreeSet<_>>();
+
+        for hyperonym in self.neighbours(meaning, Relation::Hiponym) {
+            for sibling in self.neighbours(hyperonym, Relation::Hyperonym) {
+                if sibling != meaning {
+                    coordinates.insert(sibling);
+                }
+            }
+        }
+
+        coordinates
+    }
+
+    /// Meanings declared to be hyponyms of the given one, without inference.
+    pub fn direct_hyponyms(
+        &self,
+        meaning: Meaning,
+    ) -> impl Iterator<Item = Meaning> + '_ {
+        self.neighbours(meaning, Relation::Hyperonym)
+    }
+
+    /// Every declared relation, once: hyponymy from the hyperonym, and
+    /// symmetric relations from the lesser meaning.
+    pub fn declared(
+        &self,
+    ) -> impl Iterator<Item = (Meaning, Meaning, Relation)> + '_ {
+        self.edges.iter().flat_map(|(&first, edges)| {
+            edges.iter().filter_map(move |(&second, &relation)| {
+                match relation {
+                    Relation::Hiponym | Relation::Unrelated => None,
+                    Relation::Hyperonym => Some((first, second, relation)),
+                    _ if second < first => None,
+                    _ => Some((first, second, relation)),
+                }
+            })
+        })
+    }
+
+    /// Renders the declared relations as a Graphviz DOT graph. Hyponymy is
+    /// drawn as an arrow from the hyperonym to the hyponym; symmetric
+    /// relations are drawn once, without arrows.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph semantics {\n");
+        let meanings =
+            self.edges.keys().copied().collect::<BTreeSet<Meaning>>();
+
+        for meaning in &meanings {
+            writeln!(
+                dot,
+                "    \"{}\";",
+                meaning.identifier().replace('"', "\\\"")
+            )
+            .unwrap();
+        }
+
+        for (first, second, relation) in self.declared() {
+            let attrs = match relation {
+                Relation::Hyperonym => String::new(),
+                relation => format!(" [dir=none, label=\"{}\"]", relation),
+            };
+            writeln!(
+                dot,
+                "    \"{}\" -> \"{}\"{};",
+                first.identifier().replace('"', "\\\""),
+                second.identifier().replace('"', "\\\""),
+                attrs,
+            )
+            .unwrap();
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn relations_are_symmetric() {
+        let network = SemanticNetwork::standard();
+        for &first in Meaning::SHARED {
+            for &second in Meaning::SHARED {
+                assert_eq!(
+                    network.relation(first, second),
+                    network.relation(second, first).flip(),
+                    "{:?} and {:?}",
+                    first,
+                    second,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn transitive_hyponyms() {
+        const STAR: Meaning = Meaning::STAR;
+        const SUN: Meaning = Meaning::SUN;
+        const FIRE: Meaning = Meaning::FIRE;
+        const NIGHT_STAR: Meaning = Meaning::NIGHT_STAR;
+
+        let mut network = SemanticNetwork::new();
+        network.declare(STAR, SUN, Relation::Hyperonym).unwrap();
+        network.declare(SUN, FIRE, Relation::Hyperonym).unwrap();
+        network.declare(STAR, NIGHT_STAR, Relation::Hyperonym).unwrap();
+
+        let expected = [SUN, FIRE, NIGHT_STAR].iter().copied().collect();
+        assert_eq!(network.hyponyms(STAR), expected);
+        assert_eq!(network.relation(FIRE, STAR), Relation::Hiponym);
+        assert_eq!(network.relation(SUN, NIGHT_STAR), Relation::Coordinate);
+        assert!(network.declare(FIRE, STAR, Relation::Hyperonym).is_err());
+        assert!(network.declare(SUN, STAR, Relation::Coordinate).is_err());
+        assert!(network.declare(FIRE, STAR, Relation::Coordinate).is_err());
+        assert!(network.declare(STAR, FIRE, Relation::Coordinate).is_err());
+    }
+
+    #[test]
+    fn coordinates_cannot_become_hyponyms() {
+        const STAR: Meaning = Meaning::STAR;
+        const SUN: Meaning = Meaning::SUN;
+        const FIRE: Meaning = Meaning::FIRE;
+
+        let mut network = SemanticNetwork::new();
+        network.declare(STAR, FIRE, Relation::Coordinate).unwrap();
+        network.declare(STAR, SUN, Relation::Hyperonym).unwrap();
+        assert!(network.declare(SUN, FIRE, Relation::Hyperonym).is_err());
+        assert!(network.declare(FIRE, SUN, Relation::Hiponym).is_err());
+        assert_eq!(network.relation(SUN, FIRE), Relation::Unrelated);
+    }
+}
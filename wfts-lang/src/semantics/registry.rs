@@ -0,0 +1,159 @@
+use super::{network::SemanticNetwork, Domain, Meaning, Relation};
+use std::collections::{btree_map, BTreeMap};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error)]
+#[error("Meaning id {id:?} is already registered with another definition")]
+pub struct DuplicateMeaning {
+    pub id: &'static str,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Unknown meaning id {id:?}")]
+pub struct UnknownMeaning {
+    pub id: String,
+}
+
+/// Set of meanings known to the encyclopedia, indexed by id, together with
+/// the relations between them.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    meanings: BTreeMap<&'static str, Meaning>,
+    network: SemanticNetwork,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A registry with the meanings shared by every language and the
+    /// standard relations between them.
+    pub fn with_shared() -> Self {
+        let mut this = Self::new();
+        for &meaning in Meaning::SHARED {
+            this.register(meaning).unwrap();
+        }
+        this.network = SemanticNetwork::standard().clone();
+        this
+    }
+
+    /// Registers a meaning. Registering the same definition twice is
+    /// allowed, but reusing an id for a different definition is an error.
+    pub fn register(&mut self, meaning: Meaning) -> anyhow::Result<()> {
+        match self.meanings.entry(meaning.id()) {
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(meaning);
+            },
+            btree_map::Entry::Occupied(entry) => {
+                if entry.get().def() != meaning.def() {
+                    Err(DuplicateMeaning { id: meaning.id() })?;
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Declares a relation between two registered meanings, as in
+    /// [`SemanticNetwork::declare`].
+    pub fn declare(
+        &mut self,
+        first: Meaning,
+        second: Meaning,
+        relation: Relation,
+    ) -> anyhow::Result<()> {
+        for meaning in [first, second] {
+            if self.meanings.get(meaning.id()) != Some(&meaning) {
+                Err(UnknownMeaning { id: meaning.id().to_owned() })?;
+            }
+        }
+        self.network.declare(first, second, relation)
+    }
+
+    /// The network of the standard relations and of every relation declared
+    /// in this registry.
+    pub fn network(&self) -> &SemanticNetwork {
+        &self.network
+    }
+
+    pub fn get(&self, id: &str) -> anyhow::Result<Meaning> {
+        let meaning = self
+            .meanings
+            .get(id)
+            .copied()
+            .ok_or_else(|| UnknownMeaning { id: id.to_owned() })?;
+        Ok(meaning)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Meaning> + '_ {
+        self.meanings.values().copied()
+    }
+
+    pub fn in_domain(
+        &self,
+        domain: Domain,
+    ) -> impl Iterator<Item = Meaning> + '_ {
+        self.iter().filter(move |meaning| meaning.domain() == domain)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::semantics::{MeaningDef, MeaningClass};
+    use wfts_pedia_ssg::i18n::UiLang;
+
+    #[test]
+    fn shared_meanings_have_unique_ids() {
+        let registry = Registry::with_shared();
+        assert_eq!(registry.iter().count(), Meaning::SHARED.len());
+        assert_eq!(registry.get("star").unwrap(), Meaning::STAR);
+    }
+
+    #[test]
+    fn conflicting_id_is_rejected() {
+        const FAKE_STAR: Meaning = Meaning::new(&MeaningDef {
+            id: "star",
+            domain: Domain::Nature,
+            class: MeaningClass::Noun,
+            identifiers: &[(UiLang::English, "star")],
+            descriptions: &[],
+        });
+
+        let mut registry = Registry::with_shared();
+        assert!(registry.register(Meaning::STAR).is_ok());
+        assert!(registry.register(FAKE_STAR).is_err());
+    }
+
+    #[test]
+    fn registered_meanings_join_the_network() {
+        const GIANT_STAR: Meaning = Meaning::new(&MeaningDef {
+            id: "test-giant-star",
+            domain: Domain::Astronomy,
+            class: MeaningClass::Noun,
+            identifiers: &[(UiLang::English, "giant star")],
+            descriptions: &[],
+        });
+
+        let mut registry = Registry::with_shared();
+        assert!(registry
+            .declare(Meaning::NIGHT_STAR, GIANT_STAR, Relation::Hyperonym)
+            .is_err());
+        registry.register(GIANT_STAR).unwrap();
+        registry
+            .declare(Meaning::NIGHT_STAR, GIANT_STAR, Relation::Hyperonym)
+            .unwrap();
+
+        let network = registry.network();
+        assert_eq!(
+            network.relation(GIANT_STAR, Meaning::STAR),
+            Relation::Hiponym
+        );
+        assert!(network.to_dot().contains("\"giant star\""));
+        let standard = SemanticNetwork::standard();
+        assert_eq!(
+            standard.relation(GIANT_STAR, Meaning::STAR),
+            Relation::Unrelated
+        );
+    }
+}
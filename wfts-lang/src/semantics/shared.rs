@@ -0,0 +1,399 @@
+use super::{Domain, Meaning, MeaningClass, MeaningDef};
+use wfts_pedia_ssg::i18n::UiLang;
+
+/// Meanings shared by every language of the encyclopedia.
+impl Meaning {
+    pub const STAR: Self = Self::new(&MeaningDef {
+        id: "star",
+        domain: Domain::Astronomy,
+        class: MeaningClass::Noun,
+        identifiers: &[
+            (UiLang::English, "star"),
+            (UiLang::BrazilianPortuguese, "estrela"),
+        ],
+        descriptions: &[
+            (UiLang::English, "A star."),
+            (UiLang::BrazilianPortuguese, "Uma estrela."),
+        ],
+    });
+
+    pub const NIGHT_STAR: Self = Self::new(&MeaningDef {
+        id: "night-star",
+        domain: Domain::Astronomy,
+        class: MeaningClass::Noun,
+        identifiers: &[
+            (UiLang::English, "night star"),
+            (UiLang::BrazilianPortuguese, "estrela noturna"),
+        ],
+        descriptions: &[
+            (UiLang::English, "A star seen at night."),
+            (UiLang::BrazilianPortuguese, "Uma estrela vista à noite."),
+        ],
+    });
+
+    pub const SUN: Self = Self::new(&MeaningDef {
+        id: "sun",
+        domain: Domain::Astronomy,
+        class: MeaningClass::Noun,
+        identifiers: &[
+            (UiLang::English, "sun"),
+            (UiLang::BrazilianPortuguese, "sol"),
+        ],
+        descriptions: &[
+            (UiLang::English, "One of the suns; a star seen during the day."),
+            (
+                UiLang::BrazilianPortuguese,
+                "Um dos sóis; uma estrela vista durante o dia.",
+            ),
+        ],
+    });
+
+    pub const EYE: Self = Self::new(&MeaningDef {
+        id: "eye",
+        domain: Domain::Body,
+        class: MeaningClass::Noun,
+        identifiers: &[
+            (UiLang::English, "eye"),
+            (UiLang::BrazilianPortuguese, "olho"),
+        ],
+        descriptions: &[
+            (UiLang::English, "An eye."),
+            (UiLang::BrazilianPortuguese, "Um olho."),
+        ],
+    });
+
+    pub const WIND: Self = Self::new(&MeaningDef {
+        id: "wind",
+        domain: Domain::Nature,
+        class: MeaningClass::Noun,
+        identifiers: &[
+            (UiLang::English, "wind"),
+            (UiLang::BrazilianPortuguese, "vento"),
+        ],
+        descriptions: &[
+            (UiLang::English, "The wind; the air that blows naturally."),
+            (
+                UiLang::BrazilianPortuguese,
+                "O vento; o ar que sopra naturalmente.",
+            ),
+        ],
+    });
+
+    pub const FIRE: Self = Self::new(&MeaningDef {
+        id: "fire",
+        domain: Domain::Nature,
+        class: MeaningClass::Noun,
+        identifiers: &[
+            (UiLang::English, "fire"),
+            (UiLang::BrazilianPortuguese, "fogo"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Fire; the light and heat emitted by burning something.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Fogo; a luz e o calor emitidos ao queimar algo.",
+            ),
+        ],
+    });
+
+    pub const TREE: Self = Self::new(&MeaningDef {
+        id: "tree",
+        domain: Domain::Nature,
+        class: MeaningClass::Noun,
+        identifiers: &[
+            (UiLang::English, "tree"),
+            (UiLang::BrazilianPortuguese, "árvore"),
+        ],
+        descriptions: &[
+            (UiLang::English, "A tree."),
+            (UiLang::BrazilianPortuguese, "Uma árvore."),
+        ],
+    });
+
+    pub const BIG: Self = Self::new(&MeaningDef {
+        id: "big",
+        domain: Domain::Size,
+        class: MeaningClass::Adjective,
+        identifiers: &[
+            (UiLang::English, "big"),
+            (UiLang::BrazilianPortuguese, "grande"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Big; the quality of having a big size, but not huge.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Grande; a qualidade de ter um tamanho grande, mas não enorme.",
+            ),
+        ],
+    });
+
+    pub const THIS_NEAR: Self = Self::new(&MeaningDef {
+        id: "near-this",
+        domain: Domain::Deixis,
+        class: MeaningClass::Pronoun,
+        identifiers: &[
+            (UiLang::English, "near this"),
+            (UiLang::BrazilianPortuguese, "este próximo"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Demonstrative pronoun used for near things; this.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Pronome demonstrativo usado para coisas próximas; este.",
+            ),
+        ],
+    });
+
+    pub const THAT_FAR: Self = Self::new(&MeaningDef {
+        id: "far-this",
+        domain: Domain::Deixis,
+        class: MeaningClass::Pronoun,
+        identifiers: &[
+            (UiLang::English, "far this"),
+            (UiLang::BrazilianPortuguese, "esse distante"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Demonstrative pronoun used far but not so far things; that.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Pronome demonstrativo usado para coisas distantes, mas não \
+                 tão distantes; esse.",
+            ),
+        ],
+    });
+
+    pub const THAT_VERY_FAR: Self = Self::new(&MeaningDef {
+        id: "emphatic-far-this",
+        domain: Domain::Deixis,
+        class: MeaningClass::Pronoun,
+        identifiers: &[
+            (UiLang::English, "emphatic far this"),
+            (UiLang::BrazilianPortuguese, "aquele enfático"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Demonstrative pronoun used for very far things, in an \
+                 emphatic manner; that.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Pronome demonstrativo usado para coisas muito distantes, de \
+                 maneira enfática; aquele.",
+            ),
+        ],
+    });
+
+    pub const INFORMAL_PERSONAL: Self = Self::new(&MeaningDef {
+        id: "informal-personal-pronoun",
+        domain: Domain::Person,
+        class: MeaningClass::Pronoun,
+        identifiers: &[
+            (UiLang::English, "informal personal pronoun"),
+            (UiLang::BrazilianPortuguese, "pronome pessoal informal"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Informal personal pronoun, informal I, you, he, she, they, \
+                 it, we, etc.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Pronome pessoal informal; eu, você, ele, ela, eles, nós, etc. \
+                 informais.",
+            ),
+        ],
+    });
+
+    pub const FORMAL_PERSONAL: Self = Self::new(&MeaningDef {
+        id: "formal-personal-pronoun",
+        domain: Domain::Person,
+        class: MeaningClass::Pronoun,
+        identifiers: &[
+            (UiLang::English, "formal personal pronoun"),
+            (UiLang::BrazilianPortuguese, "pronome pessoal formal"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Formal personal pronoun, informal I, you, he, she, they, it, \
+                 we, etc.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Pronome pessoal formal; eu, você, ele, ela, eles, nós, etc. \
+                 formais.",
+            ),
+        ],
+    });
+
+    pub const WHAT: Self = Self::new(&MeaningDef {
+        id: "what",
+        domain: Domain::Reference,
+        class: MeaningClass::Pronoun,
+        identifiers: &[
+            (UiLang::English, "what"),
+            (UiLang::BrazilianPortuguese, "o quê"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Question pronoun used to refer to things in general; what.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Pronome interrogativo usado para se referir a coisas em \
+                 geral; o quê.",
+            ),
+        ],
+    });
+
+    pub const THAT_RELATIVE: Self = Self::new(&MeaningDef {
+        id: "relative-that",
+        domain: Domain::Reference,
+        class: MeaningClass::Pronoun,
+        identifiers: &[
+            (UiLang::English, "relative that"),
+            (UiLang::BrazilianPortuguese, "que relativo"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Relative pronoun used to refer to things in general; that.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Pronome relativo usado para se referir a coisas em geral; \
+                 que.",
+            ),
+        ],
+    });
+
+    pub const TO: Self = Self::new(&MeaningDef {
+        id: "to",
+        domain: Domain::Function,
+        class: MeaningClass::Adposition,
+        identifiers: &[
+            (UiLang::English, "to"),
+            (UiLang::BrazilianPortuguese, "para"),
+        ],
+        descriptions: &[
+            (UiLang::English, "Particle used to indicate destination; to"),
+            (
+                UiLang::BrazilianPortuguese,
+                "Partícula usada para indicar destino; para.",
+            ),
+        ],
+    });
+
+    pub const OF: Self = Self::new(&MeaningDef {
+        id: "of",
+        domain: Domain::Function,
+        class: MeaningClass::Adposition,
+        identifiers: &[
+            (UiLang::English, "of"),
+            (UiLang::BrazilianPortuguese, "de"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Particle used to express a relationship between two things, \
+                 usually possession or pertinence; of.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Partícula usada para expressar uma relação entre duas coisas, \
+                 geralmente posse ou pertinência; de.",
+            ),
+        ],
+    });
+
+    pub const AND: Self = Self::new(&MeaningDef {
+        id: "and",
+        domain: Domain::Function,
+        class: MeaningClass::Conjunction,
+        identifiers: &[
+            (UiLang::English, "and"),
+            (UiLang::BrazilianPortuguese, "e"),
+        ],
+        descriptions: &[
+            (
+                UiLang::English,
+                "Conjunction used to express that all the clauses are true; \
+                 and.",
+            ),
+            (
+                UiLang::BrazilianPortuguese,
+                "Conjunção usada para expressar que todas as orações são \
+                 verdadeiras; e.",
+            ),
+        ],
+    });
+
+    pub const COMPOUND_CONJ: Self = Self::new(&MeaningDef {
+        id: "equals",
+        domain: Domain::Function,
+        class: MeaningClass::Conjunction,
+        identifiers: &[
+            (UiLang::English, "equals"),
+            (UiLang::BrazilianPortuguese, "igual"),
+        ],
+        descriptions: &[
+            (UiLang::English, "Conjunction used to compound phrases."),
+            (
+                UiLang::BrazilianPortuguese,
+                "Conjunção usada para compor frases.",
+            ),
+        ],
+    });
+
+    pub const TO_WALK: Self = Self::new(&MeaningDef {
+        id: "to-walk",
+        domain: Domain::Motion,
+        class: MeaningClass::Verb,
+        identifiers: &[
+            (UiLang::English, "to walk"),
+            (UiLang::BrazilianPortuguese, "andar"),
+        ],
+        descriptions: &[
+            (UiLang::English, "To walk."),
+            (UiLang::BrazilianPortuguese, "Andar; caminhar."),
+        ],
+    });
+
+    pub const SHARED: &'static [Self] = &[
+        Meaning::STAR,
+        Meaning::NIGHT_STAR,
+        Meaning::SUN,
+        Meaning::EYE,
+        Meaning::WIND,
+        Meaning::FIRE,
+        Meaning::TREE,
+        Meaning::BIG,
+        Meaning::THIS_NEAR,
+        Meaning::THAT_FAR,
+        Meaning::THAT_VERY_FAR,
+        Meaning::INFORMAL_PERSONAL,
+        Meaning::FORMAL_PERSONAL,
+        Meaning::WHAT,
+        Meaning::THAT_RELATIVE,
+        Meaning::TO,
+        Meaning::OF,
+        Meaning::AND,
+        Meaning::COMPOUND_CONJ,
+        Meaning::TO_WALK,
+    ];
+}
@@ -0,0 +1,722 @@
+use std::{collections::BTreeSet, fmt};
+use thiserror::Error;
+
+/// Maximum number of passes over a cyclic stage before giving up on reaching
+/// a fixed point.
+const MAX_CYCLES: usize = 64;
+
+#[derive(Debug, Clone, Error)]
+#[error("Unknown segment {symbol:?}")]
+pub struct UnknownSegment {
+    pub symbol: String,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Invalid sound change {rule:?}: {reason}")]
+pub struct RuleSyntaxError {
+    pub rule: String,
+    pub reason: &'static str,
+}
+
+/// A sound, written with its symbol and described by its features.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Segment {
+    pub symbol: String,
+    pub features: BTreeSet<String>,
+}
+
+impl Segment {
+    pub fn new(symbol: &str, features: &[&str]) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            features: features.iter().map(|&feat| feat.to_owned()).collect(),
+        }
+    }
+
+    pub fn has(&self, feature: &str) -> bool {
+        self.features.contains(feature)
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(&self.symbol)
+    }
+}
+
+/// Renders a sequence of segments by concatenating their symbols.
+pub fn render(form: &[Segment]) -> String {
+    form.iter().map(|segment| segment.symbol.as_str()).collect()
+}
+
+/// Every segment the rules of a language may read or produce. When two
+/// segments share a symbol, the first one is used when reading that symbol.
+#[derive(Debug, Clone, Default)]
+pub struct Inventory {
+    segments: Vec<Segment>,
+}
+
+impl Inventory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, segment: Segment) {
+        self.segments.push(segment);
+    }
+
+    /// Adds, for every segment, a variant with the same symbol and the given
+    /// extra feature. Useful for features that are tracked by the rules but
+    /// not written.
+    pub fn add_unwritten_feature(&mut self, feature: &str) {
+        let variants = self
+            .segments
+            .iter()
+            .filter(|segment| !segment.has(feature))
+            .map(|segment| {
+                let mut variant = segment.clone();
+                variant.features.insert(feature.to_owned());
+                variant
+            })
+            .collect::<Vec<_>>();
+        self.segments.extend(variants);
+    }
+
+    pub fn get(&self, symbol: &str) -> anyhow::Result<&Segment> {
+        let segment = self
+            .segments
+            .iter()
+            .find(|segment| segment.symbol == symbol)
+            .ok_or_else(|| UnknownSegment { symbol: symbol.to_owned() })?;
+        Ok(segment)
+    }
+
+    /// Finds the segment with exactly the given features, preferring one
+    /// written with `symbol`.
+    fn with_features(
+        &self,
+        features: &BTreeSet<String>,
+        symbol: &str,
+    ) -> Option<&Segment> {
+        let mut candidates =
+            self.segments.iter().filter(|seg| &seg.features == features);
+        let first = candidates.clone().next();
+        candidates.find(|segment| segment.symbol == symbol).or(first)
+    }
+
+    /// Longest symbol of the inventory that is a prefix of `input`.
+    fn longest_prefix(&self, input: &str) -> Option<&Segment> {
+        let mut best = None::<&Segment>;
+        for segment in &self.segments {
+            let longer = best.is_none_or(|best| {
+                segment.symbol.len() > best.symbol.len()
+            });
+            if input.starts_with(segment.symbol.as_str()) && longer {
+                best = Some(segment);
+            }
+        }
+        best
+    }
+
+    /// Splits a written form into segments, always picking the longest
+    /// symbol. Characters listed in `ignore` (e.g. stress and syllable
+    /// marks) are skipped.
+    pub fn tokenize(
+        &self,
+        input: &str,
+        ignore: &[char],
+    ) -> anyhow::Result<Vec<Segment>> {
+        let mut form = Vec::new();
+        let mut rest = input;
+
+        while let Some(ch) = rest.chars().next() {
+            if ignore.contains(&ch) || ch.is_whitespace() {
+                rest = &rest[ch.len_utf8() ..];
+            } else {
+                let segment = self.longest_prefix(rest).ok_or_else(|| {
+                    UnknownSegment { symbol: ch.to_string() }
+                })?;
+                rest = &rest[segment.symbol.len() ..];
+                form.push(segment.clone());
+            }
+        }
+
+        Ok(form)
+    }
+}
+
+/// A feature bundle such as `[+palatal -round]`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Bundle {
+    pub plus: BTreeSet<String>,
+    pub minus: BTreeSet<String>,
+}
+
+impl Bundle {
+    pub fn matches(&self, segment: &Segment) -> bool {
+        self.plus.iter().all(|feat| segment.has(feat))
+            && self.minus.iter().all(|feat| !segment.has(feat))
+    }
+
+    fn apply(&self, segment: &Segment) -> BTreeSet<String> {
+        let mut features = segment.features.clone();
+        for feature in &self.minus {
+            features.remove(feature);
+        }
+        features.extend(self.plus.iter().cloned());
+        features
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Matcher {
+    Segment(Segment),
+    Bundle(Bundle),
+    Set(Vec<Matcher>),
+}
+
+impl Matcher {
+    pub fn matches(&self, segment: &Segment) -> bool {
+        match self {
+            Matcher::Segment(expected) => expected.symbol == segment.symbol,
+            Matcher::Bundle(bundle) => bundle.matches(segment),
+            Matcher::Set(matchers) => {
+                matchers.iter().any(|matcher| matcher.matches(segment))
+            },
+        }
+    }
+
+    /// Position of the first member of a set matching the segment.
+    fn position(&self, segment: &Segment) -> Option<usize> {
+        match self {
+            Matcher::Set(matchers) => {
+                matchers.iter().position(|matcher| matcher.matches(segment))
+            },
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnvElement {
+    Boundary,
+    Matcher(Matcher),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Replacement {
+    Segment(Segment),
+    /// Replaces each member of the target set with the member of this list
+    /// at the same position.
+    Set(Vec<Segment>),
+    Features(Bundle),
+    Deletion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// A context-sensitive rewrite rule, written `target > replacement / left _
+/// right`. Rules are applied iteratively: a change made at one position is
+/// visible to the environment of the following positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    pub notation: String,
+    pub target: Matcher,
+    pub replacement: Replacement,
+    pub left: Vec<EnvElement>,
+    pub right: Vec<EnvElement>,
+    pub direction: Direction,
+}
+
+impl Rule {
+    /// Parses a rule in the notation `target > replacement / left _ right`,
+    /// optionally followed by `(rtl)` to apply it from right to left.
+    ///
+    /// Targets and environment elements are segment symbols, feature bundles
+    /// such as `[+nasal -palatal]` or sets such as `{a, e, [+high]}`. `#`
+    /// marks a word boundary in the environment. The replacement is a
+    /// symbol, a set of symbols mapped pairwise onto a target set, a feature
+    /// bundle to change or `∅` for deletion.
+    pub fn parse(
+        notation: &str,
+        inventory: &Inventory,
+    ) -> anyhow::Result<Self> {
+        let error =
+            |reason| RuleSyntaxError { rule: notation.to_owned(), reason };
+
+        let mut text = notation.trim();
+        let mut direction = Direction::LeftToRight;
+        if let Some(stripped) = text.strip_suffix("(rtl)") {
+            text = stripped.trim_end();
+            direction = Direction::RightToLeft;
+        }
+
+        let (change, env) = match text.find('/') {
+            Some(index) => (&text[.. index], Some(&text[index + 1 ..])),
+            None => (text, None),
+        };
+        let arrow = change.find('>').ok_or_else(|| error("missing `>`"))?;
+
+        let mut parser =
+            Parser { notation, input: &change[.. arrow], inventory };
+        let target = parser.matcher()?.ok_or_else(|| error("missing target"))?;
+        if !parser.at_end() {
+            Err(error("target must be a single element"))?;
+        }
+
+        let mut parser =
+            Parser { notation, input: &change[arrow + 1 ..], inventory };
+        let replacement = parser.replacement(&target, &error)?;
+        if !parser.at_end() {
+            Err(error("replacement must be a single element"))?;
+        }
+
+        let (left, right) = match env {
+            Some(env) => {
+                let underscore = env
+                    .find('_')
+                    .ok_or_else(|| error("environment missing `_`"))?;
+                let left =
+                    Parser { notation, input: &env[.. underscore], inventory }
+                        .environment()?;
+                let right = Parser {
+                    notation,
+                    input: &env[underscore + 1 ..],
+                    inventory,
+                }
+                .environment()?;
+                let misplaced_boundary = left
+                    .iter()
+                    .skip(1)
+                    .chain(right.iter().rev().skip(1))
+                    .any(|elem| *elem == EnvElement::Boundary);
+                if misplaced_boundary {
+                    Err(error("`#` must be at the edge of the environment"))?;
+                }
+                (left, right)
+            },
+            None => (Vec::new(), Vec::new()),
+        };
+
+        Ok(Self {
+            notation: notation.trim().to_owned(),
+            target,
+            replacement,
+            left,
+            right,
+            direction,
+        })
+    }
+
+    fn env_matches(&self, form: &[Segment], index: usize) -> bool {
+        let left_ok = {
+            let mut pos = index;
+            self.left.iter().rev().all(|elem| match elem {
+                EnvElement::Boundary => pos == 0,
+                EnvElement::Matcher(matcher) => {
+                    if pos == 0 {
+                        false
+                    } else {
+                        pos -= 1;
+                        matcher.matches(&form[pos])
+                    }
+                },
+            })
+        };
+
+        let right_ok = {
+            let mut pos = index + 1;
+            self.right.iter().all(|elem| match elem {
+                EnvElement::Boundary => pos == form.len(),
+                EnvElement::Matcher(matcher) => {
+                    let matched =
+                        pos < form.len() && matcher.matches(&form[pos]);
+                    pos += 1;
+                    matched
+                },
+            })
+        };
+
+        left_ok && right_ok
+    }
+
+    fn rewrite(
+        &self,
+        segment: &Segment,
+        inventory: &Inventory,
+    ) -> Option<Segment> {
+        match &self.replacement {
+            Replacement::Segment(replacement) => Some(replacement.clone()),
+            Replacement::Set(replacements) => {
+                let index = self.target.position(segment)?;
+                Some(replacements[index].clone())
+            },
+            Replacement::Features(bundle) => {
+                let features = bundle.apply(segment);
+                let found = inventory
+                    .with_features(&features, &segment.symbol)
+                    .cloned();
+                Some(found.unwrap_or_else(|| Segment {
+                    symbol: segment.symbol.clone(),
+                    features,
+                }))
+            },
+            Replacement::Deletion => None,
+        }
+    }
+
+    /// Applies the rule to a form, returning whether anything changed.
+    pub fn apply(
+        &self,
+        form: &mut Vec<Segment>,
+        inventory: &Inventory,
+    ) -> bool {
+        let mut changed = false;
+        let mut index = match self.direction {
+            Direction::LeftToRight => 0,
+            Direction::RightToLeft => form.len(),
+        };
+
+        loop {
+            let curr = match self.direction {
+                Direction::LeftToRight if index < form.len() => index,
+                Direction::RightToLeft if index > 0 => index - 1,
+                _ => break,
+            };
+
+            let matches = self.target.matches(&form[curr])
+                && self.env_matches(form, curr);
+
+            let mut deleted = false;
+            if matches {
+                match self.rewrite(&form[curr], inventory) {
+                    Some(segment) => {
+                        if segment != form[curr] {
+                            form[curr] = segment;
+                            changed = true;
+                        }
+                    },
+                    None => {
+                        form.remove(curr);
+                        changed = true;
+                        deleted = true;
+                    },
+                }
+            }
+
+            match self.direction {
+                Direction::LeftToRight if !deleted => index += 1,
+                Direction::LeftToRight => (),
+                Direction::RightToLeft => index -= 1,
+            }
+        }
+
+        changed
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(&self.notation)
+    }
+}
+
+struct Parser<'input, 'inv> {
+    notation: &'input str,
+    input: &'input str,
+    inventory: &'inv Inventory,
+}
+
+impl<'input, 'inv> Parser<'input, 'inv> {
+    fn skip_space(&mut self) {
+        self.input = self.input.trim_start_matches(|ch: char| {
+            ch.is_whitespace() || ch == ','
+        });
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_space();
+        self.input.is_empty()
+    }
+
+    fn syntax_error(&self, reason: &'static str) -> RuleSyntaxError {
+        RuleSyntaxError { rule: self.notation.to_owned(), reason }
+    }
+
+    fn matcher(&mut self) -> anyhow::Result<Option<Matcher>> {
+        self.skip_space();
+        if self.input.is_empty() {
+            Ok(None)
+        } else if self.input.starts_with('[') {
+            Ok(Some(Matcher::Bundle(self.bundle()?)))
+        } else if let Some(rest) = self.input.strip_prefix('{') {
+            self.input = rest;
+            let mut members = Vec::new();
+            loop {
+                self.skip_space();
+                if let Some(rest) = self.input.strip_prefix('}') {
+                    self.input = rest;
+                    break;
+                }
+                let member = self
+                    .matcher()?
+                    .ok_or_else(|| self.syntax_error("unclosed `{`"))?;
+                members.push(member);
+            }
+            Ok(Some(Matcher::Set(members)))
+        } else {
+            Ok(Some(Matcher::Segment(self.segment()?)))
+        }
+    }
+
+    fn segment(&mut self) -> anyhow::Result<Segment> {
+        let segment =
+            self.inventory.longest_prefix(self.input).ok_or_else(|| {
+                let symbol = self
+                    .input
+                    .split(|ch: char| {
+                        ch.is_whitespace() || "[]{},_#".contains(ch)
+                    })
+                    .next()
+                    .unwrap_or("");
+                UnknownSegment { symbol: symbol.to_owned() }
+            })?;
+        self.input = &self.input[segment.symbol.len() ..];
+        Ok(segment.clone())
+    }
+
+    fn bundle(&mut self) -> anyhow::Result<Bundle> {
+        let end = self
+            .input
+            .find(']')
+            .ok_or_else(|| self.syntax_error("unclosed `[`"))?;
+        let contents = &self.input[1 .. end];
+        self.input = &self.input[end + 1 ..];
+
+        let mut bundle = Bundle::default();
+        let specs = contents
+            .split(|ch: char| ch.is_whitespace() || ch == ',')
+            .filter(|spec| !spec.is_empty());
+        for spec in specs {
+            if let Some(feature) = spec.strip_prefix('+') {
+                bundle.plus.insert(feature.to_owned());
+            } else if let Some(feature) = spec.strip_prefix('-') {
+                bundle.minus.insert(feature.to_owned());
+            } else {
+                Err(self.syntax_error("features must start with `+` or `-`"))?;
+            }
+        }
+        Ok(bundle)
+    }
+
+    fn replacement<F>(
+        &mut self,
+        target: &Matcher,
+        error: &F,
+    ) -> anyhow::Result<Replacement>
+    where
+        F: Fn(&'static str) -> RuleSyntaxError,
+    {
+        self.skip_space();
+        if let Some(rest) = self.input.strip_prefix('∅') {
+            self.input = rest;
+            return Ok(Replacement::Deletion);
+        }
+
+        match self.matcher()? {
+            None => Err(error("missing replacement"))?,
+            Some(Matcher::Segment(segment)) => {
+                Ok(Replacement::Segment(segment))
+            },
+            Some(Matcher::Bundle(bundle)) => Ok(Replacement::Features(bundle)),
+            Some(Matcher::Set(members)) => {
+                let target_len = match target {
+                    Matcher::Set(targets) => targets.len(),
+                    _ => Err(error("a replacement set needs a target set"))?,
+                };
+                if members.len() != target_len {
+                    Err(error("target and replacement sets differ in size"))?;
+                }
+                let segments = members
+                    .into_iter()
+                    .map(|member| match member {
+                        Matcher::Segment(segment) => Ok(segment),
+                        _ => Err(error("replacement sets hold only segments")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Replacement::Set(segments))
+            },
+        }
+    }
+
+    fn environment(&mut self) -> anyhow::Result<Vec<EnvElement>> {
+        let mut elements = Vec::new();
+        loop {
+            self.skip_space();
+            if let Some(rest) = self.input.strip_prefix('#') {
+                self.input = rest;
+                elements.push(EnvElement::Boundary);
+            } else {
+                match self.matcher()? {
+                    Some(matcher) => {
+                        elements.push(EnvElement::Matcher(matcher))
+                    },
+                    None => break,
+                }
+            }
+        }
+        Ok(elements)
+    }
+}
+
+/// An ordered group of rules. A cyclic stage is reapplied as a whole until
+/// the form stops changing, which models processes such as spreading.
+#[derive(Debug, Clone)]
+pub struct Stage {
+    pub name: String,
+    pub cyclic: bool,
+    pub rules: Vec<Rule>,
+}
+
+impl Stage {
+    pub fn parse<'rule, I>(
+        name: &str,
+        cyclic: bool,
+        rules: I,
+        inventory: &Inventory,
+    ) -> anyhow::Result<Self>
+    where
+        I: IntoIterator<Item = &'rule str>,
+    {
+        let rules = rules
+            .into_iter()
+            .map(|rule| Rule::parse(rule, inventory))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(Self { name: name.to_owned(), cyclic, rules })
+    }
+}
+
+/// A rule application that changed the form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub stage: String,
+    pub rule: String,
+    pub form: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Derivation {
+    pub input: Vec<Segment>,
+    pub steps: Vec<Step>,
+}
+
+impl Derivation {
+    pub fn output(&self) -> &[Segment] {
+        self.steps.last().map_or(&self.input, |step| &step.form)
+    }
+
+    /// The form as it was at the end of the given stage.
+    pub fn after_stage(&self, stage: &str) -> Option<&[Segment]> {
+        let index = self.steps.iter().rposition(|step| step.stage == stage)?;
+        Some(&self.steps[index].form)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SoundChanges {
+    pub inventory: Inventory,
+    pub stages: Vec<Stage>,
+}
+
+impl SoundChanges {
+    pub fn derive(&self, input: Vec<Segment>) -> Derivation {
+        let mut form = input.clone();
+        let mut steps = Vec::new();
+
+        for stage in &self.stages {
+            let mut cycles = 0;
+            loop {
+                let mut changed = false;
+                for rule in &stage.rules {
+                    if rule.apply(&mut form, &self.inventory) {
+                        changed = true;
+                        steps.push(Step {
+                            stage: stage.name.clone(),
+                            rule: rule.notation.clone(),
+                            form: form.clone(),
+                        });
+                    }
+                }
+                cycles += 1;
+                if !stage.cyclic || !changed || cycles >= MAX_CYCLES {
+                    break;
+                }
+            }
+        }
+
+        Derivation { input, steps }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn inventory() -> Inventory {
+        let mut inventory = Inventory::new();
+        inventory.add(Segment::new("a", &["vowel", "low"]));
+        inventory.add(Segment::new("i", &["vowel", "high"]));
+        inventory.add(Segment::new("s", &["consonant", "coronal"]));
+        inventory.add(Segment::new("ɕ", &["consonant", "coronal", "palatal"]));
+        inventory.add(Segment::new("j", &["consonant", "palatal"]));
+        inventory.add(Segment::new("t", &["consonant", "coronal", "stop"]));
+        inventory.add(Segment::new("tʰ", &["consonant", "coronal", "stop"]));
+        inventory
+    }
+
+    fn run(rule: &str, input: &str) -> String {
+        let inventory = inventory();
+        let rule = Rule::parse(rule, &inventory).unwrap();
+        let mut form = inventory.tokenize(input, &[]).unwrap();
+        rule.apply(&mut form, &inventory);
+        render(&form)
+    }
+
+    #[test]
+    fn tokenize_longest_symbol() {
+        let form = inventory().tokenize("ˈtʰa.t", &['ˈ', '.']).unwrap();
+        assert_eq!(render(&form), "tʰat");
+        assert_eq!(form.len(), 3);
+    }
+
+    #[test]
+    fn contexts() {
+        assert_eq!(run("s > ɕ / [+palatal]_", "jsas"), "jɕas");
+        assert_eq!(run("s > ɕ / _#", "sas"), "saɕ");
+        assert_eq!(run("s > ɕ / #_", "sas"), "ɕas");
+        assert_eq!(run("{a, i} > {i, a} / s_", "sasi"), "sisa");
+        assert_eq!(run("s > [+palatal]", "sa"), "ɕa");
+        assert_eq!(run("a > ∅ / _s", "tasa"), "tsa");
+    }
+
+    #[test]
+    fn direction() {
+        assert_eq!(run("s > ɕ / [+palatal]_", "jsss"), "jɕɕɕ");
+        assert_eq!(run("s > ɕ / _[+palatal]", "sssj"), "ssɕj");
+        assert_eq!(run("s > ɕ / _[+palatal] (rtl)", "sssj"), "ɕɕɕj");
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let inventory = inventory();
+        assert!(Rule::parse("s ɕ", &inventory).is_err());
+        assert!(Rule::parse("s > ɕ / j", &inventory).is_err());
+        assert!(Rule::parse("{a, i} > {i}", &inventory).is_err());
+        assert!(Rule::parse("s > ɕ / j#_", &inventory).is_err());
+        assert!(Rule::parse("q > s", &inventory).is_err());
+    }
+}
@@ -28,22 +28,37 @@ pub struct InlineComponent;
 #[derive(Debug, Clone, Copy)]
 pub struct Context<'loc, 'site> {
     location: &'loc InternalPath,
+    edition: &'loc InternalPath,
     site: &'site Site,
 }
 
 impl<'loc, 'site> Context<'loc, 'site> {
-    pub(crate) fn new(location: &'loc InternalPath, site: &'site Site) -> Self {
-        Self { location, site }
+    pub(crate) fn new(
+        location: &'loc InternalPath,
+        edition: &'loc InternalPath,
+        site: &'site Site,
+    ) -> Self {
+        Self { location, edition, site }
     }
 
     pub fn location(self) -> &'loc InternalPath {
         self.location
     }
 
+    /// Path of the edition being generated, relative to the output
+    /// directory.
+    pub fn edition(self) -> &'loc InternalPath {
+        self.edition
+    }
+
     pub fn subpages(self) -> &'loc InternalPath {
         self.location
     }
 
+    pub fn site(self) -> &'site Site {
+        self.site
+    }
+
     pub fn renderer<T>(self, component: T) -> Renderer<'loc, 'site, T>
     where
         T: Component,
@@ -93,7 +108,7 @@ pub trait Component: fmt::Debug {
     }
 }
 
-impl<'this, T> Component for &'this T
+impl<T> Component for &T
 where
     T: Component + ?Sized,
 {
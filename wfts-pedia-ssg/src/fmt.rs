@@ -18,7 +18,7 @@ impl StrExt for str {
         let mut string = String::with_capacity(self.len());
         let mut slice = self;
 
-        while slice.len() > 0 {
+        while !slice.is_empty() {
             let index = match slice
                 .char_indices()
                 .find(|&(i, ch)| i > 0 && !ch.is_alphabetic())
@@ -0,0 +1,130 @@
+use std::fmt;
+
+/// A language the encyclopedia itself is written in, as opposed to the
+/// languages it documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum UiLang {
+    #[default]
+    English,
+    BrazilianPortuguese,
+}
+
+impl UiLang {
+    pub const ALL: &'static [Self] =
+        &[UiLang::English, UiLang::BrazilianPortuguese];
+
+    /// BCP 47 tag, used for `lang` and `hreflang` attributes and as the
+    /// directory name of the edition.
+    pub fn code(self) -> &'static str {
+        match self {
+            UiLang::English => "en",
+            UiLang::BrazilianPortuguese => "pt-BR",
+        }
+    }
+
+    /// Name of the language, written in the language itself.
+    pub fn name(self) -> &'static str {
+        match self {
+            UiLang::English => "English",
+            UiLang::BrazilianPortuguese => "Português (Brasil)",
+        }
+    }
+
+    /// Language used when something is not available in this one.
+    pub fn fallback(self) -> Option<Self> {
+        match self {
+            UiLang::English => None,
+            UiLang::BrazilianPortuguese => Some(UiLang::English),
+        }
+    }
+
+    /// This language followed by its fallbacks, in order.
+    pub fn fallback_chain(self) -> FallbackChain {
+        FallbackChain { curr: Some(self) }
+    }
+
+    /// Key to sort text written in this language in alphabetical order.
+    /// Letters are compared ignoring case and diacritics, so that "árvore"
+    /// comes between "arco" and "azul", and the text as written breaks
+    /// ties.
+    pub fn sort_key(self, text: &str) -> (String, String) {
+        let folded = text.chars().flat_map(char::to_lowercase).map(fold);
+        (folded.collect(), text.to_owned())
+    }
+
+    /// Picks the first translation available in the fallback chain.
+    pub fn select<T, F>(self, translate: F) -> Option<T>
+    where
+        F: FnMut(Self) -> Option<T>,
+    {
+        self.fallback_chain().find_map(translate)
+    }
+}
+
+impl fmt::Display for UiLang {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.code())
+    }
+}
+
+/// The base letter of a lowercase Latin letter with diacritics.
+fn fold(ch: char) -> char {
+    match ch {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        _ => ch,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FallbackChain {
+    curr: Option<UiLang>,
+}
+
+impl Iterator for FallbackChain {
+    type Item = UiLang;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let curr = self.curr?;
+        self.curr = curr.fallback();
+        Some(curr)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fallback_chain_ends_in_english() {
+        for &ui_lang in UiLang::ALL {
+            let chain = ui_lang.fallback_chain().collect::<Vec<_>>();
+            assert_eq!(chain.first(), Some(&ui_lang));
+            assert_eq!(chain.last(), Some(&UiLang::English));
+        }
+    }
+
+    #[test]
+    fn select_falls_back() {
+        let selected = UiLang::BrazilianPortuguese.select(|ui_lang| {
+            match ui_lang {
+                UiLang::English => Some("star"),
+                UiLang::BrazilianPortuguese => None,
+            }
+        });
+        assert_eq!(selected, Some("star"));
+    }
+
+    #[test]
+    fn sort_key_ignores_diacritics_and_case() {
+        let mut words = vec!["zebra", "árvore", "Azul", "arco", "abelha"];
+        words.sort_by_key(|word| UiLang::BrazilianPortuguese.sort_key(word));
+        assert_eq!(words, ["abelha", "arco", "árvore", "Azul", "zebra"]);
+    }
+}
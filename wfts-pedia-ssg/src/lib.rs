@@ -3,3 +3,4 @@ pub mod component;
 pub mod page;
 pub mod site;
 pub mod fmt;
+pub mod i18n;
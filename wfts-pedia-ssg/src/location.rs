@@ -8,6 +8,8 @@ use url::Url;
 pub enum Location {
     URL(Url),
     Internal(InternalLoc),
+    /// A file shared by every edition, relative to the output directory.
+    Shared(InternalPath),
 }
 
 impl From<InternalPath> for Location {
@@ -35,6 +37,13 @@ impl Location {
     {
         Location::Internal(InternalLoc::parse(contents).unwrap())
     }
+
+    pub fn shared<S>(contents: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Location::Shared(InternalPath::parse(contents).unwrap())
+    }
 }
 
 impl Component for Location {
@@ -44,6 +53,12 @@ impl Component for Location {
         match self {
             Location::URL(url) => write!(fmt, "{}", url),
             Location::Internal(int) => int.to_html(fmt, ctx),
+            Location::Shared(path) => {
+                for _ in 0 .. ctx.edition().fragments.len() {
+                    fmt.write_str("../")?;
+                }
+                path.to_html(fmt, ctx)
+            },
         }
     }
 }
@@ -61,7 +76,7 @@ impl InternalPath {
         let string = string.as_ref();
         let mut this = Self { fragments: Vec::new() };
 
-        if string.len() > 0 {
+        if !string.is_empty() {
             for fragment in string.split('/') {
                 this.fragments.push(Fragment::new(fragment)?);
             }
@@ -75,7 +90,7 @@ impl InternalPath {
     }
 
     pub fn is_root(&self) -> bool {
-        self.fragments.len() == 0
+        self.fragments.is_empty()
     }
 
     pub fn dir_depth(&self) -> usize {
@@ -224,7 +239,7 @@ impl Id {
 
 impl fmt::Display for Id {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt.write_str(&self.as_str())
+        fmt.write_str(self.as_str())
     }
 }
 
@@ -270,6 +285,6 @@ impl Fragment {
 
 impl fmt::Display for Fragment {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt.write_str(&self.as_str())
+        fmt.write_str(self.as_str())
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
     component::{Context, DynComponent, InlineComponent},
-    location::{Id, InternalLoc, InternalPath},
+    i18n::UiLang,
+    location::{Id, InternalLoc, InternalPath, Location},
     site::Site,
 };
 use std::fmt;
@@ -28,7 +29,7 @@ impl<'section, 'loc, 'site> fmt::Display
         if path
             .fragments
             .last()
-            .map_or(false, |last| last.as_str() == "index.html")
+            .is_some_and(|last| last.as_str() == "index.html")
         {
             path.fragments.pop();
         }
@@ -83,28 +84,72 @@ impl AsMut<Page> for Page {
     }
 }
 
+/// The same page in another edition of the site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alternate {
+    pub ui_lang: UiLang,
+    pub href: String,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct RenderPage<'page, 'loc, 'site> {
     pub page: &'page Page,
     pub location: &'loc InternalPath,
+    pub edition: &'loc InternalPath,
     pub site: &'site Site,
+    pub alternates: &'loc [Alternate],
 }
 
 impl<'page, 'loc, 'site> fmt::Display for RenderPage<'page, 'loc, 'site> {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        let ctx = Context::new(self.location, self.site);
+        let ctx = Context::new(self.location, self.edition, self.site);
+        write!(
+            fmt,
+            "<!DOCTYPE html><html lang=\"{lang}\"><head><meta \
+             charset=\"utf-8\"><meta name=\"viewport\" \
+             content=\"width=device-width, initial-scale=1.0\"><link \
+             rel=\"stylesheet\" type=\"text/css\" href=\"{css}\">",
+            lang = self.site.ui_lang,
+            css = ctx.renderer(Location::shared("css/main.css")),
+        )?;
+
+        for alternate in self.alternates {
+            write!(
+                fmt,
+                "<link rel=\"alternate\" hreflang=\"{}\" href=\"{}\">",
+                alternate.ui_lang,
+                ctx.renderer(&alternate.href),
+            )?;
+        }
+
         write!(
             fmt,
-            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><meta \
-             name=\"viewport\" content=\"width=device-width, \
-             initial-scale=1.0\"><link rel=\"stylesheet\" type=\"text/css\" \
-             href=\"{css}\"><title>{title}</title><body><div \
-             id=\"page-top\"><div id=\"banner\"><a href=\"{home}\">The World \
-             From The Stars</a></div><h1>{title}</h1><div \
-             id=\"body-wrapper\">{body}",
-            css = ctx.renderer(InternalPath::parse("css/main.css").unwrap()),
+            "<title>{title}</title><body><div id=\"page-top\"><div \
+             id=\"banner\"><a href=\"{home}\">The World From The \
+             Stars</a>",
             title = ctx.renderer(&self.page.title),
             home = ctx.renderer(InternalPath::parse("").unwrap()),
+        )?;
+
+        if self.alternates.len() > 1 {
+            write!(fmt, "<div id=\"editions\">")?;
+            for alternate in self.alternates {
+                write!(
+                    fmt,
+                    "<a href=\"{}\" hreflang=\"{}\" lang=\"{}\">{}</a>",
+                    ctx.renderer(&alternate.href),
+                    alternate.ui_lang,
+                    alternate.ui_lang,
+                    ctx.renderer(alternate.ui_lang.name()),
+                )?;
+            }
+            write!(fmt, "</div>")?;
+        }
+
+        write!(
+            fmt,
+            "</div><h1>{title}</h1><div id=\"body-wrapper\">{body}",
+            title = ctx.renderer(&self.page.title),
             body = ctx.renderer(&self.page.body),
         )?;
 
@@ -1,13 +1,15 @@
 use crate::{
+    i18n::UiLang,
     location::{Fragment, InternalPath},
-    page::{Page, RenderPage},
+    page::{Alternate, Page, RenderPage},
 };
 use anyhow::Context as _;
+use percent_encoding::{percent_encode, CONTROLS};
 use std::{
-    collections::{hash_map, HashMap},
+    collections::{btree_map, BTreeMap},
     fs,
     io::Write,
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 #[derive(Debug, Clone)]
@@ -96,9 +98,11 @@ impl AsMut<Directory> for Directory {
     }
 }
 
+/// A directory of the site. Its contents are ordered by name, so that pages
+/// are always visited in the same order.
 #[derive(Debug, Clone, Default)]
 pub struct Directory {
-    pub contents: HashMap<Fragment, Node>,
+    pub contents: BTreeMap<Fragment, Node>,
 }
 
 impl Directory {
@@ -157,7 +161,7 @@ impl Directory {
 
         let stem = last.expect("Cannot insert at root");
         match dir.contents.entry(stem.clone()) {
-            hash_map::Entry::Vacant(entry) => {
+            btree_map::Entry::Vacant(entry) => {
                 entry.insert(node);
             },
             _ => panic!("Cannot insert if already occupied"),
@@ -168,6 +172,7 @@ impl Directory {
 #[derive(Debug, Clone)]
 pub struct Site {
     pub root: Directory,
+    pub ui_lang: UiLang,
 }
 
 impl<'dir> IntoIterator for &'dir Directory {
@@ -186,7 +191,7 @@ impl<'dir> IntoIterator for &'dir Directory {
 #[derive(Debug, Clone)]
 pub struct Pages<'dir> {
     curr_loc: InternalPath,
-    curr_iter: hash_map::Iter<'dir, Fragment, Node>,
+    curr_iter: btree_map::Iter<'dir, Fragment, Node>,
     directories: Vec<(InternalPath, &'dir Directory)>,
 }
 
@@ -211,9 +216,13 @@ impl<'site> Iterator for Pages<'site> {
     }
 }
 
+/// Generates one edition of the site per UI language. The first edition is
+/// the primary one and is generated at the root of the output directory; the
+/// others are generated in a subdirectory named after their language code.
+/// The assets are shared by every edition and copied once, to the root.
 #[derive(Debug, Clone)]
 pub struct Generator {
-    pub site: Site,
+    pub editions: Vec<Site>,
     pub assets_dir: PathBuf,
     pub output_dir: PathBuf,
 }
@@ -221,21 +230,37 @@ pub struct Generator {
 impl Generator {
     pub fn gen(&self) -> anyhow::Result<()> {
         if self.assets_dir != self.output_dir {
-            self.copy_assets()?;
+            self.copy_assets(&self.output_dir)?;
+        }
+        for site in &self.editions {
+            self.gen_pages(site, &self.edition_dir(site.ui_lang))?;
         }
-        self.gen_pages()?;
 
         Ok(())
     }
 
-    fn copy_assets(&self) -> anyhow::Result<()> {
+    /// Path of the given edition relative to the output directory.
+    pub fn edition_path(&self, ui_lang: UiLang) -> InternalPath {
+        match self.editions.first() {
+            Some(primary) if primary.ui_lang != ui_lang => {
+                InternalPath::parse(ui_lang.code()).unwrap()
+            },
+            _ => InternalPath::root(),
+        }
+    }
+
+    pub fn edition_dir(&self, ui_lang: UiLang) -> PathBuf {
+        self.output_dir.join(self.edition_path(ui_lang).to_fs_path())
+    }
+
+    fn copy_assets(&self, output_root: &Path) -> anyhow::Result<()> {
         let mut dirs = vec![PathBuf::new()];
 
         while let Some(dir) = dirs.pop() {
             let src_dir = self.assets_dir.join(&dir);
-            let output_dir = self.output_dir.join(&dir);
+            let output_dir = output_root.join(&dir);
             fs::create_dir_all(&output_dir).with_context(|| {
-                format!("Creating dir{}", output_dir.display().to_string())
+                format!("Creating dir{}", output_dir.display())
             })?;
 
             let iter = fs::read_dir(&src_dir).with_context(|| {
@@ -271,22 +296,61 @@ impl Generator {
         Ok(())
     }
 
-    fn gen_pages(&self) -> anyhow::Result<()> {
-        for (loc, page) in &self.site.root {
-            let path = self.output_dir.join(loc.to_fs_path());
+    /// Links from the page at `loc` in the edition `from` to the same page
+    /// in every edition that has it, including `from` itself.
+    fn alternates(&self, from: UiLang, loc: &InternalPath) -> Vec<Alternate> {
+        let depth = loc.dir_depth() + self.edition_path(from).fragments.len();
+
+        self.editions
+            .iter()
+            .filter(|site| site.root.get(loc.clone()).is_some())
+            .map(|site| {
+                let mut path = self.edition_path(site.ui_lang);
+                path.fragments.extend(loc.fragments.iter().cloned());
+                let encoded =
+                    percent_encode(path.to_string().as_bytes(), CONTROLS)
+                        .collect::<String>();
+                Alternate {
+                    ui_lang: site.ui_lang,
+                    href: format!("{}{}", "../".repeat(depth), encoded),
+                }
+            })
+            .collect()
+    }
+
+    fn gen_pages(
+        &self,
+        site: &Site,
+        output_dir: &Path,
+    ) -> anyhow::Result<()> {
+        let edition = self.edition_path(site.ui_lang);
+        for (loc, page) in &site.root {
+            let path = output_dir.join(loc.to_fs_path());
             if let Some(parent) = path.parent() {
                 fs::create_dir_all(parent).with_context(|| {
                     format!("Creating dir {}", parent.display())
                 })?;
             }
 
+            let alternates = if self.editions.len() > 1 {
+                self.alternates(site.ui_lang, &loc)
+            } else {
+                Vec::new()
+            };
+
             let mut file = fs::File::create(&path).with_context(|| {
                 format!("Creating page file {}", path.display())
             })?;
             let res = write!(
                 file,
                 "{}",
-                RenderPage { page, location: &loc, site: &self.site }
+                RenderPage {
+                    page,
+                    location: &loc,
+                    edition: &edition,
+                    site,
+                    alternates: &alternates,
+                }
             );
             res.with_context(|| format!("Generating page {}", path.display()))?;
         }
@@ -294,3 +358,30 @@ impl Generator {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::component::Component;
+
+    #[test]
+    fn pages_are_visited_in_the_same_order() {
+        let paths = ["b/index.html", "a/z.html", "a/b/index.html", "c.html"];
+        let visit = |order: &[usize]| {
+            let mut dir = Directory::default();
+            for &i in order {
+                let page = Page {
+                    title: paths[i].to_owned(),
+                    body: "".blocking().to_dyn(),
+                    sections: Vec::new(),
+                };
+                dir.insert(InternalPath::parse(paths[i]).unwrap(), page.into());
+            }
+            dir.into_iter()
+                .map(|(path, _)| path.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(visit(&[0, 1, 2, 3]), visit(&[3, 2, 1, 0]));
+        assert_eq!(visit(&[0, 1, 2, 3]).len(), paths.len());
+    }
+}
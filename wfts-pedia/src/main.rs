@@ -1,4 +1,10 @@
-use std::{collections::HashMap, path::PathBuf, process};
+use std::{
+    collections::BTreeSet,
+    env,
+    fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
 use wfts_lang::Lang;
 use wfts_pedia_ssg::{
     component::{
@@ -6,49 +12,242 @@ use wfts_pedia_ssg::{
         text::{Link, Paragraph},
         Component,
     },
+    i18n::UiLang,
     location::{Id, InternalPath},
     page::{Page, Section},
     site::{Directory, Generator, Node, Site},
 };
-use wfts_star_lang::StarLang;
+use wfts_star_lang::{
+    audio::{self, Manifest},
+    dictionary::{export, Entry},
+    messages::Messages,
+    romanization::Scheme,
+    StarLang,
+};
 
-fn main() {
-    let mut site = Site { root: Directory { contents: HashMap::new() } };
+const USAGE: &str = "usage: wfts-pedia [--romanization <scheme>] \
+                     [--output <dir>] [--verify-reproducible]";
 
-    site.root.insert(
-        InternalPath::parse("index.html").unwrap(),
-        Node::Page(index_page()),
-    );
+#[derive(Debug, Clone, Default)]
+struct Options {
+    /// Instead of generating the site, run this program twice to generate
+    /// it in temporary directories and check that both builds are identical.
+    verify_reproducible: bool,
+    /// Directory to generate the site into, `wfts-pedia/site/` by default.
+    output: Option<PathBuf>,
+    /// Scheme in which Star Language words are shown.
+    romanization: Scheme,
+}
 
-    site.root.insert(StarLang.path(), StarLang.subsite().into());
+fn main() {
+    let options = parse_args().unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(-1);
+    });
 
-    let generator = Generator {
-        site,
-        assets_dir: PathBuf::from("wfts-pedia/assets/"),
-        output_dir: PathBuf::from("wfts-pedia/site/"),
+    let entries = Entry::all();
+    let res = if options.verify_reproducible {
+        verify_reproducible(options.romanization)
+    } else {
+        let output_dir = options
+            .output
+            .unwrap_or_else(|| PathBuf::from("wfts-pedia/site/"));
+        generate(output_dir, &entries, options.romanization)
+            .and_then(|generator| check_audio(&generator, &entries))
     };
 
-    if let Err(err) = generator.gen() {
+    if let Err(err) = res {
         eprintln!("{}: {}", err, err.root_cause());
         process::exit(-1);
     }
 }
 
-fn index_page() -> Page {
-    let body = vec![Paragraph(vec![String::from(
-        "This is the front page of the encyclopedia of \"The World From The \
-         Stars\".",
-    )])
-    .to_dyn()];
+fn parse_args() -> anyhow::Result<Options> {
+    let mut options = Options::default();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--romanization" => {
+                let name =
+                    args.next().ok_or_else(|| anyhow::anyhow!("{}", USAGE))?;
+                options.romanization = Scheme::from_name(&name)?;
+            },
+            "--output" => {
+                let dir =
+                    args.next().ok_or_else(|| anyhow::anyhow!("{}", USAGE))?;
+                options.output = Some(PathBuf::from(dir));
+            },
+            "--verify-reproducible" => options.verify_reproducible = true,
+            _ => anyhow::bail!("{}", USAGE),
+        }
+    }
+    Ok(options)
+}
+
+/// Generates every edition of the site into `output_dir`, with the
+/// dictionary exports and the synthesized audio, which the editions share.
+fn generate(
+    output_dir: PathBuf,
+    entries: &[Entry],
+    scheme: Scheme,
+) -> anyhow::Result<Generator> {
+    let assets_dir = PathBuf::from("wfts-pedia/assets/");
+    Manifest::load(&audio_dir(&assets_dir))?;
+    let generator = Generator {
+        editions: UiLang::ALL
+            .iter()
+            .map(|&ui_lang| make_site(ui_lang, scheme))
+            .collect(),
+        assets_dir,
+        output_dir,
+    };
+
+    generator.gen()?;
+    let lang_dir = generator.output_dir.join(StarLang.path().to_fs_path());
+    export::export_all(entries, &lang_dir.join(export::DIR))?;
+    audio::synthesize_all(entries, &lang_dir.join(audio::DIR))?;
+
+    Ok(generator)
+}
+
+/// Generates the site twice, each time in a new process, and fails if any
+/// file differs between the two builds.
+fn verify_reproducible(scheme: Scheme) -> anyhow::Result<()> {
+    let dir = env::temp_dir().join("wfts-pedia-reproducible");
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    let first = dir.join("first");
+    let second = dir.join("second");
+    for output in [&first, &second] {
+        let status = Command::new(env::current_exe()?)
+            .arg("--romanization")
+            .arg(scheme.name())
+            .arg("--output")
+            .arg(output)
+            .status()?;
+        if !status.success() {
+            anyhow::bail!("building into {} failed", output.display());
+        }
+    }
+
+    let first_files = list_files(&first)?;
+    let second_files = list_files(&second)?;
+    let mut differences = Vec::new();
+    for path in first_files.union(&second_files) {
+        let same = first_files.contains(path)
+            && second_files.contains(path)
+            && fs::read(first.join(path))? == fs::read(second.join(path))?;
+        if !same {
+            differences.push(path);
+        }
+    }
+
+    for path in &differences {
+        eprintln!("differs between builds: {}", path.display());
+    }
+    if !differences.is_empty() {
+        anyhow::bail!(
+            "{} of {} files differ; builds are kept in {}",
+            differences.len(),
+            first_files.union(&second_files).count(),
+            dir.display()
+        );
+    }
+    fs::remove_dir_all(&dir)?;
+    println!("{} files are identical in both builds", first_files.len());
+    Ok(())
+}
+
+/// Paths of every file inside of `root`, relative to it.
+fn list_files(root: &Path) -> anyhow::Result<BTreeSet<PathBuf>> {
+    let mut files = BTreeSet::new();
+    let mut dirs = vec![PathBuf::new()];
+
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(root.join(&dir))? {
+            let entry = entry?;
+            let path = dir.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                dirs.push(path);
+            } else {
+                files.insert(path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Directory of the recordings and of their manifest, in the assets.
+fn audio_dir(assets_dir: &Path) -> PathBuf {
+    assets_dir.join(StarLang.path().to_fs_path()).join(audio::DIR)
+}
+
+/// Warns about recordings that match no word and reports dictionary
+/// headwords that were not recorded yet.
+fn check_audio(
+    generator: &Generator,
+    entries: &[Entry],
+) -> anyhow::Result<()> {
+    let dir = audio_dir(&generator.assets_dir);
+    let report = Manifest::get().check(&dir, entries)?;
+
+    for file in &report.orphans {
+        eprintln!("warning: recording {} matches no word", file);
+    }
+    if !report.missing.is_empty() {
+        eprintln!("{} missing recordings:", report.missing.len());
+        for (word, accent) in &report.missing {
+            eprintln!("    {} ({})", word, accent);
+        }
+    }
+
+    Ok(())
+}
+
+fn make_site(ui_lang: UiLang, scheme: Scheme) -> Site {
+    let mut site = Site { root: Directory::default(), ui_lang };
+
+    site.root.insert(
+        InternalPath::parse("index.html").unwrap(),
+        Node::Page(index_page(ui_lang)),
+    );
+
+    site.root.insert(
+        StarLang.path(),
+        StarLang.romanized_subsite(ui_lang, scheme).into(),
+    );
+
+    site
+}
+
+fn index_page(ui_lang: UiLang) -> Page {
+    let (title, intro, list_title) = match ui_lang {
+        UiLang::English => (
+            "The World From The Stars Encyclopedia",
+            "This is the front page of the encyclopedia of \"The World From \
+             The Stars\".",
+            "List Of Languages",
+        ),
+        UiLang::BrazilianPortuguese => (
+            "Enciclopédia de The World From The Stars",
+            "Esta é a página inicial da enciclopédia de \"The World From The \
+             Stars\".",
+            "Lista de Línguas",
+        ),
+    };
+
+    let body = vec![Paragraph(vec![String::from(intro)]).to_dyn()];
 
     Page {
-        title: "The World From The Stars Encyclopedia".to_owned(),
+        title: title.to_owned(),
         body: body.to_dyn(),
         sections: vec![Section {
-            title: "List Of Languages".to_dyn(),
+            title: list_title.to_dyn(),
             body: UnorderedList(vec![Link {
                 location: StarLang.path().into(),
-                text: "Classical Star Language",
+                text: Messages::get(ui_lang).lang_name,
             }])
             .to_dyn(),
             id: Id::new("list-of-langs").unwrap(),
@@ -0,0 +1,28 @@
+use std::{env, fs, path::PathBuf};
+
+/// Lists the texts of the corpus directory for `corpus::SOURCES`, so that a
+/// new text only needs to be added to the directory.
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let corpus_dir = manifest_dir.join("corpus");
+    println!("cargo:rerun-if-changed={}", corpus_dir.display());
+
+    let mut paths = fs::read_dir(&corpus_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut sources = String::from("&[\n");
+    for path in &paths {
+        let id = path.file_stem().unwrap().to_str().unwrap();
+        let path = path.to_str().unwrap();
+        let line = format!("    ({:?}, include_str!({:?})),\n", id, path);
+        sources.push_str(&line);
+    }
+    sources.push(']');
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("corpus.rs"), sources).unwrap();
+}
@@ -0,0 +1,386 @@
+//! Pronunciation recordings, listed in a manifest in the audio asset
+//! directory.
+
+use crate::{
+    dictionary::Entry,
+    messages::Messages,
+    morphology::Morpheme,
+    phonology::{synthesis, Word},
+    StarLang,
+};
+use anyhow::Context as _;
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt,
+    fs,
+    io::BufWriter,
+    path::Path,
+    sync::OnceLock,
+};
+use thiserror::Error;
+use wfts_lang::Lang;
+use wfts_pedia_ssg::{
+    component::{audio::Audio, Component, Context, InlineComponent},
+    location::Location,
+};
+
+/// Directory of the recordings, relative to the language's asset directory.
+pub const DIR: &str = "audio";
+
+/// Name of the manifest file inside of the audio directory.
+pub const MANIFEST_FILE: &str = "manifest.tsv";
+
+/// Directory of the synthesized clips, inside of the audio directory.
+pub const SYNTHESIZED_DIR: &str = "synthesized";
+
+static MANIFEST_CELL: OnceLock<Manifest> = OnceLock::new();
+
+#[derive(Debug, Clone, Error)]
+#[error("Invalid audio manifest line {line}: {reason}")]
+pub struct InvalidManifestLine {
+    pub line: usize,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("The audio manifest was already loaded, or used before loading")]
+pub struct ManifestAlreadyLoaded;
+
+#[derive(Debug, Clone, Error)]
+#[error("Recording {file} of {word} is listed in the manifest but missing")]
+pub struct MissingFile {
+    pub word: String,
+    pub file: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Accent {
+    Early,
+    Late,
+}
+
+impl Accent {
+    pub const ALL: &'static [Self] = &[Accent::Early, Accent::Late];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Accent::Early => "early",
+            Accent::Late => "late",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|accent| accent.name() == name)
+    }
+}
+
+impl fmt::Display for Accent {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.name())
+    }
+}
+
+/// A recording of a word in one accent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recording {
+    pub word: String,
+    pub accent: Accent,
+    /// File name inside of the audio directory.
+    pub file: String,
+    pub speaker: String,
+    /// Date of the recording, if known.
+    pub recorded: Option<String>,
+}
+
+impl Recording {
+    pub fn audio(&self) -> Audio {
+        Audio(Location::shared(format!(
+            "{}/{}/{}",
+            StarLang.path(),
+            DIR,
+            self.file
+        )))
+    }
+}
+
+impl Component for Recording {
+    type Kind = InlineComponent;
+
+    fn to_html(&self, fmt: &mut fmt::Formatter, ctx: Context) -> fmt::Result {
+        let credit = Messages::get(ctx.site().ui_lang)
+            .recorded_by(&self.speaker, self.recorded.as_deref());
+        write!(
+            fmt,
+            "{}<span class=\"audio-credit\">{}</span>",
+            ctx.renderer(self.audio()),
+            ctx.renderer(credit)
+        )
+    }
+}
+
+/// Audio of a word in one accent: a recording if there is one, otherwise a
+/// synthesized clip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Clip {
+    Recorded(Recording),
+    Synthesized { word: String, accent: Accent },
+}
+
+impl Clip {
+    pub fn of(word: &Word, accent: Accent) -> Self {
+        let text = word.to_text();
+        match Manifest::get().find(&text, accent) {
+            Some(recording) => Clip::Recorded(recording.clone()),
+            None => Clip::Synthesized { word: text, accent },
+        }
+    }
+
+    pub fn audio(&self) -> Audio {
+        match self {
+            Clip::Recorded(recording) => recording.audio(),
+            Clip::Synthesized { word, accent } => {
+                Audio(Location::shared(format!(
+                    "{}/{}/{}/{}-{}.wav",
+                    StarLang.path(),
+                    DIR,
+                    SYNTHESIZED_DIR,
+                    word,
+                    accent
+                )))
+            },
+        }
+    }
+}
+
+impl Component for Clip {
+    type Kind = InlineComponent;
+
+    fn to_html(&self, fmt: &mut fmt::Formatter, ctx: Context) -> fmt::Result {
+        match self {
+            Clip::Recorded(recording) => {
+                write!(fmt, "{}", ctx.renderer(recording))
+            },
+            Clip::Synthesized { .. } => write!(
+                fmt,
+                "{}<span class=\"audio-credit\">{}</span>",
+                ctx.renderer(self.audio()),
+                ctx.renderer(Messages::get(ctx.site().ui_lang).synthesized)
+            ),
+        }
+    }
+}
+
+/// Writes into `dir` a synthesized clip for every word of the dictionary and
+/// accent without a recording.
+pub fn synthesize_all(entries: &[Entry], dir: &Path) -> anyhow::Result<()> {
+    let dir = dir.join(SYNTHESIZED_DIR);
+    fs::create_dir_all(&dir)?;
+    let mut done = HashSet::new();
+
+    for morpheme in entries.iter().flat_map(|entry| entry.inflections.values())
+    {
+        let word = match morpheme {
+            Morpheme::Word(word) if done.insert(word.clone()) => word,
+            _ => continue,
+        };
+        for &accent in Accent::ALL {
+            if let Clip::Synthesized { word: text, accent } =
+                Clip::of(word, accent)
+            {
+                let samples = word.synthesize(accent)?;
+                let path = dir.join(format!("{}-{}.wav", text, accent));
+                synthesis::write_wav(
+                    &samples,
+                    BufWriter::new(fs::File::create(path)?),
+                )?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The recordings listed in the manifest.
+#[derive(Debug, Clone, Default)]
+pub struct Manifest {
+    pub recordings: Vec<Recording>,
+}
+
+impl Manifest {
+    /// The manifest loaded with [`Manifest::load`]. If none was loaded, it
+    /// is empty and every clip is synthesized.
+    pub fn get() -> &'static Self {
+        MANIFEST_CELL.get_or_init(Self::default)
+    }
+
+    /// Reads the manifest of the given audio directory and makes it the one
+    /// returned by [`Manifest::get`]. Must be called before any page is
+    /// built.
+    pub fn load(dir: &Path) -> anyhow::Result<&'static Self> {
+        MANIFEST_CELL
+            .set(Self::read(dir)?)
+            .map_err(|_| ManifestAlreadyLoaded)?;
+        Ok(Self::get())
+    }
+
+    /// Reads the manifest of the given audio directory.
+    pub fn read(dir: &Path) -> anyhow::Result<Self> {
+        let path = dir.join(MANIFEST_FILE);
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("Reading {}", path.display()))?;
+        Self::parse(&text)
+    }
+
+    /// Parses a manifest. Each line has the tab-separated fields word,
+    /// accent, file, speaker and date. Empty lines and lines starting with
+    /// `#` are skipped.
+    pub fn parse(text: &str) -> anyhow::Result<Self> {
+        let mut recordings = Vec::<Recording>::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let invalid = |reason: String| InvalidManifestLine {
+                line: i + 1,
+                reason,
+            };
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let (word, accent, file, speaker, recorded) = match &*fields {
+                [word, accent, file, speaker] => {
+                    (word, accent, file, speaker, "")
+                },
+                [word, accent, file, speaker, recorded] => {
+                    (word, accent, file, speaker, *recorded)
+                },
+                _ => Err(invalid(format!(
+                    "expected 4 or 5 fields, found {}",
+                    fields.len()
+                )))?,
+            };
+            let accent = Accent::from_name(accent).ok_or_else(|| {
+                invalid(format!("unknown accent {:?}", accent))
+            })?;
+            if recordings.iter().any(|recording| {
+                recording.word == *word && recording.accent == accent
+            }) {
+                Err(invalid(format!("{} {} listed twice", word, accent)))?;
+            }
+            recordings.push(Recording {
+                word: word.to_string(),
+                accent,
+                file: file.to_string(),
+                speaker: speaker.to_string(),
+                recorded: Some(recorded.trim())
+                    .filter(|recorded| !recorded.is_empty())
+                    .map(String::from),
+            });
+        }
+
+        Ok(Self { recordings })
+    }
+
+    pub fn find(&self, word: &str, accent: Accent) -> Option<&Recording> {
+        self.recordings.iter().find(|recording| {
+            recording.word == word && recording.accent == accent
+        })
+    }
+
+    /// Compares the manifest with the files in the audio directory and with
+    /// the dictionary. Fails if a listed file does not exist.
+    pub fn check(
+        &self,
+        dir: &Path,
+        entries: &[Entry],
+    ) -> anyhow::Result<Report> {
+        let mut files = BTreeSet::new();
+        for dir_entry in fs::read_dir(dir)? {
+            let name = dir_entry?.file_name().to_string_lossy().into_owned();
+            if name != MANIFEST_FILE {
+                files.insert(name);
+            }
+        }
+
+        for recording in &self.recordings {
+            if !files.contains(&recording.file) {
+                Err(MissingFile {
+                    word: recording.word.clone(),
+                    file: recording.file.clone(),
+                })?;
+            }
+        }
+
+        let words = entries
+            .iter()
+            .flat_map(|entry| entry.inflections.values())
+            .filter_map(|morpheme| match morpheme {
+                Morpheme::Word(word) => Some(word.to_text()),
+                Morpheme::Template(_) => None,
+            })
+            .collect::<HashSet<_>>();
+
+        let mut report = Report::default();
+        for entry in entries {
+            if let Morpheme::Word(word) = entry.headword() {
+                let text = word.to_text();
+                for &accent in Accent::ALL {
+                    if self.find(&text, accent).is_none() {
+                        report.missing.insert((text.clone(), accent));
+                    }
+                }
+            }
+        }
+        for recording in &self.recordings {
+            if !words.contains(&recording.word) {
+                report.orphans.insert(recording.file.clone());
+            }
+        }
+        for file in files {
+            if self.recordings.iter().all(|recording| recording.file != file) {
+                report.orphans.insert(file);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// Result of checking the manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Dictionary headwords without a recording in some accent.
+    pub missing: BTreeSet<(String, Accent)>,
+    /// Files which are not in the manifest, or which record no word of the
+    /// dictionary.
+    pub orphans: BTreeSet<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn manifest_agrees_with_assets() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../wfts-pedia/assets")
+            .join(StarLang.path().to_fs_path())
+            .join(DIR);
+        let manifest = Manifest::read(&dir).unwrap();
+        let report = manifest.check(&dir, &Entry::all()).unwrap();
+        assert_eq!(report.orphans, BTreeSet::new());
+    }
+
+    #[test]
+    fn invalid_manifest_lines() {
+        let manifest =
+            Manifest::parse("# comment\n\nsaŋ\tearly\tsaŋ.ogg\tme\n").unwrap();
+        assert_eq!(manifest.recordings[0].recorded, None);
+        assert!(Manifest::parse("saŋ\tmiddle\tsaŋ.ogg\tme").is_err());
+        assert!(Manifest::parse("saŋ\tearly").is_err());
+        let twice = "saŋ\tlate\ta.ogg\tme\nsaŋ\tlate\tb.ogg\tme";
+        let error = Manifest::parse(twice).unwrap_err();
+        let error = error.downcast::<InvalidManifestLine>().unwrap();
+        assert_eq!(error.line, 2);
+    }
+}
@@ -0,0 +1,80 @@
+use std::{env, process, str::FromStr};
+use wfts_lang::random::Rng;
+use wfts_star_lang::{
+    dictionary::Entry,
+    generator::{Lexicon, WordGenerator},
+    grammar::Paradigm,
+    phonology::Word,
+    romanization::Scheme,
+};
+
+const USAGE: &str = "usage: coin <paradigm> [--count <n>] [--seed <n>] \
+                     [--romanization <scheme>] [--avoid <word>]...";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(-1);
+    }
+}
+
+fn run() -> anyhow::Result<()> {
+    let mut paradigm = None;
+    let mut count = 10;
+    let mut seed = 0;
+    let mut scheme = Scheme::default();
+    let mut avoid = Vec::new();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--count" => count = number(args.next())?,
+            "--seed" => seed = number(args.next())?,
+            "--romanization" => {
+                let name =
+                    args.next().ok_or_else(|| anyhow::anyhow!("{}", USAGE))?;
+                scheme = Scheme::from_name(&name)?;
+            },
+            "--avoid" => {
+                let word =
+                    args.next().ok_or_else(|| anyhow::anyhow!("{}", USAGE))?;
+                avoid.push(Scheme::parse_detected::<Word>(&word)?);
+            },
+            name => match Paradigm::from_name(name) {
+                Some(found) if paradigm.is_none() => paradigm = Some(found),
+                _ => {
+                    let names = Paradigm::ALL
+                        .iter()
+                        .map(|paradigm| paradigm.name())
+                        .collect::<Vec<_>>();
+                    anyhow::bail!("{}\nparadigms: {}", USAGE, names.join(", "))
+                },
+            },
+        }
+    }
+
+    let paradigm = paradigm.ok_or_else(|| anyhow::anyhow!("{}", USAGE))?;
+    let generator = WordGenerator::default();
+    let mut lexicon = Lexicon::from_entries(&Entry::all());
+    for word in &avoid {
+        lexicon.insert(word);
+    }
+    let mut rng = Rng::new(seed);
+
+    for _ in 0 .. count {
+        let word = generator.coin(&mut rng, paradigm, &mut lexicon)?;
+        let text = scheme.render(word.phonemes());
+        println!("{}\t/{}/", text, word.to_broad_ipa());
+    }
+
+    Ok(())
+}
+
+fn number<T>(arg: Option<String>) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let arg = arg.ok_or_else(|| anyhow::anyhow!("{}", USAGE))?;
+    Ok(arg.parse()?)
+}
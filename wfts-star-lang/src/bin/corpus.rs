@@ -0,0 +1,82 @@
+use std::{env, path::PathBuf, process, str::FromStr};
+use wfts_star_lang::{
+    corpus::Corpus,
+    dictionary::Analyzer,
+    phonology::Word,
+    romanization::Scheme,
+};
+
+const USAGE: &str = "usage: corpus frequency | concordance <word> | unknown \
+                     [--dir <path>] [--context <n>]";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(-1);
+    }
+}
+
+fn run() -> anyhow::Result<()> {
+    let mut positional = Vec::new();
+    let mut dir = None;
+    let mut context = 4;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dir" => {
+                let path =
+                    args.next().ok_or_else(|| anyhow::anyhow!("{}", USAGE))?;
+                dir = Some(PathBuf::from(path));
+            },
+            "--context" => context = number(args.next())?,
+            _ => positional.push(arg),
+        }
+    }
+
+    let corpus = match dir {
+        Some(dir) => Corpus::load(&dir)?,
+        None => Corpus::get().clone(),
+    };
+    let analyzer = Analyzer::get();
+
+    match &positional.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["frequency"] => {
+            for (word, count) in corpus.frequencies() {
+                let known =
+                    if analyzer.analyze(&word).is_empty() { "?" } else { "" };
+                println!("{}\t{}{}", count, word, known);
+            }
+        },
+        ["concordance", word] => {
+            let word = Scheme::parse_detected::<Word>(word)?;
+            for line in corpus.concordance(&word, context) {
+                println!(
+                    "{}:{}\t{}\t[{}]\t{}",
+                    line.text.id,
+                    line.line.number,
+                    line.left,
+                    line.keyword,
+                    line.right
+                );
+            }
+        },
+        ["unknown"] => {
+            for (word, count) in corpus.unknown_words(analyzer) {
+                println!("{}\t{}", count, word);
+            }
+        },
+        _ => anyhow::bail!("{}", USAGE),
+    }
+
+    Ok(())
+}
+
+fn number<T>(arg: Option<String>) -> anyhow::Result<T>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    let arg = arg.ok_or_else(|| anyhow::anyhow!("{}", USAGE))?;
+    Ok(arg.parse()?)
+}
@@ -0,0 +1,252 @@
+//! Alphabetical ordering of the Star Language. Words are compared letter by
+//! letter in the traditional order of the alphabet, independently of how
+//! phonemes are declared, and can be turned into byte sort keys for use
+//! outside of this crate.
+
+use crate::phonology::Phoneme;
+use std::{cmp::Ordering, fmt};
+use thiserror::Error;
+
+/// The letters in their traditional order.
+pub const ALPHABET: &[Phoneme] = &[
+    Phoneme::B,
+    Phoneme::Gw,
+    Phoneme::D,
+    Phoneme::J,
+    Phoneme::G,
+    Phoneme::P,
+    Phoneme::Kw,
+    Phoneme::T,
+    Phoneme::C,
+    Phoneme::K,
+    Phoneme::M,
+    Phoneme::Mg,
+    Phoneme::N,
+    Phoneme::Nj,
+    Phoneme::Ng,
+    Phoneme::F,
+    Phoneme::Xw,
+    Phoneme::W,
+    Phoneme::S,
+    Phoneme::R,
+    Phoneme::Y,
+    Phoneme::Ii,
+    Phoneme::X,
+    Phoneme::I,
+    Phoneme::Ee,
+    Phoneme::H,
+    Phoneme::E,
+    Phoneme::Rr,
+    Phoneme::A,
+    Phoneme::Aa,
+];
+
+#[derive(Debug, Clone, Error)]
+#[error("{ch:?} is not a letter of the Star Language")]
+pub struct NotALetter {
+    pub ch: char,
+}
+
+/// Position of the letter in the alphabet, starting at 1.
+fn rank(phoneme: Phoneme) -> u8 {
+    let index = ALPHABET.iter().position(|&letter| letter == phoneme);
+    index.unwrap() as u8 + 1
+}
+
+/// The short vowel of a long vowel; other phonemes are returned as they are.
+fn short(phoneme: Phoneme) -> Phoneme {
+    match phoneme {
+        Phoneme::Ii => Phoneme::I,
+        Phoneme::Ee => Phoneme::E,
+        Phoneme::Aa => Phoneme::A,
+        _ => phoneme,
+    }
+}
+
+/// How long vowels are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Length {
+    /// Long vowels are letters of their own, in their place of the alphabet.
+    Distinct,
+    /// Long vowels sort as their short vowels, and words which differ only
+    /// in length put the short vowel first.
+    Secondary,
+    /// Long vowels sort as their short vowels, and length is not compared
+    /// at all.
+    Ignored,
+}
+
+/// A way of ordering words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Collation {
+    pub length: Length,
+}
+
+impl Default for Collation {
+    fn default() -> Self {
+        Self::TRADITIONAL
+    }
+}
+
+impl Collation {
+    /// The order of the alphabet, as used by the dictionary.
+    pub const TRADITIONAL: Self = Self { length: Length::Distinct };
+
+    /// Ordering which treats long and short vowels alike, except to break
+    /// ties.
+    pub const LENGTH_SECONDARY: Self = Self { length: Length::Secondary };
+
+    /// Ordering which does not distinguish long and short vowels.
+    pub const LENGTH_IGNORED: Self = Self { length: Length::Ignored };
+
+    /// The letter a phoneme is ordered and grouped under.
+    pub fn letter(self, phoneme: Phoneme) -> Phoneme {
+        match self.length {
+            Length::Distinct => phoneme,
+            Length::Secondary | Length::Ignored => short(phoneme),
+        }
+    }
+
+    /// Sort key of a sequence of phonemes. Comparing the keys gives the same
+    /// result as comparing the sequences with `compare`.
+    pub fn key<I>(self, phonemes: I) -> SortKey
+    where
+        I: IntoIterator<Item = Phoneme>,
+    {
+        let phonemes = phonemes.into_iter().collect::<Vec<_>>();
+        let mut bytes = phonemes
+            .iter()
+            .map(|&phoneme| rank(self.letter(phoneme)))
+            .collect::<Vec<_>>();
+        if self.length == Length::Secondary {
+            // The separator is lower than any weight, so that a prefix sorts
+            // before the longer words starting with it.
+            bytes.push(0);
+            bytes.extend(phonemes.iter().map(|&phoneme| {
+                if short(phoneme) == phoneme {
+                    1
+                } else {
+                    2
+                }
+            }));
+        }
+        SortKey(bytes)
+    }
+
+    /// Sort key of text in the standard romanization. Whitespace,
+    /// punctuation and other symbols, such as the hyphens of templates, are
+    /// ignored.
+    pub fn key_str(self, text: &str) -> anyhow::Result<SortKey> {
+        let mut phonemes = Vec::new();
+        for ch in text.chars().filter(|ch| ch.is_alphabetic()) {
+            phonemes.push(Phoneme::new(ch).map_err(|_| NotALetter { ch })?);
+        }
+        Ok(self.key(phonemes))
+    }
+
+    pub fn compare<I, J>(self, left: I, right: J) -> Ordering
+    where
+        I: IntoIterator<Item = Phoneme>,
+        J: IntoIterator<Item = Phoneme>,
+    {
+        self.key(left).cmp(&self.key(right))
+    }
+}
+
+/// A sort key: a sequence of bytes which orders like the words it was made
+/// from, and can be stored or compared without this crate.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SortKey(Vec<u8>);
+
+impl SortKey {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(fmt, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialOrd for Phoneme {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Phoneme {
+    fn cmp(&self, other: &Self) -> Ordering {
+        rank(*self).cmp(&rank(*other))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sorted(collation: Collation, words: &[&str]) -> Vec<String> {
+        let mut words =
+            words.iter().map(|&word| word.to_owned()).collect::<Vec<_>>();
+        words.sort_by_key(|word| collation.key_str(word).unwrap());
+        words
+    }
+
+    #[test]
+    fn alphabet_has_every_phoneme_once() {
+        for &phoneme in Phoneme::ALL {
+            let count =
+                ALPHABET.iter().filter(|&&letter| letter == phoneme).count();
+            assert_eq!(count, 1, "{}", phoneme);
+        }
+        assert_eq!(ALPHABET.len(), Phoneme::ALL.len());
+    }
+
+    #[test]
+    fn traditional_order() {
+        assert_eq!(
+            sorted(Collation::TRADITIONAL, &["sa", "bá", "sá", "ba", "s"]),
+            ["ba", "bá", "s", "sa", "sá"]
+        );
+        assert_eq!(
+            sorted(Collation::TRADITIONAL, &["ix", "íx", "xi"]),
+            ["íx", "xi", "ix"]
+        );
+        assert!(Phoneme::B < Phoneme::Aa);
+    }
+
+    #[test]
+    fn length_orderings() {
+        let words = ["íx", "xi", "ix", "ib"];
+        assert_eq!(
+            sorted(Collation::LENGTH_SECONDARY, &words),
+            ["xi", "ib", "ix", "íx"]
+        );
+        let ignored = Collation::LENGTH_IGNORED;
+        assert_eq!(
+            ignored.key_str("íx").unwrap(),
+            ignored.key_str("ix").unwrap()
+        );
+        let key = |text| Collation::LENGTH_SECONDARY.key_str(text).unwrap();
+        assert!(key("sa") < key("sá"));
+        assert!(key("sá") < key("sab"));
+    }
+
+    #[test]
+    fn key_str_skips_symbols() {
+        let collation = Collation::TRADITIONAL;
+        assert_eq!(
+            collation.key_str("-ŕá").unwrap(),
+            collation.key_str("ŕá").unwrap()
+        );
+        assert!(collation.key_str("qa").is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    audio::Clip,
     grammar::grammemes::{
         BasicCase,
         BasicMood,
@@ -11,12 +12,14 @@ use crate::{
         Person,
         Tense,
     },
+    messages::Messages,
     morphology::Morpheme,
+    romanization::Scheme,
+    script,
 };
 use std::fmt;
 use wfts_pedia_ssg::{
     component::{
-        audio::Audio,
         list::UnorderedList,
         table,
         text::{Bold, Italic},
@@ -29,14 +32,17 @@ use wfts_pedia_ssg::{
     fmt::StrExt,
 };
 
+/// Text in the standard romanization, written in the star script. The
+/// letters are encoded in the Private Use Area, so copying the text carries
+/// the script rather than its romanization.
 #[derive(Debug, Clone, Copy)]
-pub struct WithStarAlphabet<T, K>(pub T)
+pub struct WithStarAlphabet<T>(pub T)
 where
-    T: Component<Kind = K>;
+    T: AsRef<str>;
 
-impl<T> Component for WithStarAlphabet<T, InlineComponent>
+impl<T> Component for WithStarAlphabet<T>
 where
-    T: Component<Kind = InlineComponent>,
+    T: AsRef<str> + fmt::Debug,
 {
     type Kind = InlineComponent;
 
@@ -44,23 +50,26 @@ where
         write!(
             fmt,
             "<span class=\"star-alphabet\">{}</span>",
-            ctx.renderer(&self.0)
+            ctx.renderer(script::to_script(self.0.as_ref()))
         )
     }
 }
 
-impl<T> Component for WithStarAlphabet<T, BlockComponent>
+/// Text in the standard romanization, shown in the given scheme.
+#[derive(Debug, Clone)]
+pub struct Romanized<T>(pub Scheme, pub T)
+where
+    T: AsRef<str>;
+
+impl<T> Component for Romanized<T>
 where
-    T: Component<Kind = BlockComponent>,
+    T: AsRef<str> + fmt::Debug,
 {
     type Kind = InlineComponent;
 
     fn to_html(&self, fmt: &mut fmt::Formatter, ctx: Context) -> fmt::Result {
-        write!(
-            fmt,
-            "<div class=\"star-alphabet\">{}</div>",
-            ctx.renderer(&self.0)
-        )
+        let text = self.0.transliterate(self.1.as_ref());
+        write!(fmt, "{}", ctx.renderer(text))
     }
 }
 
@@ -76,8 +85,9 @@ impl Component for DefinitionHead {
     fn to_html(&self, fmt: &mut fmt::Formatter, ctx: Context) -> fmt::Result {
         write!(
             fmt,
-            "<div class=\"definition-head\">{} (inflected for ",
+            "<div class=\"definition-head\">{} ({} ",
             ctx.renderer(Bold(WithStarAlphabet(&self.name))),
+            ctx.renderer(Messages::get(ctx.site().ui_lang).inflected_for),
         )?;
 
         let mut first = true;
@@ -100,7 +110,7 @@ impl Component for DefinitionHead {
 struct PronunciationKey {
     name: String,
     pronunciation: String,
-    audio: Option<Audio>,
+    audio: Option<Clip>,
 }
 
 impl Component for PronunciationKey {
@@ -130,27 +140,28 @@ impl Component for PronunciationKey {
 #[derive(Debug, Clone)]
 pub struct Pronunciation {
     pub morpheme: Morpheme,
-    pub audio_early: Option<Audio>,
-    pub audio_late: Option<Audio>,
+    pub audio_early: Option<Clip>,
+    pub audio_late: Option<Clip>,
 }
 
 impl Component for Pronunciation {
     type Kind = BlockComponent;
 
     fn to_html(&self, fmt: &mut fmt::Formatter, ctx: Context) -> fmt::Result {
+        let messages = Messages::get(ctx.site().ui_lang);
         let mut list = vec![PronunciationKey {
-            name: "Phonemic".to_owned(),
+            name: messages.phonemic.to_owned(),
             pronunciation: format!("/{}/", self.morpheme.to_broad_ipa()),
             audio: None,
         }];
         if let Morpheme::Word(word) = &self.morpheme {
             list.push(PronunciationKey {
-                name: "Early CSL Accents".to_owned(),
+                name: messages.early_accents.to_owned(),
                 pronunciation: format!("[{}]", word.to_early_narrow_ipa()),
                 audio: self.audio_early.clone(),
             });
             list.push(PronunciationKey {
-                name: "Some Late CSL Accents".to_owned(),
+                name: messages.late_accents.to_owned(),
                 pronunciation: format!("[{}]", word.to_late_narrow_ipa()),
                 audio: self.audio_late.clone(),
             });
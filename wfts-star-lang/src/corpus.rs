@@ -0,0 +1,483 @@
+//! The corpus: Star Language texts with a translation of every line, kept
+//! in the `corpus` directory of this crate. A text may be written in any
+//! romanization scheme; it is stored in the standard one.
+
+use crate::{
+    collation::Collation,
+    dictionary::{Analysis, Analyzer},
+    phonology::{phrase::Phrase, Parse, Word},
+    romanization::Scheme,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    fs,
+    iter,
+    path::Path,
+    sync::OnceLock,
+};
+use thiserror::Error;
+use wfts_pedia_ssg::location::Id;
+
+/// Directory of the texts, relative to this crate.
+pub const DIR: &str = "corpus";
+
+/// Extension of the text files.
+pub const EXTENSION: &str = "txt";
+
+/// The texts shipped with the crate, by id, listed by the build script from
+/// the files of [`DIR`].
+const SOURCES: &[(&str, &str)] =
+    include!(concat!(env!("OUT_DIR"), "/corpus.rs"));
+
+#[derive(Debug, Clone, Error)]
+#[error("Invalid text {text} at line {line}: {reason}")]
+pub struct InvalidText {
+    pub text: String,
+    pub line: usize,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Kind {
+    Story,
+    Prayer,
+}
+
+impl Kind {
+    pub const ALL: &'static [Self] = &[Kind::Story, Kind::Prayer];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::Story => "story",
+            Kind::Prayer => "prayer",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|kind| kind.name() == name)
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.name())
+    }
+}
+
+/// A word of a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// Byte offset of the word in the line.
+    pub start: usize,
+    /// The word as written, without the surrounding punctuation.
+    pub surface: String,
+    pub word: Word,
+}
+
+/// A line of a text and its translation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// Number of the line in the text, starting at 1.
+    pub number: usize,
+    pub text: String,
+    pub translation: String,
+    pub tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Text {
+    /// Name of the file without extension.
+    pub id: String,
+    pub title: String,
+    pub kind: Kind,
+    pub lines: Vec<Line>,
+}
+
+impl Text {
+    /// Parses a text. The file starts with `key: value` lines giving the
+    /// `title` and the `kind`, then comes one paragraph per line of the
+    /// text: the line in some romanization scheme followed by its English
+    /// translation. Lines starting with `#` are comments. The scheme is
+    /// detected from all the lines of the text together, and the lines are
+    /// converted to the standard romanization.
+    pub fn parse(id: &str, source: &str) -> anyhow::Result<Self> {
+        let mut title = None;
+        let mut kind = None;
+        let mut paragraphs = Vec::new();
+        let mut pending = None::<(usize, String)>;
+        let mut in_header = true;
+
+        for (i, line) in source.lines().enumerate() {
+            let invalid = |reason: String| InvalidText {
+                text: id.to_owned(),
+                line: i + 1,
+                reason,
+            };
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            if line.is_empty() {
+                in_header = false;
+                if pending.is_some() {
+                    Err(invalid("line without translation".to_owned()))?;
+                }
+                continue;
+            }
+
+            if in_header {
+                let (key, value) = line.split_once(':').ok_or_else(|| {
+                    invalid("expected a `key: value` header".to_owned())
+                })?;
+                match key.trim() {
+                    "title" => title = Some(value.trim().to_owned()),
+                    "kind" => {
+                        let found = Kind::from_name(value.trim());
+                        kind = Some(found.ok_or_else(|| {
+                            invalid(format!("unknown kind {:?}", value.trim()))
+                        })?);
+                    },
+                    key => Err(invalid(format!("unknown header {:?}", key)))?,
+                }
+            } else if let Some((number, text)) = pending.take() {
+                paragraphs.push((number, text, line.to_owned()));
+            } else {
+                pending = Some((i + 1, line.to_owned()));
+            }
+        }
+
+        let body = paragraphs
+            .iter()
+            .map(|(_, text, _)| text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let scheme = Scheme::detect(&body);
+        let mut lines = Vec::new();
+        for (number, text, translation) in paragraphs {
+            let invalid = |error: anyhow::Error| InvalidText {
+                text: id.to_owned(),
+                line: number,
+                reason: format!("{:#}", error),
+            };
+            let tokens = tokenize(&text, scheme).map_err(invalid)?;
+            let text = standardize(&text, &tokens, scheme);
+            let tokens =
+                tokenize(&text, Scheme::Diacritic).map_err(invalid)?;
+            lines.push(Line {
+                number: lines.len() + 1,
+                text,
+                translation,
+                tokens,
+            });
+        }
+
+        let invalid = |reason: &str| InvalidText {
+            text: id.to_owned(),
+            line: source.lines().count(),
+            reason: reason.to_owned(),
+        };
+        if pending.is_some() {
+            Err(invalid("line without translation"))?;
+        }
+        Ok(Self {
+            id: id.to_owned(),
+            title: title.ok_or_else(|| invalid("missing title"))?,
+            kind: kind.ok_or_else(|| invalid("missing kind"))?,
+            lines,
+        })
+    }
+}
+
+/// Splits a line written in the given scheme into words, leaving out
+/// punctuation.
+pub fn tokenize(text: &str, scheme: Scheme) -> anyhow::Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (i, ch) in text.char_indices().chain(iter::once((text.len(), ' '))) {
+        if ch.is_whitespace() || is_punctuation(ch, scheme) {
+            if let Some(start) = start.take() {
+                let surface = &text[start .. i];
+                tokens.push(Token {
+                    start,
+                    surface: surface.to_owned(),
+                    word: Word::parse(&scheme.parse(surface)?)?,
+                });
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// The words of a line spoken together, as a phrase.
+pub fn phrase(tokens: &[Token]) -> Phrase {
+    let words =
+        tokens.iter().map(|token| token.word.clone()).collect::<Vec<_>>();
+    Phrase::new(&words)
+}
+
+/// Rewrites a line in the standard romanization, given its words in the
+/// scheme it is written in. A word keeps its initial capital.
+fn standardize(text: &str, tokens: &[Token], scheme: Scheme) -> String {
+    if scheme == Scheme::Diacritic {
+        return text.to_owned();
+    }
+    let mut output = String::new();
+    let mut end = 0;
+    for token in tokens {
+        output.push_str(&text[end .. token.start]);
+        let word = token.word.to_text();
+        if token.surface.starts_with(char::is_uppercase) {
+            let mut chars = word.chars();
+            output.extend(chars.next().iter().flat_map(|ch| ch.to_uppercase()));
+            output.push_str(chars.as_str());
+        } else {
+            output.push_str(&word);
+        }
+        end = token.start + token.surface.len();
+    }
+    output.push_str(&text[end ..]);
+    output
+}
+
+/// X-SAMPA uses `:` for length and `?` for the glottal stop.
+fn is_punctuation(ch: char, scheme: Scheme) -> bool {
+    match ch {
+        ':' | '?' => scheme != Scheme::XSampa,
+        _ => matches!(ch, '.' | ',' | ';' | '!' | '"' | '(' | ')'),
+    }
+}
+
+/// A word in its context, for a concordance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Concordance<'corpus> {
+    pub text: &'corpus Text,
+    pub line: &'corpus Line,
+    /// Words before the keyword in the line.
+    pub left: String,
+    pub keyword: String,
+    /// Words after the keyword in the line.
+    pub right: String,
+}
+
+/// An occurrence of a word of a dictionary entry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attestation<'corpus> {
+    pub text: &'corpus Text,
+    pub line: &'corpus Line,
+    pub token: &'corpus Token,
+    /// The readings of the word as a form of the entry.
+    pub analyses: Vec<Analysis>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Corpus {
+    pub texts: Vec<Text>,
+}
+
+impl Corpus {
+    /// The texts shipped with the crate.
+    pub fn get() -> &'static Self {
+        static CORPUS: OnceLock<Corpus> = OnceLock::new();
+
+        CORPUS.get_or_init(|| {
+            let texts = SOURCES
+                .iter()
+                .map(|(id, source)| Text::parse(id, source))
+                .collect::<anyhow::Result<_>>();
+            Self { texts: texts.unwrap() }
+        })
+    }
+
+    /// Loads every text in a directory, such as a copy of the corpus being
+    /// edited.
+    pub fn load(dir: &Path) -> anyhow::Result<Self> {
+        let mut paths = Vec::new();
+        for dir_entry in fs::read_dir(dir)? {
+            let path = dir_entry?.path();
+            if path.extension().is_some_and(|ext| ext == EXTENSION) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+
+        let mut texts = Vec::new();
+        for path in paths {
+            let id = path.file_stem().unwrap().to_string_lossy();
+            texts.push(Text::parse(&id, &fs::read_to_string(&path)?)?);
+        }
+        Ok(Self { texts })
+    }
+
+    pub fn text(&self, id: &str) -> Option<&Text> {
+        self.texts.iter().find(|text| text.id == id)
+    }
+
+    /// Every token of the corpus, with the text and line it is in.
+    pub fn tokens(&self) -> impl Iterator<Item = (&Text, &Line, &Token)> {
+        self.texts.iter().flat_map(|text| {
+            text.lines.iter().flat_map(move |line| {
+                line.tokens.iter().map(move |token| (text, line, token))
+            })
+        })
+    }
+
+    /// Word forms and the number of times they occur, the most frequent
+    /// first, ties in alphabetical order.
+    pub fn frequencies(&self) -> Vec<(Word, usize)> {
+        let mut counts = HashMap::<&Word, usize>::new();
+        for (_, _, token) in self.tokens() {
+            *counts.entry(&token.word).or_default() += 1;
+        }
+        let mut frequencies = counts
+            .into_iter()
+            .map(|(word, count)| (word.clone(), count))
+            .collect::<Vec<_>>();
+        frequencies.sort_by(|(left, left_count), (right, right_count)| {
+            right_count.cmp(left_count).then_with(|| {
+                Collation::TRADITIONAL
+                    .compare(left.phonemes(), right.phonemes())
+            })
+        });
+        frequencies
+    }
+
+    /// Word forms which are not in the dictionary, with their frequencies.
+    pub fn unknown_words(&self, analyzer: &Analyzer) -> Vec<(Word, usize)> {
+        let mut frequencies = self.frequencies();
+        frequencies.retain(|(word, _)| analyzer.analyze(word).is_empty());
+        frequencies
+    }
+
+    /// Every occurrence of the word, with up to `context` words around it.
+    pub fn concordance(
+        &self,
+        word: &Word,
+        context: usize,
+    ) -> Vec<Concordance<'_>> {
+        let mut concordance = Vec::new();
+        for text in &self.texts {
+            for line in &text.lines {
+                for (i, token) in line.tokens.iter().enumerate() {
+                    if token.word != *word {
+                        continue;
+                    }
+                    let join = |tokens: &[Token]| {
+                        tokens
+                            .iter()
+                            .map(|token| &token.surface[..])
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    };
+                    let start = i.saturating_sub(context);
+                    let end = line.tokens.len().min(i + 1 + context);
+                    concordance.push(Concordance {
+                        text,
+                        line,
+                        left: join(&line.tokens[start .. i]),
+                        keyword: token.surface.clone(),
+                        right: join(&line.tokens[i + 1 .. end]),
+                    });
+                }
+            }
+        }
+        concordance
+    }
+
+    /// Every occurrence of some form of the entry.
+    pub fn attestations(
+        &self,
+        analyzer: &Analyzer,
+        entry: &Id,
+    ) -> Vec<Attestation<'_>> {
+        let mut attestations = Vec::new();
+        for (text, line, token) in self.tokens() {
+            let analyses = analyzer
+                .analyze(&token.word)
+                .iter()
+                .filter(|analysis| analysis.entry == *entry)
+                .cloned()
+                .collect::<Vec<_>>();
+            if !analyses.is_empty() {
+                attestations.push(Attestation { text, line, token, analyses });
+            }
+        }
+        attestations
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn sources_agree_with_directory() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(DIR);
+        let loaded = Corpus::load(&dir).unwrap();
+        assert_eq!(loaded.texts, Corpus::get().texts);
+    }
+
+    #[test]
+    fn invalid_texts() {
+        let text = Text::parse("t", "title: T\nkind: story\n\nsaŋ.\nStar.\n")
+            .unwrap();
+        assert_eq!(text.lines[0].tokens[0].surface, "saŋ");
+        let tokens = tokenize("kef, jí wiyafa.", Scheme::Diacritic).unwrap();
+        assert_eq!(tokens[1].start, 5);
+        assert_eq!(tokens[2].surface, "wiyafa");
+        assert!(Text::parse("t", "title: T\nkind: poem\n").is_err());
+        assert!(Text::parse("t", "title: T\n\nsaŋ\nStar\n").is_err());
+        let error =
+            Text::parse("t", "title: T\nkind: story\n\nsaŋ\n\n").unwrap_err();
+        let error = error.downcast::<InvalidText>().unwrap();
+        assert_eq!(error.line, 5);
+        assert!(Text::parse("t", "title: T\nkind: story\n\nqa\nQ\n").is_err());
+    }
+
+    #[test]
+    fn other_schemes() {
+        let ascii = "title: T\nkind: story\n\nSang, kef.\nStar, fire.\n";
+        let text = Text::parse("t", ascii).unwrap();
+        assert_eq!(text.lines[0].text, "Saŋ, kef.");
+        assert_eq!(text.lines[0].tokens[1].start, 6);
+        let x_sampa = "title: T\nkind: story\n\nsaN k_hep\\.\nStar fire.\n";
+        let text = Text::parse("t", x_sampa).unwrap();
+        assert_eq!(text.lines[0].text, "saŋ kef.");
+        let kef = Word::parse_str("kef").unwrap();
+        assert_eq!(text.lines[0].tokens[1].word, kef);
+    }
+
+    #[test]
+    fn tools() {
+        let corpus = Corpus::get();
+        let analyzer = Analyzer::get();
+        let kef = Word::parse_str("kef").unwrap();
+        assert_eq!(corpus.frequencies()[0], (kef.clone(), 4));
+
+        let unknown = corpus
+            .unknown_words(analyzer)
+            .into_iter()
+            .map(|(word, _)| word.to_text())
+            .collect::<Vec<_>>();
+        assert_eq!(unknown, ["nawa", "tema"]);
+
+        let lines = corpus.concordance(&kef, 2);
+        assert_eq!(lines[0].left, "");
+        assert_eq!(lines[0].right, "jí wiyafa");
+        assert_eq!(lines[2].left, "fwi nawa");
+
+        let fire = analyzer.analyze(&kef)[0].entry.clone();
+        let attestations = corpus.attestations(analyzer, &fire);
+        assert_eq!(attestations.len(), 5);
+        assert_eq!(attestations[1].token.surface, "keys");
+        assert_eq!(
+            attestations[1].analyses[0].inflection,
+            "postpositional divine singular"
+        );
+    }
+}
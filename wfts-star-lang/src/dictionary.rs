@@ -1,115 +1,399 @@
+pub mod export;
+
 use crate::{
-    component::{DefinitionHead, Pronunciation, WithStarAlphabet},
-    grammar::{adjective, conjunction, noun, postposition, pronoun, verb},
+    audio::Accent,
+    collation::Collation,
+    component::{DefinitionHead, Pronunciation, Romanized, WithStarAlphabet},
+    corpus::{self, Corpus},
+    grammar::{
+        adjective,
+        conjunction,
+        noun,
+        postposition,
+        pronoun,
+        verb,
+        Paradigm,
+        PartOfSpeech,
+    },
+    messages::Messages,
     morphology::Morpheme,
+    pages::texts,
+    phonology::{self, Parse},
+    romanization::Scheme,
+    StarLang,
 };
 use indexmap::IndexMap;
-use std::collections::HashMap;
-use wfts_lang::semantics::Meaning;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    slice,
+    sync::OnceLock,
+};
+use thiserror::Error;
+use wfts_lang::{
+    semantics::Meaning,
+    sound_change::{self, Derivation},
+    Lang,
+};
 use wfts_pedia_ssg::{
     component::{
-        list::OrderedList,
+        list::{OrderedList, UnorderedList},
         table::{self, Table},
+        text::{Link, Paragraph},
         Component,
+        Context,
         DynComponent,
+        InlineComponent,
     },
-    location::Id,
+    i18n::UiLang,
+    location::{Id, Location},
     page::Section,
 };
 
+/// Register of a word, as between the informal and formal personal
+/// pronouns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Register {
+    Informal,
+    Formal,
+}
+
+impl Register {
+    pub fn name(self) -> &'static str {
+        match self {
+            Register::Informal => "informal",
+            Register::Formal => "formal",
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.name())
+    }
+}
+
+/// A morpheme referred to by an entry, linking to its dictionary page when
+/// it has one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    pub morpheme: Morpheme,
+    /// Id of the entry of the morpheme, if it is in the dictionary.
+    pub entry: Option<Id>,
+}
+
+impl Reference {
+    /// Reference to the entry with the given id and headword.
+    pub fn entry(headword: &str, id: &str) -> Self {
+        Self {
+            morpheme: phonology::Word::parse_str(headword).unwrap().into(),
+            entry: Some(Id::new(id).unwrap()),
+        }
+    }
+
+    /// The morpheme in the star alphabet, followed by its romanization in
+    /// the given scheme.
+    pub fn component(&self, scheme: Scheme) -> DynComponent<InlineComponent> {
+        let text = WithStarAlphabet(self.morpheme.to_text());
+        let written = match &self.entry {
+            Some(entry) => {
+                Link { location: lexeme_location(entry), text }.to_dyn()
+            },
+            None => text.to_dyn(),
+        };
+        vec![
+            written,
+            " (".to_dyn(),
+            Romanized(scheme, self.morpheme.to_text()).to_dyn(),
+            ")".to_dyn(),
+        ]
+        .to_dyn()
+    }
+}
+
+/// Where a word comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Etymology {
+    /// The morphemes the word was formed from, in order.
+    pub sources: Vec<Reference>,
+    pub explanation: String,
+}
+
+impl Etymology {
+    /// The sources, romanized in the given scheme, followed by the
+    /// explanation.
+    pub fn component(
+        &self,
+        ui_lang: UiLang,
+        scheme: Scheme,
+    ) -> DynComponent<InlineComponent> {
+        let mut parts = Vec::new();
+        if !self.sources.is_empty() {
+            parts.push(Messages::get(ui_lang).from.to_dyn());
+            for (i, source) in self.sources.iter().enumerate() {
+                if i > 0 {
+                    parts.push(" + ".to_dyn());
+                }
+                parts.push(source.component(scheme));
+            }
+            parts.push(". ".to_dyn());
+        }
+        parts.push(self.explanation.clone().to_dyn());
+        parts.to_dyn()
+    }
+}
+
+/// An example sentence, in the standard romanization.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub text: String,
+    pub translation: String,
+}
+
+impl Example {
+    /// The sentence in the star alphabet, romanized in the given scheme and
+    /// transcribed, followed by its translation.
+    pub fn component(&self, scheme: Scheme) -> DynComponent<InlineComponent> {
+        let tokens = corpus::tokenize(&self.text, Scheme::Diacritic)
+            .expect("examples are checked by the tests");
+        vec![
+            WithStarAlphabet(self.text.clone()).to_dyn(),
+            " (".to_dyn(),
+            Romanized(scheme, self.text.clone()).to_dyn(),
+            format!(
+                ") [{}] “{}”",
+                corpus::phrase(&tokens).to_early_narrow_ipa(),
+                self.translation
+            )
+            .to_dyn(),
+        ]
+        .to_dyn()
+    }
+}
+
+/// Information about an entry besides its meanings and inflection. Each
+/// field which is present becomes a section of the entry.
+#[derive(Debug, Clone, Default)]
+pub struct Notes {
+    pub etymology: Option<Etymology>,
+    pub usage: Option<DynComponent>,
+    pub register: Option<Register>,
+    pub examples: Vec<Example>,
+    pub see_also: Vec<Reference>,
+}
+
+impl Notes {
+    /// Sections of the fields, with ids made from the id of the entry, such
+    /// as `{id}-etymology` and `{id}-see-also`.
+    pub fn sections(
+        &self,
+        id: &Id,
+        ui_lang: UiLang,
+        scheme: Scheme,
+    ) -> Vec<Section> {
+        let messages = Messages::get(ui_lang);
+        let section = |title: &'static str, suffix: &str, body| Section {
+            title: title.to_dyn(),
+            id: Id::new(format!("{}-{}", id.as_str(), suffix)).unwrap(),
+            body,
+            children: vec![],
+        };
+        let mut sections = Vec::new();
+
+        if let Some(etymology) = &self.etymology {
+            sections.push(section(
+                messages.etymology,
+                "etymology",
+                Paragraph(etymology.component(ui_lang, scheme)).to_dyn(),
+            ));
+        }
+        if let Some(usage) = &self.usage {
+            sections.push(section(messages.usage, "usage", usage.clone()));
+        }
+        if let Some(register) = self.register {
+            sections.push(section(
+                messages.register,
+                "register",
+                Paragraph(format!("{}{}.", messages.this_word_is, register))
+                    .to_dyn(),
+            ));
+        }
+        if !self.examples.is_empty() {
+            sections.push(section(
+                messages.examples,
+                "examples",
+                UnorderedList(
+                    self.examples
+                        .iter()
+                        .map(|example| example.component(scheme))
+                        .collect(),
+                )
+                .to_dyn(),
+            ));
+        }
+        if !self.see_also.is_empty() {
+            sections.push(section(
+                messages.see_also,
+                "see-also",
+                UnorderedList(
+                    self.see_also
+                        .iter()
+                        .map(|reference| reference.component(scheme))
+                        .collect(),
+                )
+                .to_dyn(),
+            ));
+        }
+
+        sections
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Entry {
     pub id: Id,
     pub class: String,
+    pub part_of_speech: PartOfSpeech,
     pub inflections: IndexMap<String, Morpheme>,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
-    pub inflection_table: table::Entries<DynComponent>,
+    pub notes: Notes,
+    pub paradigm: Paradigm,
 }
 
 impl Entry {
-    pub fn sections(self) -> Vec<(Morpheme, Section)> {
-        let mut map = HashMap::new();
-        for (key, morpheme) in self.inflections {
-            let vec = map.entry(morpheme.clone()).or_insert(Vec::new());
-            vec.push(key);
+    pub fn headword(&self) -> &Morpheme {
+        let (_, morpheme) = self.inflections.get_index(0).unwrap();
+        morpheme
+    }
+
+    /// The grammemes of each distinct form of the entry, in the order the
+    /// forms first appear.
+    pub fn forms(&self) -> IndexMap<&Morpheme, Vec<&str>> {
+        let mut forms = IndexMap::<_, Vec<_>>::new();
+        for (key, morpheme) in &self.inflections {
+            forms.entry(morpheme).or_default().push(&key[..]);
         }
+        forms
+    }
+
+    /// Inflection table of the entry, with the forms romanized in the given
+    /// scheme.
+    pub fn inflection_table(
+        &self,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
+        let Morpheme::Word(lemma) = self.headword() else {
+            panic!("headword of {} is not a word", self.id)
+        };
+        self.paradigm
+            .table(lemma.clone(), &self.id, scheme)
+            .expect("inflections were built from the headword")
+    }
 
+    fn meanings_list(&self, ui_lang: UiLang) -> DynComponent {
         let meanings = self
             .meanings
-            .into_iter()
-            .map(|def| def.description())
+            .iter()
+            .map(|def| def.description_in(ui_lang))
             .collect::<Vec<_>>();
-        let mut sections = Vec::new();
+        OrderedList(meanings).to_dyn()
+    }
+
+    /// Section of the canonical page of the entry. Its id and the ids of its
+    /// children all start with the id of the entry.
+    pub fn lexeme_section(
+        &self,
+        label: &Label,
+        ui_lang: UiLang,
+        scheme: Scheme,
+    ) -> Section {
+        let messages = Messages::get(ui_lang);
+        let headword = self.headword();
+        let inflected_for = self.forms()[headword]
+            .iter()
+            .map(|&key| key.to_owned())
+            .collect();
+        let head = DefinitionHead { name: headword.to_text(), inflected_for };
 
-        for (morpheme, inflected_for) in map {
-            let head =
-                DefinitionHead { name: morpheme.to_text(), inflected_for };
-
-            let romanization = Section {
-                title: "Romanization".to_dyn(),
-                id: Id::new(format!("{}-roman", self.id.as_str())).unwrap(),
-                body: morpheme.to_text().blocking().to_dyn(),
-                children: vec![],
-            };
-
-            let pronunciation = Section {
-                title: "Pronunciation".to_dyn(),
-                id: Id::new(format!("{}-pronunciation", self.id.as_str()))
-                    .unwrap(),
-                body: match &morpheme {
-                    Morpheme::Template(_) => Pronunciation {
-                        morpheme: morpheme.clone(),
-                        audio_early: None,
-                        audio_late: None,
-                    },
-                    Morpheme::Word(word) => Pronunciation {
-                        morpheme: morpheme.clone(),
-                        audio_early: word.audio_early(),
-                        audio_late: word.audio_late(),
-                    },
+        let mut children = form_sections(headword, self.id.as_str(), ui_lang);
+        children.extend(self.notes.sections(&self.id, ui_lang, scheme));
+        children.push(Section {
+            title: messages.inflection.to_dyn(),
+            id: Id::new(format!("{}-inflection", self.id.as_str())).unwrap(),
+            body: vec![
+                self.class.clone().blocking().to_dyn(),
+                Table {
+                    title: vec![
+                        messages.inflection_for.to_dyn(),
+                        label.clone().to_dyn(),
+                        ".".to_dyn(),
+                    ]
+                    .to_dyn(),
+                    entries: self.inflection_table(scheme),
                 }
                 .to_dyn(),
-                children: vec![],
-            };
-
-            let inflection = Section {
-                title: "Inflection".to_dyn(),
-                id: Id::new(format!("{}-inflection", self.id.as_str()))
-                    .unwrap(),
-                body: vec![
-                    self.class.clone().blocking().to_dyn(),
-                    Table {
-                        title: vec![
-                            "Inflection for ".to_dyn(),
-                            WithStarAlphabet(morpheme.to_text()).to_dyn(),
-                            ".".to_dyn(),
-                        ]
-                        .to_dyn(),
-                        entries: self.inflection_table.clone(),
+            ]
+            .to_dyn(),
+            children: vec![],
+        });
+        children.push(Section {
+            title: messages.attested_in.to_dyn(),
+            id: Id::new(format!("{}-attested", self.id.as_str())).unwrap(),
+            body: attestations(&self.id, ui_lang, scheme),
+            children: vec![],
+        });
+
+        Section {
+            title: messages.definition.to_dyn(),
+            id: self.id.clone(),
+            body: vec![head.to_dyn(), self.meanings_list(ui_lang)].to_dyn(),
+            children,
+        }
+    }
+
+    /// Section of the page of one form of the entry, linking to the
+    /// canonical page. Its id is the id of the entry.
+    pub fn form_section(
+        &self,
+        label: &Label,
+        morpheme: &Morpheme,
+        ui_lang: UiLang,
+    ) -> Section {
+        let messages = Messages::get(ui_lang);
+        let inflected_for = self.forms()[morpheme]
+            .iter()
+            .map(|&key| key.to_owned())
+            .collect();
+        let head = DefinitionHead { name: morpheme.to_text(), inflected_for };
+
+        Section {
+            title: vec![
+                label.clone().to_dyn(),
+                format!(
+                    " ({})",
+                    messages.part_of_speech(self.part_of_speech)
+                )
+                .to_dyn(),
+            ]
+            .to_dyn(),
+            id: self.id.clone(),
+            body: vec![
+                head.to_dyn(),
+                self.meanings_list(ui_lang),
+                Paragraph(vec![
+                    messages.see_the.to_dyn(),
+                    Link {
+                        location: lexeme_location(&self.id),
+                        text: messages.full_entry,
                     }
                     .to_dyn(),
-                ]
+                    ".".to_dyn(),
+                ])
                 .to_dyn(),
-                children: vec![],
-            };
-
-            let section = Section {
-                title: "Definition".to_dyn(),
-                id: self.id.clone(),
-                body: vec![
-                    head.to_dyn(),
-                    OrderedList(meanings.clone()).to_dyn(),
-                    self.notes.clone(),
-                ]
-                .to_dyn(),
-                children: vec![romanization, pronunciation, inflection],
-            };
-
-            sections.push((morpheme, section));
+            ]
+            .to_dyn(),
+            children: vec![],
         }
-
-        sections
     }
 
     pub fn all() -> Vec<Self> {
@@ -123,24 +407,492 @@ impl Entry {
     }
 }
 
+/// Sections about a form itself: romanization, pronunciation and sound
+/// changes. Their ids start with `prefix`.
+fn form_sections(
+    morpheme: &Morpheme,
+    prefix: &str,
+    ui_lang: UiLang,
+) -> Vec<Section> {
+    let messages = Messages::get(ui_lang);
+    let id = |suffix: &str| Id::new(format!("{}-{}", prefix, suffix)).unwrap();
+
+    let romanization = Section {
+        title: messages.romanization.to_dyn(),
+        id: id("roman"),
+        body: UnorderedList(
+            Scheme::ALL
+                .iter()
+                .map(|scheme| {
+                    format!(
+                        "{}: {}",
+                        scheme,
+                        scheme.transliterate(&morpheme.to_text())
+                    )
+                })
+                .collect(),
+        )
+        .to_dyn(),
+        children: vec![],
+    };
+
+    let pronunciation = Section {
+        title: messages.pronunciation.to_dyn(),
+        id: id("pronunciation"),
+        body: match morpheme {
+            Morpheme::Template(_) => Pronunciation {
+                morpheme: morpheme.clone(),
+                audio_early: None,
+                audio_late: None,
+            },
+            Morpheme::Word(word) => Pronunciation {
+                morpheme: morpheme.clone(),
+                audio_early: Some(word.clip(Accent::Early)),
+                audio_late: Some(word.clip(Accent::Late)),
+            },
+        }
+        .to_dyn(),
+        children: vec![],
+    };
+
+    let mut sections = vec![romanization, pronunciation];
+    if let Morpheme::Word(word) = morpheme {
+        sections.push(Section {
+            title: messages.sound_changes.to_dyn(),
+            id: id("sound-changes"),
+            body: Table {
+                title: vec![
+                    messages.derivation_of.to_dyn(),
+                    WithStarAlphabet(morpheme.to_text()).to_dyn(),
+                    ".".to_dyn(),
+                ]
+                .to_dyn(),
+                entries: derivation_table(word, ui_lang),
+            }
+            .to_dyn(),
+            children: vec![],
+        });
+    }
+    sections
+}
+
+/// List of the lines of the corpus where a form of the entry occurs.
+fn attestations(entry: &Id, ui_lang: UiLang, scheme: Scheme) -> DynComponent {
+    let messages = Messages::get(ui_lang);
+    let attestations = Corpus::get().attestations(Analyzer::get(), entry);
+    if attestations.is_empty() {
+        return Paragraph(messages.not_attested).to_dyn();
+    }
+    let items = attestations
+        .into_iter()
+        .map(|attestation| {
+            let inflections = attestation
+                .analyses
+                .iter()
+                .map(|analysis| &analysis.inflection[..])
+                .collect::<Vec<_>>();
+            vec![
+                Link {
+                    location: texts::line_location(
+                        attestation.text,
+                        attestation.line,
+                    ),
+                    text: format!(
+                        "{}, {} {}",
+                        attestation.text.title,
+                        messages.line,
+                        attestation.line.number
+                    ),
+                }
+                .to_dyn(),
+                ": ".to_dyn(),
+                Romanized(scheme, attestation.line.text.clone()).to_dyn(),
+                format!(
+                    " “{}” ({}: {})",
+                    attestation.line.translation,
+                    attestation.token.surface,
+                    inflections.join(&format!(" {} ", messages.or))
+                )
+                .to_dyn(),
+            ]
+        })
+        .collect();
+    UnorderedList(items).to_dyn()
+}
+
+/// Table with one row per sound change applied to the word, grouped by
+/// stage.
+fn derivation_table(
+    word: &phonology::Word,
+    ui_lang: UiLang,
+) -> table::Entries<String> {
+    let messages = Messages::get(ui_lang);
+    let Derivation { input, steps } = word.derivation();
+
+    let header = |data: &str| table::Entry {
+        header: true,
+        ..table::Entry::new(data.to_owned())
+    };
+
+    let mut entries = vec![
+        vec![
+            header(messages.stage),
+            header(messages.rule),
+            header(messages.result),
+        ],
+        vec![
+            header(messages.phonemic),
+            table::Entry::new(String::new()),
+            table::Entry::new(format!("/{}/", sound_change::render(&input))),
+        ],
+    ];
+
+    let mut prev_stage = None;
+    for step in &steps {
+        let mut row = Vec::new();
+        if prev_stage != Some(&step.stage) {
+            let rowspan = steps
+                .iter()
+                .skip_while(|other| other.stage != step.stage)
+                .take_while(|other| other.stage == step.stage)
+                .count();
+            row.push(table::Entry {
+                rowspan: rowspan as u32,
+                ..header(&step.stage)
+            });
+            prev_stage = Some(&step.stage);
+        }
+        row.push(table::Entry::new(step.rule.clone()));
+        row.push(table::Entry::new(format!(
+            "[{}]",
+            sound_change::render(&step.form)
+        )));
+        entries.push(row);
+    }
+
+    entries
+}
+
+/// Location of the canonical page of an entry.
+pub fn lexeme_location(id: &Id) -> Location {
+    Location::internal(format!(
+        "{}/dictionary/{}/{}",
+        StarLang.path(),
+        LEXEME_DIR,
+        id
+    ))
+}
+
+/// Directory of the canonical pages of the entries, inside of the
+/// dictionary. Its name is not a valid word, so it cannot clash with the
+/// page of a form.
+pub const LEXEME_DIR: &str = "lexemes";
+
+#[derive(Debug, Clone, Error)]
+#[error("Entry id {id} is used by more than one entry")]
+pub struct DuplicateEntryId {
+    pub id: Id,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Section id {id} is used more than once in the page of {page}")]
+pub struct DuplicateSectionId {
+    pub page: String,
+    pub id: Id,
+}
+
+/// Fails if two sections, at any depth, have the same id.
+fn check_section_ids(page: &str, sections: &[Section]) -> anyhow::Result<()> {
+    fn collect<'section>(
+        sections: &'section [Section],
+        ids: &mut Vec<&'section Id>,
+    ) {
+        for section in sections {
+            ids.push(&section.id);
+            collect(&section.children, ids);
+        }
+    }
+
+    let mut ids = Vec::new();
+    collect(sections, &mut ids);
+    let mut seen = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) {
+            Err(DuplicateSectionId { page: page.to_owned(), id: id.clone() })?;
+        }
+    }
+    Ok(())
+}
+
+/// Name of an entry: its headword, numbered when other entries have the
+/// same headword.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label {
+    pub headword: Morpheme,
+    pub homograph: Option<usize>,
+}
+
+impl Label {
+    /// Labels of the entries, in the same order. Homographs are numbered
+    /// from 1 in the order of the entries.
+    pub fn of_all(entries: &[Entry]) -> Vec<Self> {
+        let mut totals = HashMap::<&Morpheme, usize>::new();
+        for entry in entries {
+            *totals.entry(entry.headword()).or_default() += 1;
+        }
+        let mut counts = HashMap::<&Morpheme, usize>::new();
+        entries
+            .iter()
+            .map(|entry| {
+                let headword = entry.headword();
+                let count = counts.entry(headword).or_default();
+                *count += 1;
+                Label {
+                    headword: headword.clone(),
+                    homograph: Some(*count).filter(|_| totals[headword] > 1),
+                }
+            })
+            .collect()
+    }
+
+    /// The headword in the standard romanization, followed by the homograph
+    /// number in superscript digits.
+    pub fn to_text(&self) -> String {
+        let mut text = self.headword.to_text();
+        if let Some(number) = self.homograph {
+            text.extend(number.to_string().chars().map(superscript));
+        }
+        text
+    }
+}
+
+fn superscript(digit: char) -> char {
+    match digit {
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        _ => char::from_u32(0x2070 + digit.to_digit(10).unwrap()).unwrap(),
+    }
+}
+
+impl Component for Label {
+    type Kind = InlineComponent;
+
+    fn to_html(&self, fmt: &mut fmt::Formatter, ctx: Context) -> fmt::Result {
+        write!(
+            fmt,
+            "{}",
+            ctx.renderer(WithStarAlphabet(self.headword.to_text()))
+        )?;
+        if let Some(number) = self.homograph {
+            write!(fmt, "<sup>{}</sup>", number)?;
+        }
+        Ok(())
+    }
+}
+
+/// The canonical page of an entry.
+#[derive(Debug, Clone)]
+pub struct LexemePage {
+    pub id: Id,
+    pub label: Label,
+    pub sections: Vec<Section>,
+}
+
+/// The page of a form, listing every entry with that form.
+#[derive(Debug, Clone)]
+pub struct FormPage {
+    pub morpheme: Morpheme,
+    pub sections: Vec<Section>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Dictionary {
-    pub sections: HashMap<Morpheme, Vec<Section>>,
+    /// In the order of the entries.
+    pub lexemes: Vec<LexemePage>,
+    /// In alphabetical order.
+    pub forms: Vec<FormPage>,
 }
 
 impl Dictionary {
-    pub fn with_all_entries() -> Self {
-        Self::from_entries(Entry::all())
+    pub fn with_all_entries(
+        ui_lang: UiLang,
+        scheme: Scheme,
+    ) -> anyhow::Result<Self> {
+        Self::from_entries(&Entry::all(), ui_lang, scheme)
+    }
+
+    /// Builds the pages of the entries. Fails if entry ids are not unique
+    /// or if a page would have two sections with the same id.
+    pub fn from_entries(
+        entries: &[Entry],
+        ui_lang: UiLang,
+        scheme: Scheme,
+    ) -> anyhow::Result<Self> {
+        let mut ids = HashSet::new();
+        for entry in entries {
+            if !ids.insert(&entry.id) {
+                Err(DuplicateEntryId { id: entry.id.clone() })?;
+            }
+        }
+
+        let labels = Label::of_all(entries);
+        let mut lexemes = Vec::new();
+        let mut forms = BTreeMap::<_, Vec<_>>::new();
+        for (entry, label) in entries.iter().zip(&labels) {
+            let section = entry.lexeme_section(label, ui_lang, scheme);
+            check_section_ids(&label.to_text(), slice::from_ref(&section))?;
+            lexemes.push(LexemePage {
+                id: entry.id.clone(),
+                label: label.clone(),
+                sections: vec![section],
+            });
+            for &morpheme in entry.forms().keys() {
+                let key = (
+                    Collation::TRADITIONAL.key(morpheme.phonemes()),
+                    morpheme.clone(),
+                );
+                let section = entry.form_section(label, morpheme, ui_lang);
+                forms.entry(key).or_default().push(section);
+            }
+        }
+
+        let mut pages = Vec::new();
+        for ((_, morpheme), mut sections) in forms {
+            sections.extend(form_sections(&morpheme, "form", ui_lang));
+            check_section_ids(&morpheme.to_text(), &sections)?;
+            pages.push(FormPage { morpheme, sections });
+        }
+
+        Ok(Self { lexemes, forms: pages })
+    }
+}
+
+/// One way of reading a word: as an inflection of a dictionary entry.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Analysis {
+    /// Id of the entry.
+    pub entry: Id,
+    pub headword: Morpheme,
+    /// The grammemes the word is inflected for, as in `Entry::inflections`.
+    pub inflection: String,
+}
+
+/// Morphological analyzer: finds the entries and inflections a word form
+/// belongs to.
+#[derive(Debug, Clone, Default)]
+pub struct Analyzer {
+    forms: HashMap<phonology::Word, Vec<Analysis>>,
+}
+
+impl Analyzer {
+    /// Analyzer over all entries of the dictionary.
+    pub fn get() -> &'static Self {
+        static ANALYZER: OnceLock<Analyzer> = OnceLock::new();
+
+        ANALYZER.get_or_init(|| Self::new(&Entry::all()))
     }
 
-    pub fn from_entries(entries: Vec<Entry>) -> Self {
-        let mut this = Self { sections: HashMap::new() };
+    pub fn new(entries: &[Entry]) -> Self {
+        let mut this = Self::default();
         for entry in entries {
-            for (morpheme, section) in entry.sections() {
-                let vec = this.sections.entry(morpheme).or_insert(Vec::new());
-                vec.push(section);
+            for (inflection, morpheme) in &entry.inflections {
+                if let Morpheme::Word(word) = morpheme {
+                    let analyses = this.forms.entry(word.clone()).or_default();
+                    analyses.push(Analysis {
+                        entry: entry.id.clone(),
+                        headword: entry.headword().clone(),
+                        inflection: inflection.clone(),
+                    });
+                }
             }
         }
         this
     }
+
+    /// Every reading of the word, in dictionary order. Empty if the word is
+    /// not in the dictionary.
+    pub fn analyze(&self, word: &phonology::Word) -> &[Analysis] {
+        self.forms.get(word).map_or(&[], |analyses| &analyses[..])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::corpus;
+
+    #[test]
+    fn notes_refer_to_entries() {
+        let entries = Entry::all();
+        for entry in &entries {
+            let notes = &entry.notes;
+            let sources = notes.etymology.iter().flat_map(|etym| &etym.sources);
+            for reference in sources.chain(&notes.see_also) {
+                let Some(id) = &reference.entry else { continue };
+                let found = entries.iter().find(|other| other.id == *id);
+                assert_eq!(
+                    found.map(Entry::headword),
+                    Some(&reference.morpheme),
+                    "{}",
+                    entry.id
+                );
+            }
+            for example in &notes.examples {
+                let tokens =
+                    corpus::tokenize(&example.text, Scheme::Diacritic).unwrap();
+                for token in tokens {
+                    let analyses = Analyzer::get().analyze(&token.word);
+                    assert!(!analyses.is_empty(), "{}", token.surface);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn homographs_get_numbers_and_share_form_pages() {
+        let mut entries = Entry::all();
+        let star = entries.iter().find(|entry| entry.id.as_str() == "star");
+        let mut homograph = star.unwrap().clone();
+        homograph.id = Id::new("star-again").unwrap();
+        entries.push(homograph.clone());
+
+        let labels = Label::of_all(&entries);
+        let texts = labels
+            .iter()
+            .map(Label::to_text)
+            .filter(|text| text.starts_with("saŋ"))
+            .collect::<Vec<_>>();
+        assert_eq!(texts, ["saŋ¹", "saŋ²"]);
+
+        let scheme = Scheme::default();
+        let dict = Dictionary::from_entries(&entries, UiLang::English, scheme)
+            .unwrap();
+        let page = dict
+            .forms
+            .iter()
+            .find(|page| page.morpheme.to_text() == "saŋ")
+            .unwrap();
+        let ids = page
+            .sections
+            .iter()
+            .map(|section| section.id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(ids[.. 2], ["star", "star-again"]);
+        let morphemes = dict
+            .forms
+            .iter()
+            .map(|page| page.morpheme.clone())
+            .collect::<Vec<_>>();
+        let mut sorted = morphemes.clone();
+        sorted.sort_by_key(|morpheme| {
+            Collation::TRADITIONAL.key(morpheme.phonemes())
+        });
+        assert_eq!(morphemes, sorted);
+
+        entries.push(homograph);
+        let error = Dictionary::from_entries(&entries, UiLang::English, scheme)
+            .unwrap_err();
+        assert!(error.downcast_ref::<DuplicateEntryId>().is_some());
+    }
 }
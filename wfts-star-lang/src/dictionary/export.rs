@@ -0,0 +1,725 @@
+use crate::{
+    collation::Collation,
+    dictionary::Entry,
+    grammar::PartOfSpeech,
+    morphology::Morpheme,
+    script,
+    StarLang,
+};
+use anyhow::Context;
+use indexmap::IndexMap;
+use std::{
+    cmp::Ordering,
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+use wfts_lang::{
+    semantics::{registry::Registry, Meaning},
+    Lang,
+};
+use wfts_pedia_ssg::location::Id;
+
+/// Code used for Classical Star Language forms in the exported files.
+pub const LANG_CODE: &str = "str-cls";
+
+/// Code used for forms written in the star script, with a private use
+/// script subtag.
+pub const SCRIPT_LANG_CODE: &str = "str-cls-Qaaa";
+
+/// Base name of the exported files.
+pub const BASENAME: &str = "str-cls-dictionary";
+
+/// Directory of the exported files, relative to the language subsite.
+pub const DIR: &str = "dictionary/export";
+
+/// Directory of the headword images, relative to the export directory.
+pub const SVG_DIR: &str = "script";
+
+/// Graphviz DOT graph of the semantic network, relative to the export
+/// directory.
+pub const NETWORK_FILE: &str = "str-cls-semantic-network.dot";
+
+/// Separates the meanings of a lexeme, and their descriptions, within a
+/// single CSV/TSV field. Descriptions may contain semicolons, so a bar is
+/// used instead.
+pub const LIST_SEPARATOR: &str = " | ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Format {
+    Lift,
+    Csv,
+    Tsv,
+    Json,
+    Dictd,
+    StarDict,
+}
+
+impl Format {
+    pub const ALL: &'static [Self] = &[
+        Format::Lift,
+        Format::Csv,
+        Format::Tsv,
+        Format::Json,
+        Format::Dictd,
+        Format::StarDict,
+    ];
+
+    /// Paths of the files written by this format, relative to the export
+    /// directory.
+    pub fn files(self) -> Vec<String> {
+        match self {
+            Format::Lift => vec![format!("{}.lift", BASENAME)],
+            Format::Csv => vec![format!("{}.csv", BASENAME)],
+            Format::Tsv => vec![format!("{}.tsv", BASENAME)],
+            Format::Json => vec![format!("{}.json", BASENAME)],
+            Format::Dictd => vec![
+                format!("dictd/{}.index", BASENAME),
+                format!("dictd/{}.dict", BASENAME),
+            ],
+            Format::StarDict => vec![
+                format!("stardict/{}.ifo", BASENAME),
+                format!("stardict/{}.idx", BASENAME),
+                format!("stardict/{}.dict", BASENAME),
+            ],
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Format::Lift => "LIFT XML",
+            Format::Csv => "CSV",
+            Format::Tsv => "TSV",
+            Format::Json => "JSON",
+            Format::Dictd => "dictd",
+            Format::StarDict => "StarDict",
+        }
+    }
+
+    pub fn export(self, records: &[Record], dir: &Path) -> anyhow::Result<()> {
+        let mut outputs = Vec::new();
+        match self {
+            Format::Lift => {
+                let mut buf = Vec::new();
+                write_lift(records, &mut buf)?;
+                outputs.push(buf);
+            },
+            Format::Csv => {
+                let mut buf = Vec::new();
+                write_separated(records, b',', &mut buf)?;
+                outputs.push(buf);
+            },
+            Format::Tsv => {
+                let mut buf = Vec::new();
+                write_separated(records, b'\t', &mut buf)?;
+                outputs.push(buf);
+            },
+            Format::Json => {
+                let mut buf = Vec::new();
+                write_json(records, &mut buf)?;
+                outputs.push(buf);
+            },
+            Format::Dictd => {
+                let mut index = Vec::new();
+                let mut dict = Vec::new();
+                write_dictd(records, &mut index, &mut dict)?;
+                outputs.push(index);
+                outputs.push(dict);
+            },
+            Format::StarDict => {
+                let mut ifo = Vec::new();
+                let mut idx = Vec::new();
+                let mut dict = Vec::new();
+                write_stardict(records, &mut ifo, &mut idx, &mut dict)?;
+                outputs.push(ifo);
+                outputs.push(idx);
+                outputs.push(dict);
+            },
+        }
+
+        for (file, contents) in self.files().into_iter().zip(outputs) {
+            let path = dir.join(file);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).with_context(|| {
+                    format!("Creating dir {}", parent.display())
+                })?;
+            }
+            fs::write(&path, contents).with_context(|| {
+                format!("Exporting {} to {}", self.name(), path.display())
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Exports the given entries in every supported format into `dir`.
+pub fn export_all(entries: &[Entry], dir: &Path) -> anyhow::Result<()> {
+    let records = entries.iter().map(Record::from_entry).collect::<Vec<_>>();
+    for &format in Format::ALL {
+        format.export(&records, dir)?;
+    }
+    export_svgs(&records, &dir.join(SVG_DIR))?;
+    export_network(dir)
+}
+
+/// Writes the semantic network of the language's meanings as a Graphviz DOT
+/// graph.
+pub fn export_network(dir: &Path) -> anyhow::Result<()> {
+    let mut registry = Registry::with_shared();
+    StarLang.register_meanings(&mut registry)?;
+    let path = dir.join(NETWORK_FILE);
+    fs::write(&path, registry.network().to_dot())
+        .with_context(|| format!("Writing {}", path.display()))
+}
+
+/// Draws every headword in the star script as an SVG image named after its
+/// romanization.
+pub fn export_svgs(records: &[Record], dir: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Creating dir {}", dir.display()))?;
+    for record in records {
+        let headword = record.headword.to_text();
+        let svg = script::Font::get().to_svg(&headword)?;
+        let path = dir.join(format!("{}.svg", headword));
+        fs::write(&path, svg)
+            .with_context(|| format!("Writing {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// A lexicon record, independent of how the entry is rendered in the site.
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub id: Id,
+    pub headword: Morpheme,
+    pub part_of_speech: PartOfSpeech,
+    pub class: String,
+    pub meanings: Vec<Meaning>,
+    pub inflections: IndexMap<String, Morpheme>,
+}
+
+impl Record {
+    pub fn from_entry(entry: &Entry) -> Self {
+        Self {
+            id: entry.id.clone(),
+            headword: entry.headword().clone(),
+            part_of_speech: entry.part_of_speech,
+            class: entry.class.clone(),
+            meanings: entry.meanings.clone(),
+            inflections: entry.inflections.clone(),
+        }
+    }
+
+    /// Plain text definition, used by the dictionary-application formats.
+    pub fn to_plain_text(&self) -> String {
+        let mut output = String::new();
+        writeln!(output, "{}", self.headword).unwrap();
+        writeln!(output, "/{}/", self.headword.to_broad_ipa()).unwrap();
+        writeln!(output, "{}, {}", self.part_of_speech, self.class).unwrap();
+        writeln!(output).unwrap();
+        for (i, meaning) in self.meanings.iter().enumerate() {
+            writeln!(
+                output,
+                "{}. {} — {}",
+                i + 1,
+                meaning.identifier(),
+                meaning.description()
+            )
+            .unwrap();
+        }
+        writeln!(output).unwrap();
+        writeln!(output, "Inflection:").unwrap();
+        for (grammemes, morpheme) in &self.inflections {
+            writeln!(output, "  {}: {}", grammemes, morpheme).unwrap();
+        }
+        output
+    }
+}
+
+pub fn write_lift<W>(records: &[Record], mut output: W) -> io::Result<()>
+where
+    W: Write,
+{
+    writeln!(output, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(output, "<lift version=\"0.13\" producer=\"wfts-star-lang\">")?;
+    for record in records {
+        writeln!(output, "<entry id=\"{}\">", xml_escape(record.id.as_str()))?;
+        writeln!(
+            output,
+            "<lexical-unit><form lang=\"{}\"><text>{}</text></form><form \
+             lang=\"{}\"><text>{}</text></form></lexical-unit>",
+            LANG_CODE,
+            xml_escape(&record.headword.to_text()),
+            SCRIPT_LANG_CODE,
+            xml_escape(&script::to_script(&record.headword.to_text())),
+        )?;
+        writeln!(
+            output,
+            "<pronunciation><form lang=\"{}-fonipa\"><text>{}</text></form>\
+             </pronunciation>",
+            LANG_CODE,
+            xml_escape(&record.headword.to_broad_ipa()),
+        )?;
+        writeln!(
+            output,
+            "<trait name=\"inflection-class\" value=\"{}\"/>",
+            xml_escape(&record.class),
+        )?;
+        for (i, meaning) in record.meanings.iter().enumerate() {
+            writeln!(
+                output,
+                "<sense id=\"{}-{}\"><grammatical-info value=\"{}\"/><gloss \
+                 lang=\"en\"><text>{}</text></gloss><definition><form \
+                 lang=\"en\"><text>{}</text></form></definition></sense>",
+                xml_escape(record.id.as_str()),
+                i + 1,
+                record.part_of_speech,
+                xml_escape(meaning.identifier()),
+                xml_escape(meaning.description()),
+            )?;
+        }
+        for (grammemes, morpheme) in &record.inflections {
+            writeln!(
+                output,
+                "<variant><form lang=\"{}\"><text>{}</text></form><form \
+                 lang=\"{}-fonipa\"><text>{}</text></form><trait \
+                 name=\"inflected-for\" value=\"{}\"/></variant>",
+                LANG_CODE,
+                xml_escape(&morpheme.to_text()),
+                LANG_CODE,
+                xml_escape(&morpheme.to_broad_ipa()),
+                xml_escape(grammemes),
+            )?;
+        }
+        writeln!(output, "</entry>")?;
+    }
+    writeln!(output, "</lift>")?;
+    Ok(())
+}
+
+/// Writes one row per inflected form, with fields separated by `separator`.
+/// Commas produce RFC 4180 CSV, tabs produce TSV.
+pub fn write_separated<W>(
+    records: &[Record],
+    separator: u8,
+    mut output: W,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let header = [
+        "id",
+        "headword",
+        "headword_ipa",
+        "part_of_speech",
+        "class",
+        "meanings",
+        "descriptions",
+        "inflected_for",
+        "form",
+        "form_ipa",
+    ];
+    write_row(&header, separator, &mut output)?;
+
+    for record in records {
+        let headword = record.headword.to_text();
+        let headword_ipa = record.headword.to_broad_ipa();
+        let part_of_speech = record.part_of_speech.to_string();
+        let meanings = record
+            .meanings
+            .iter()
+            .map(|meaning| meaning.identifier())
+            .collect::<Vec<_>>()
+            .join(LIST_SEPARATOR);
+        let descriptions = record
+            .meanings
+            .iter()
+            .map(|meaning| meaning.description())
+            .collect::<Vec<_>>()
+            .join(LIST_SEPARATOR);
+        for (grammemes, morpheme) in &record.inflections {
+            let form = morpheme.to_text();
+            let form_ipa = morpheme.to_broad_ipa();
+            let row = [
+                record.id.as_str(),
+                &headword,
+                &headword_ipa,
+                &part_of_speech,
+                &record.class,
+                &meanings,
+                &descriptions,
+                grammemes,
+                &form,
+                &form_ipa,
+            ];
+            write_row(&row, separator, &mut output)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_row<W>(
+    fields: &[&str],
+    separator: u8,
+    output: &mut W,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut first = true;
+    for field in fields {
+        if first {
+            first = false;
+        } else {
+            output.write_all(&[separator])?;
+        }
+        if separator == b'\t' {
+            let cleaned = field.replace(['\t', '\n', '\r'], " ");
+            output.write_all(cleaned.as_bytes())?;
+        } else if field.bytes().any(|ch| matches!(ch, b'"' | b'\n' | b'\r'))
+            || field.as_bytes().contains(&separator)
+        {
+            write!(output, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            output.write_all(field.as_bytes())?;
+        }
+    }
+    output.write_all(b"\r\n")?;
+    Ok(())
+}
+
+pub fn write_json<W>(records: &[Record], mut output: W) -> io::Result<()>
+where
+    W: Write,
+{
+    writeln!(output, "[")?;
+    let mut first = true;
+    for record in records {
+        if first {
+            first = false;
+        } else {
+            writeln!(output, ",")?;
+        }
+        write!(
+            output,
+            "{{\"id\":\"{}\",\"headword\":\"{}\",\"script\":\"{}\",\
+             \"ipa\":\"{}\",\"part_of_speech\":\"{}\",\"class\":\"{}\",\
+             \"meanings\":[",
+            json_escape(record.id.as_str()),
+            json_escape(&record.headword.to_text()),
+            json_escape(&script::to_script(&record.headword.to_text())),
+            json_escape(&record.headword.to_broad_ipa()),
+            record.part_of_speech,
+            json_escape(&record.class),
+        )?;
+        let mut first_meaning = true;
+        for meaning in &record.meanings {
+            if first_meaning {
+                first_meaning = false;
+            } else {
+                write!(output, ",")?;
+            }
+            write!(
+                output,
+                "{{\"identifier\":\"{}\",\"description\":\"{}\"}}",
+                json_escape(meaning.identifier()),
+                json_escape(meaning.description()),
+            )?;
+        }
+        write!(output, "],\"inflections\":[")?;
+        let mut first_inflection = true;
+        for (grammemes, morpheme) in &record.inflections {
+            if first_inflection {
+                first_inflection = false;
+            } else {
+                write!(output, ",")?;
+            }
+            write!(
+                output,
+                "{{\"inflected_for\":\"{}\",\"form\":\"{}\",\
+                 \"script\":\"{}\",\"ipa\":\"{}\"}}",
+                json_escape(grammemes),
+                json_escape(&morpheme.to_text()),
+                json_escape(&script::to_script(&morpheme.to_text())),
+                json_escape(&morpheme.to_broad_ipa()),
+            )?;
+        }
+        write!(output, "]}}")?;
+    }
+    writeln!(output, "\n]")?;
+    Ok(())
+}
+
+/// Writes the `.index` and `.dict` files of a dictd database. Inflected forms
+/// are indexed as well, pointing to the definition of their lexeme. The
+/// database information comes first, and the words follow in the order of
+/// the alphabet.
+pub fn write_dictd<I, D>(
+    records: &[Record],
+    mut index: I,
+    mut dict: D,
+) -> io::Result<()>
+where
+    I: Write,
+    D: Write,
+{
+    let mut info_keys = Vec::new();
+    let mut keys = Vec::new();
+    let mut offset = 0;
+
+    let info = [
+        ("00-database-short", "Classical Star Language Dictionary"),
+        (
+            "00-database-info",
+            "Dictionary of the Classical Star Language, from The World From \
+             The Stars.",
+        ),
+    ];
+    for (key, text) in info.iter() {
+        let contents = format!("{}\n{}\n", key, text);
+        dict.write_all(contents.as_bytes())?;
+        info_keys.push((key.to_string(), offset, contents.len()));
+        offset += contents.len();
+    }
+
+    for record in records {
+        let contents = record.to_plain_text();
+        dict.write_all(contents.as_bytes())?;
+        for word in index_words(record) {
+            keys.push((word, offset, contents.len()));
+        }
+        offset += contents.len();
+    }
+
+    let collation = Collation::TRADITIONAL;
+    keys.sort_by_cached_key(|(word, offset, len)| {
+        (collation.key_str(word).unwrap(), word.clone(), *offset, *len)
+    });
+    keys.dedup();
+    for (word, offset, len) in info_keys.into_iter().chain(keys) {
+        writeln!(
+            index,
+            "{}\t{}\t{}",
+            word,
+            dictd_base64(offset as u64),
+            dictd_base64(len as u64)
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Writes the `.ifo`, `.idx` and `.dict` files of a StarDict dictionary.
+pub fn write_stardict<F, I, D>(
+    records: &[Record],
+    mut ifo: F,
+    mut idx: I,
+    mut dict: D,
+) -> io::Result<()>
+where
+    F: Write,
+    I: Write,
+    D: Write,
+{
+    let mut keys = Vec::new();
+    let mut offset = 0;
+
+    for record in records {
+        let contents = record.to_plain_text();
+        dict.write_all(contents.as_bytes())?;
+        for word in index_words(record) {
+            keys.push((word, offset as u32, contents.len() as u32));
+        }
+        offset += contents.len();
+    }
+
+    keys.sort_by(|(left, ..), (right, ..)| stardict_cmp(left, right));
+    keys.dedup();
+
+    let mut idx_buf = Vec::new();
+    for (word, offset, len) in &keys {
+        idx_buf.extend_from_slice(word.as_bytes());
+        idx_buf.push(0);
+        idx_buf.extend_from_slice(&offset.to_be_bytes());
+        idx_buf.extend_from_slice(&len.to_be_bytes());
+    }
+    idx.write_all(&idx_buf)?;
+
+    writeln!(ifo, "StarDict's dict ifo file")?;
+    writeln!(ifo, "version=2.4.2")?;
+    writeln!(ifo, "wordcount={}", keys.len())?;
+    writeln!(ifo, "idxfilesize={}", idx_buf.len())?;
+    writeln!(ifo, "bookname=Classical Star Language Dictionary")?;
+    writeln!(ifo, "sametypesequence=m")?;
+
+    Ok(())
+}
+
+fn index_words(record: &Record) -> Vec<String> {
+    let mut words = vec![record.headword.to_text()];
+    for morpheme in record.inflections.values() {
+        let text = morpheme.to_text();
+        if !words.contains(&text) {
+            words.push(text);
+        }
+    }
+    words
+}
+
+/// StarDict requires the index to be sorted with `g_ascii_strcasecmp`, ties
+/// broken by `strcmp`.
+fn stardict_cmp(left: &str, right: &str) -> Ordering {
+    let left_lower = left.bytes().map(|ch| ch.to_ascii_lowercase());
+    let right_lower = right.bytes().map(|ch| ch.to_ascii_lowercase());
+    left_lower.cmp(right_lower).then_with(|| left.cmp(right))
+}
+
+fn dictd_base64(mut number: u64) -> String {
+    const DIGITS: &[u8] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut digits = Vec::new();
+    loop {
+        digits.push(DIGITS[(number % 64) as usize]);
+        number /= 64;
+        if number == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap()
+}
+
+fn xml_escape(string: &str) -> String {
+    let mut output = String::with_capacity(string.len());
+    for ch in string.chars() {
+        match ch {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&apos;"),
+            _ => output.push(ch),
+        }
+    }
+    output
+}
+
+fn json_escape(string: &str) -> String {
+    let mut output = String::with_capacity(string.len());
+    for ch in string.chars() {
+        match ch {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => {
+                write!(output, "\\u{:04x}", ch as u32).unwrap()
+            },
+            _ => output.push(ch),
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        Entry::all().iter().map(Record::from_entry).collect()
+    }
+
+    #[test]
+    fn dictd_numbers() {
+        assert_eq!(dictd_base64(0), "A");
+        assert_eq!(dictd_base64(63), "/");
+        assert_eq!(dictd_base64(64), "BA");
+        assert_eq!(dictd_base64(4095), "//");
+    }
+
+    #[test]
+    fn csv_has_every_form() {
+        let records = records();
+        let mut output = Vec::new();
+        write_separated(&records, b',', &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let forms =
+            records.iter().map(|rec| rec.inflections.len()).sum::<usize>();
+        assert_eq!(output.lines().count(), forms + 1);
+        assert!(output.lines().any(|line| line.starts_with("eye,gas,")));
+    }
+
+    #[test]
+    fn dictd_index_points_to_definitions() {
+        let records = records();
+        let mut index = Vec::new();
+        let mut dict = Vec::new();
+        write_dictd(&records, &mut index, &mut dict).unwrap();
+        let index = String::from_utf8(index).unwrap();
+        let line = index
+            .lines()
+            .find(|line| line.starts_with("gas\t"))
+            .unwrap();
+        let fields = line.split('\t').collect::<Vec<_>>();
+        let decode = |digits: &str| {
+            digits.bytes().fold(0, |acc, ch| {
+                let digit = match ch {
+                    b'A' ..= b'Z' => ch - b'A',
+                    b'a' ..= b'z' => ch - b'a' + 26,
+                    b'0' ..= b'9' => ch - b'0' + 52,
+                    b'+' => 62,
+                    _ => 63,
+                };
+                acc * 64 + digit as usize
+            })
+        };
+        let start = decode(fields[1]);
+        let end = start + decode(fields[2]);
+        let definition = std::str::from_utf8(&dict[start .. end]).unwrap();
+        assert!(definition.starts_with("gas\n"));
+        assert!(definition.contains("An eye."));
+    }
+
+    #[test]
+    fn dictd_index_follows_alphabet() {
+        let records = records();
+        let mut index = Vec::new();
+        write_dictd(&records, &mut index, Vec::new()).unwrap();
+        let index = String::from_utf8(index).unwrap();
+        let words = index
+            .lines()
+            .map(|line| line.split('\t').next().unwrap())
+            .filter(|word| !word.starts_with("00-"))
+            .collect::<Vec<_>>();
+        let collation = Collation::TRADITIONAL;
+        assert!(words.windows(2).all(|pair| {
+            collation.key_str(pair[0]).unwrap()
+                <= collation.key_str(pair[1]).unwrap()
+        }));
+        assert!(index.starts_with("00-database-short\t"));
+    }
+
+    #[test]
+    fn separated_lists_can_be_split() {
+        let records = records();
+        let mut output = Vec::new();
+        write_separated(&records, b'\t', &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        for line in output.lines().skip(1) {
+            let fields = line.split('\t').collect::<Vec<_>>();
+            let record = records
+                .iter()
+                .find(|record| record.id.as_str() == fields[0])
+                .unwrap();
+            let meanings = fields[5].split(LIST_SEPARATOR).count();
+            let descriptions = fields[6].split(LIST_SEPARATOR).count();
+            assert_eq!(meanings, record.meanings.len());
+            assert_eq!(descriptions, record.meanings.len());
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{
+    dictionary::Entry,
+    grammar::Paradigm,
+    morphology::Morpheme,
+    phonology::{Coda, Onset, Phoneme, Syllable, Word},
+};
+use std::collections::BTreeSet;
+use thiserror::Error;
+use wfts_lang::random::Rng;
+
+#[derive(Debug, Clone, Error)]
+#[error("Could not coin a {paradigm} word in {attempts} attempts")]
+pub struct CoinageFailed {
+    pub paradigm: Paradigm,
+    pub attempts: usize,
+}
+
+/// Every word form already in use, so that coined words are not homophones
+/// of existing ones.
+#[derive(Debug, Clone, Default)]
+pub struct Lexicon {
+    forms: BTreeSet<Vec<Phoneme>>,
+}
+
+impl Lexicon {
+    pub fn from_entries(entries: &[Entry]) -> Self {
+        let mut this = Self::default();
+        for entry in entries {
+            for word in word_forms(entry.inflections.values()) {
+                this.insert(&word);
+            }
+        }
+        this
+    }
+
+    pub fn insert(&mut self, word: &Word) -> bool {
+        self.forms.insert(word.phonemes().collect())
+    }
+
+    pub fn contains(&self, word: &Word) -> bool {
+        self.forms.contains(&word.phonemes().collect::<Vec<_>>())
+    }
+}
+
+/// Samples words allowed by the phonotactics, as enforced by `Onset::new`,
+/// `Coda::new`, `Syllable::new` and `Word::new`.
+#[derive(Debug, Clone)]
+pub struct WordGenerator {
+    /// Relative frequency of each phoneme. Phonemes left out are never
+    /// generated.
+    pub phoneme_weights: Vec<(Phoneme, u32)>,
+    /// Relative frequency of each number of syllables.
+    pub syllable_counts: Vec<(usize, u32)>,
+    /// Chance, in percent, of leaving an optional consonant slot empty.
+    pub empty_slot_percent: u32,
+    /// How many candidates are tried before giving up.
+    pub max_attempts: usize,
+}
+
+impl Default for WordGenerator {
+    fn default() -> Self {
+        Self {
+            phoneme_weights: Phoneme::ALL.iter().map(|&ph| (ph, 1)).collect(),
+            syllable_counts: vec![(1, 4), (2, 4), (3, 2)],
+            empty_slot_percent: 60,
+            max_attempts: 10000,
+        }
+    }
+}
+
+impl WordGenerator {
+    fn pick<F>(&self, rng: &mut Rng, fits: F) -> Option<Phoneme>
+    where
+        F: Fn(Phoneme) -> bool,
+    {
+        let candidates = self
+            .phoneme_weights
+            .iter()
+            .copied()
+            .filter(|&(ph, _)| fits(ph))
+            .collect::<Vec<_>>();
+        rng.weighted(&candidates).copied()
+    }
+
+    fn pick_optional<F>(&self, rng: &mut Rng, fits: F) -> Option<Phoneme>
+    where
+        F: Fn(Phoneme) -> bool,
+    {
+        if rng.percent(self.empty_slot_percent) {
+            None
+        } else {
+            self.pick(rng, fits)
+        }
+    }
+
+    /// Samples a single syllable, or `None` if the sampled slots do not form
+    /// a valid one.
+    pub fn syllable(&self, rng: &mut Rng) -> Option<Syllable> {
+        let outer = self.pick_optional(rng, |ph| {
+            Onset::valid_outer_medial(Some(ph), None)
+        });
+        let medial = self.pick_optional(rng, |ph| {
+            Some(ph) != outer && Onset::valid_outer_medial(outer, Some(ph))
+        });
+        let inner = self.pick_optional(rng, |ph| Onset::valid_inner(Some(ph)));
+        let nucleus = self.pick(rng, Phoneme::can_be_nucleus)?;
+        let coda_inner =
+            self.pick_optional(rng, |ph| Coda::valid_inner(Some(ph)));
+        let coda_outer =
+            self.pick_optional(rng, |ph| Coda::valid_outer(Some(ph)));
+
+        let onset = Onset::new(outer, medial, inner).ok()?;
+        let coda = Coda::new(coda_inner, coda_outer).ok()?;
+        Syllable::new(onset, nucleus, coda).ok()
+    }
+
+    /// Samples a single word, or `None` if the sampled syllables do not form
+    /// a valid one.
+    pub fn word(&self, rng: &mut Rng) -> Option<Word> {
+        let &count = rng.weighted(&self.syllable_counts)?;
+        let syllables = (0 .. count)
+            .map(|_| self.syllable(rng))
+            .collect::<Option<Vec<_>>>()?;
+        Word::new(syllables).ok()
+    }
+
+    /// Coins a word that fits the given inflectional class and none of whose
+    /// inflected forms is already in the lexicon. Every form of the coined
+    /// word is added to the lexicon.
+    pub fn coin(
+        &self,
+        rng: &mut Rng,
+        paradigm: Paradigm,
+        lexicon: &mut Lexicon,
+    ) -> anyhow::Result<Word> {
+        for _ in 0 .. self.max_attempts {
+            let word = match self.word(rng) {
+                Some(word) => word,
+                None => continue,
+            };
+            let forms = match paradigm.inflections(word.clone()) {
+                Ok(inflections) => word_forms(inflections.values()),
+                Err(_) => continue,
+            };
+            if forms.iter().all(|form| !lexicon.contains(form)) {
+                for form in &forms {
+                    lexicon.insert(form);
+                }
+                return Ok(word);
+            }
+        }
+
+        Err(CoinageFailed { paradigm, attempts: self.max_attempts })?
+    }
+}
+
+/// The inflected forms that are whole words.
+fn word_forms<'morpheme, I>(morphemes: I) -> Vec<Word>
+where
+    I: IntoIterator<Item = &'morpheme Morpheme>,
+{
+    morphemes
+        .into_iter()
+        .filter_map(|morpheme| match morpheme {
+            Morpheme::Word(word) => Some(word.clone()),
+            Morpheme::Template(_) => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn coined_forms_are_new_and_fit_paradigm() {
+        let mut lexicon = Lexicon::from_entries(&Entry::all());
+        let existing = lexicon.clone();
+        let generator = WordGenerator::default();
+        let mut rng = Rng::new(0x5747_5346);
+
+        for &paradigm in Paradigm::ALL {
+            for _ in 0 .. 20 {
+                let word = generator.coin(&mut rng, paradigm, &mut lexicon);
+                let word = word.unwrap();
+                paradigm.check(word.clone()).unwrap();
+                let inflections = paradigm.inflections(word.clone()).unwrap();
+                for form in word_forms(inflections.values()) {
+                    assert!(!existing.contains(&form), "{} ({})", form, word);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_words() {
+        let generator = WordGenerator::default();
+        let words = |seed| {
+            let mut rng = Rng::new(seed);
+            let mut lexicon = Lexicon::default();
+            (0 .. 10)
+                .map(|_| {
+                    generator
+                        .coin(&mut rng, Paradigm::FullNoun1, &mut lexicon)
+                        .unwrap()
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(words(3), words(3));
+    }
+}
@@ -5,3 +5,326 @@ pub mod pronoun;
 pub mod postposition;
 pub mod conjunction;
 pub mod verb;
+
+use crate::{
+    dictionary::Notes,
+    morphology::Morpheme,
+    phonology,
+    romanization::Scheme,
+};
+use indexmap::IndexMap;
+use std::fmt;
+use thiserror::Error;
+use wfts_pedia_ssg::{
+    component::{table, DynComponent},
+    location::Id,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PartOfSpeech {
+    Noun,
+    Adjective,
+    Pronoun,
+    Postposition,
+    Conjunction,
+    Verb,
+}
+
+impl PartOfSpeech {
+    pub const ALL: &'static [Self] = &[
+        PartOfSpeech::Noun,
+        PartOfSpeech::Adjective,
+        PartOfSpeech::Pronoun,
+        PartOfSpeech::Postposition,
+        PartOfSpeech::Conjunction,
+        PartOfSpeech::Verb,
+    ];
+}
+
+impl fmt::Display for PartOfSpeech {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            PartOfSpeech::Noun => "noun",
+            PartOfSpeech::Adjective => "adjective",
+            PartOfSpeech::Pronoun => "pronoun",
+            PartOfSpeech::Postposition => "postposition",
+            PartOfSpeech::Conjunction => "conjunction",
+            PartOfSpeech::Verb => "verb",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Error)]
+#[error(
+    "Cannot inflect {lemma} ({paradigm}) for {cell}: {constraint}",
+    lemma = lemma.to_text()
+)]
+pub struct InflectionError {
+    pub lemma: phonology::Word,
+    pub paradigm: Paradigm,
+    /// Grammemes of the failing cell, as in the dictionary entry.
+    pub cell: String,
+    /// The phonotactic constraint the inflected form violates.
+    pub constraint: String,
+}
+
+/// An inflectional class, whose citation form must satisfy the class'
+/// constraints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Paradigm {
+    FullNoun1,
+    FullNoun2,
+    DivineNoun1,
+    DivineNoun2,
+    RegularAdjective,
+    DemonstrativePronoun,
+    PersonalPronoun,
+    RelativePronoun,
+    UnaryPostposition,
+    BinaryPostposition,
+    AdditiveConjunction,
+    IsomorphicConjunction,
+    RegularVerb1,
+}
+
+impl Paradigm {
+    pub const ALL: &'static [Self] = &[
+        Paradigm::FullNoun1,
+        Paradigm::FullNoun2,
+        Paradigm::DivineNoun1,
+        Paradigm::DivineNoun2,
+        Paradigm::RegularAdjective,
+        Paradigm::DemonstrativePronoun,
+        Paradigm::PersonalPronoun,
+        Paradigm::RelativePronoun,
+        Paradigm::UnaryPostposition,
+        Paradigm::BinaryPostposition,
+        Paradigm::AdditiveConjunction,
+        Paradigm::IsomorphicConjunction,
+        Paradigm::RegularVerb1,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Paradigm::FullNoun1 => "full-noun-1",
+            Paradigm::FullNoun2 => "full-noun-2",
+            Paradigm::DivineNoun1 => "divine-noun-1",
+            Paradigm::DivineNoun2 => "divine-noun-2",
+            Paradigm::RegularAdjective => "regular-adjective",
+            Paradigm::DemonstrativePronoun => "demonstrative-pronoun",
+            Paradigm::PersonalPronoun => "personal-pronoun",
+            Paradigm::RelativePronoun => "relative-pronoun",
+            Paradigm::UnaryPostposition => "unary-postposition",
+            Paradigm::BinaryPostposition => "binary-postposition",
+            Paradigm::AdditiveConjunction => "additive-conjunction",
+            Paradigm::IsomorphicConjunction => "isomorphic-conjunction",
+            Paradigm::RegularVerb1 => "regular-verb-1",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|paradigm| paradigm.name() == name)
+    }
+
+    pub fn part_of_speech(self) -> PartOfSpeech {
+        match self {
+            Paradigm::FullNoun1
+            | Paradigm::FullNoun2
+            | Paradigm::DivineNoun1
+            | Paradigm::DivineNoun2 => PartOfSpeech::Noun,
+            Paradigm::RegularAdjective => PartOfSpeech::Adjective,
+            Paradigm::DemonstrativePronoun
+            | Paradigm::PersonalPronoun
+            | Paradigm::RelativePronoun => PartOfSpeech::Pronoun,
+            Paradigm::UnaryPostposition | Paradigm::BinaryPostposition => {
+                PartOfSpeech::Postposition
+            },
+            Paradigm::AdditiveConjunction
+            | Paradigm::IsomorphicConjunction => PartOfSpeech::Conjunction,
+            Paradigm::RegularVerb1 => PartOfSpeech::Verb,
+        }
+    }
+
+    /// Names the lemma, this class and the cell in an error raised while
+    /// inflecting.
+    pub fn inflection_error<C>(
+        self,
+        lemma: &phonology::Word,
+        cell: C,
+        error: anyhow::Error,
+    ) -> InflectionError
+    where
+        C: fmt::Display,
+    {
+        InflectionError {
+            lemma: lemma.clone(),
+            paradigm: self,
+            cell: cell.to_string(),
+            constraint: format!("{:#}", error),
+        }
+    }
+
+    /// Checks whether the word can be the citation form of this class.
+    pub fn check(self, word: phonology::Word) -> anyhow::Result<()> {
+        match self {
+            Paradigm::FullNoun1 => drop(noun::full1::Word::new(word)?),
+            Paradigm::FullNoun2 => drop(noun::full2::Word::new(word)?),
+            Paradigm::DivineNoun1 => drop(noun::divine1::Word::new(word)?),
+            Paradigm::DivineNoun2 => drop(noun::divine2::Word::new(word)?),
+            Paradigm::RegularAdjective => {
+                drop(adjective::regular::Word::new(word)?)
+            },
+            Paradigm::DemonstrativePronoun => {
+                drop(pronoun::demonstrative::Word::new(word)?)
+            },
+            Paradigm::PersonalPronoun => {
+                drop(pronoun::personal::Word::new(word)?)
+            },
+            Paradigm::RelativePronoun => {
+                drop(pronoun::relative::Word::new(word)?)
+            },
+            Paradigm::UnaryPostposition => {
+                drop(postposition::unary::Word::new(word)?)
+            },
+            Paradigm::BinaryPostposition => {
+                drop(postposition::binary::Word::new(word)?)
+            },
+            Paradigm::AdditiveConjunction => {
+                drop(conjunction::additive::Word::new(word)?)
+            },
+            Paradigm::IsomorphicConjunction => {
+                drop(conjunction::isomorphic::Word::new(word)?)
+            },
+            Paradigm::RegularVerb1 => drop(verb::regular1::Word::new(word)?),
+        }
+        Ok(())
+    }
+
+    /// Every inflected form of the word in this class, keyed by its
+    /// grammemes, as they would appear in a dictionary entry.
+    pub fn inflections(
+        self,
+        word: phonology::Word,
+    ) -> anyhow::Result<IndexMap<String, Morpheme>> {
+        macro_rules! inflections {
+            ($($module:ident)::+, $word:expr) => {
+                $($module)::+::Definition {
+                    id: Id::new("word").unwrap(),
+                    word: $word,
+                    meanings: Vec::new(),
+                    notes: Notes::default(),
+                }
+                .to_dict_entry()
+                .inflections
+            };
+        }
+
+        let inflections = match self {
+            Paradigm::FullNoun1 => {
+                inflections!(noun::full1, noun::full1::Word::new(word)?)
+            },
+            Paradigm::FullNoun2 => {
+                inflections!(noun::full2, noun::full2::Word::new(word)?)
+            },
+            Paradigm::DivineNoun1 => {
+                inflections!(noun::divine1, noun::divine1::Word::new(word)?)
+            },
+            Paradigm::DivineNoun2 => {
+                inflections!(noun::divine2, noun::divine2::Word::new(word)?)
+            },
+            Paradigm::RegularAdjective => inflections!(
+                adjective::regular,
+                adjective::regular::Word::new(word)?
+            ),
+            Paradigm::DemonstrativePronoun => inflections!(
+                pronoun::demonstrative,
+                pronoun::demonstrative::Word::new(word)?
+            ),
+            Paradigm::PersonalPronoun => inflections!(
+                pronoun::personal,
+                pronoun::personal::Word::new(word)?
+            ),
+            Paradigm::RelativePronoun => inflections!(
+                pronoun::relative,
+                pronoun::relative::Word::new(word)?
+            ),
+            Paradigm::UnaryPostposition => inflections!(
+                postposition::unary,
+                postposition::unary::Word::new(word)?
+            ),
+            Paradigm::BinaryPostposition => inflections!(
+                postposition::binary,
+                postposition::binary::Word::new(word)?
+            ),
+            Paradigm::AdditiveConjunction => inflections!(
+                conjunction::additive,
+                conjunction::additive::Word::new(word)?
+            ),
+            Paradigm::IsomorphicConjunction => inflections!(
+                conjunction::isomorphic,
+                conjunction::isomorphic::Word::new(word)?
+            ),
+            Paradigm::RegularVerb1 => {
+                inflections!(verb::regular1, verb::regular1::Word::new(word)?)
+            },
+        };
+
+        Ok(inflections)
+    }
+
+    /// Inflection table of the word in this class, as in its dictionary
+    /// entry, with the forms romanized in the given scheme.
+    pub fn table(
+        self,
+        word: phonology::Word,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> anyhow::Result<table::Entries<DynComponent>> {
+        macro_rules! table {
+            ($($module:ident)::+) => {
+                $($module)::+::Word::new(word)?.table(entry_id, scheme)
+            };
+        }
+
+        let table = match self {
+            Paradigm::FullNoun1 => table!(noun::full1),
+            Paradigm::FullNoun2 => table!(noun::full2),
+            Paradigm::DivineNoun1 => table!(noun::divine1),
+            Paradigm::DivineNoun2 => table!(noun::divine2),
+            Paradigm::RegularAdjective => table!(adjective::regular),
+            Paradigm::DemonstrativePronoun => table!(pronoun::demonstrative),
+            Paradigm::PersonalPronoun => table!(pronoun::personal),
+            Paradigm::RelativePronoun => table!(pronoun::relative),
+            Paradigm::UnaryPostposition => table!(postposition::unary),
+            Paradigm::BinaryPostposition => table!(postposition::binary),
+            Paradigm::AdditiveConjunction => table!(conjunction::additive),
+            Paradigm::IsomorphicConjunction => table!(conjunction::isomorphic),
+            Paradigm::RegularVerb1 => table!(verb::regular1),
+        };
+
+        Ok(table)
+    }
+}
+
+impl fmt::Display for Paradigm {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::phonology::Parse;
+
+    #[test]
+    fn invalid_cell_names_lemma_and_cell() {
+        let word = phonology::Word::parse_str("sméyh").unwrap();
+        let error = Paradigm::FullNoun2.check(word.clone()).unwrap_err();
+        let error = error.downcast::<InflectionError>().unwrap();
+        assert_eq!(error.lemma, word);
+        assert_eq!(error.paradigm, Paradigm::FullNoun2);
+        assert!(!error.cell.is_empty());
+        assert!(!error.constraint.is_empty());
+    }
+}
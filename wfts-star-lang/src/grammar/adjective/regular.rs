@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         adjective,
         grammemes::{BasicCase, Gender, Number},
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,14 +32,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::RegularAdjective,
             class: "Regular Class".to_owned(),
+            part_of_speech: PartOfSpeech::Adjective,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -47,6 +51,7 @@ impl Definition {
                                 format!("{} {} {}", case, gender, number),
                                 self.word
                                     .inflect(case, gender, number)
+                                    .expect("checked by Word::new")
                                     .phonemes
                                     .into(),
                             );
@@ -101,15 +106,32 @@ impl Word {
     pub fn new(nom_div_sing: phonology::Word) -> anyhow::Result<Self> {
         let last = nom_div_sing.phonemes().next_back().unwrap();
         match last {
-            Phoneme::Ii => Ok(Self { nom_div_sing }),
+            Phoneme::Ii => {
+                let this = Self { nom_div_sing };
+                for &case in BasicCase::ALL {
+                    for &gender in Gender::ALL {
+                        for &number in Number::ALL {
+                            this.inflect(case, gender, number)?;
+                        }
+                    }
+                }
+                Ok(this)
+            },
             _ => Err(Invalid { nom_div_sing })?,
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::bcase_gender_number_table(|case, gender, number| {
-            let inflected =
-                self.inflect(case, gender, number).phonemes.to_text();
+            let inflected = self
+                .inflect(case, gender, number)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -119,8 +141,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -185,20 +209,31 @@ impl Word {
         case: BasicCase,
         gender: Gender,
         number: Number,
-    ) -> adjective::Inflected {
+    ) -> anyhow::Result<adjective::Inflected> {
         let affix = Self::affix(case, gender, number);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::RegularAdjective.inflection_error(
+                &self.nom_div_sing,
+                format_args!("{} {} {}", case, gender, number),
+                error,
+            )
+        })?;
+
+        Ok(adjective::Inflected { phonemes, case, gender, number })
+    }
+
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let mut phonemes = self
             .nom_div_sing
-            .replace_final_rhyme(affix.nucleus, affix.coda)
-            .unwrap();
+            .replace_final_rhyme(affix.nucleus, affix.coda)?;
         if let Some(suffix) = affix.suffix {
-            phonemes = phonemes.append(suffix).unwrap();
+            phonemes = phonemes.append(suffix)?;
         }
 
-        adjective::Inflected { phonemes, case, gender, number }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Regular Class",
             entries: component::bcase_gender_number_table(
@@ -206,7 +241,7 @@ impl Word {
                     let affix = Self::affix(case, gender, number);
                     UnmarkedList(vec![
                         WithStarAlphabet(affix.to_string()).to_dyn(),
-                        affix.to_string().to_dyn(),
+                        Romanized(scheme, affix.to_string()).to_dyn(),
                     ])
                     .to_dyn()
                 },
@@ -218,8 +253,8 @@ impl Word {
 pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("big").unwrap(),
-        meanings: vec![Meaning::Big],
-        notes: "".blocking().to_dyn(),
+        meanings: vec![Meaning::BIG],
+        notes: dictionary::Notes::default(),
         word: Word::new(phonology::Word::parse_str("mací").unwrap()).unwrap(),
     }]
 }
@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         conjunction,
         grammemes::{BasicCase, Case, ClauseCase},
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Parse, Phoneme},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,21 +32,26 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::AdditiveConjunction,
             class: "Additive Class".to_owned(),
+            part_of_speech: PartOfSpeech::Conjunction,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
                 for &case in ClauseCase::ALL {
                     map.insert(
                         format!("{}", case),
-                        self.word.inflect(case).phonemes.into(),
+                        self.word
+                            .inflect(case)
+                            .expect("checked by Word::new")
+                            .phonemes
+                            .into(),
                     );
                 }
                 map
@@ -91,13 +99,25 @@ pub struct Word {
 }
 
 impl Word {
-    pub fn new(coord: phonology::Word) -> Self {
-        Self { coord }
+    pub fn new(coord: phonology::Word) -> anyhow::Result<Self> {
+        let this = Self { coord };
+        for &case in ClauseCase::ALL {
+            this.inflect(case)?;
+        }
+        Ok(this)
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::ccase_table(|case| {
-            let inflected = self.inflect(case).phonemes.to_text();
+            let inflected = self
+                .inflect(case)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -107,8 +127,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -136,31 +158,45 @@ impl Word {
         Affix { nucleus, coda }
     }
 
-    pub fn inflect(&self, case: ClauseCase) -> conjunction::Inflected {
+    pub fn inflect(
+        &self,
+        case: ClauseCase,
+    ) -> anyhow::Result<conjunction::Inflected> {
         let affix = Self::affix(case);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::AdditiveConjunction.inflection_error(
+                &self.coord,
+                case,
+                error,
+            )
+        })?;
+
+        Ok(conjunction::Inflected { phonemes, case })
+    }
 
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let phonemes = match (affix.nucleus, affix.coda) {
             (Some(nucleus), Some(coda)) => {
-                self.coord.replace_final_rhyme(nucleus, coda).unwrap()
+                self.coord.replace_final_rhyme(nucleus, coda)?
             },
             (Some(nucleus), None) => {
-                self.coord.replace_final_nucleus(nucleus).unwrap()
+                self.coord.replace_final_nucleus(nucleus)?
             },
-            (None, Some(coda)) => self.coord.replace_final_coda(coda).unwrap(),
+            (None, Some(coda)) => self.coord.replace_final_coda(coda)?,
             (None, None) => self.coord.clone(),
         };
 
-        conjunction::Inflected { phonemes, case }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Additive Class",
             entries: component::ccase_table(|case| {
                 let affix = Self::affix(case);
                 UnmarkedList(vec![
                     WithStarAlphabet(affix.to_string()).to_dyn(),
-                    affix.to_string().to_dyn(),
+                    Romanized(scheme, affix.to_string()).to_dyn(),
                 ])
                 .to_dyn()
             }),
@@ -171,8 +207,8 @@ impl Word {
 pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("and").unwrap(),
-        meanings: vec![Meaning::And],
-        notes: "".blocking().to_dyn(),
-        word: Word::new(phonology::Word::parse_str("eta").unwrap()),
+        meanings: vec![Meaning::AND],
+        notes: dictionary::Notes::default(),
+        word: Word::new(phonology::Word::parse_str("eta").unwrap()).unwrap(),
     }]
 }
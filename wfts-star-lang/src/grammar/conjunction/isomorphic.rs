@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         conjunction,
         grammemes::{BasicCase, Case, ClauseCase},
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Onset, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,21 +32,26 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::IsomorphicConjunction,
             class: "Isomorphic Class".to_owned(),
+            part_of_speech: PartOfSpeech::Conjunction,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
                 for &case in ClauseCase::ALL {
                     map.insert(
                         format!("{}", case),
-                        self.word.inflect(case).phonemes.into(),
+                        self.word
+                            .inflect(case)
+                            .expect("checked by Word::new")
+                            .phonemes
+                            .into(),
                     );
                 }
                 map
@@ -96,15 +104,27 @@ impl Word {
 
         let last_but_one = init.last().map(|syl| syl.nucleus());
         if last.nucleus() == Phoneme::R && last_but_one != Some(Phoneme::A) {
-            Ok(Self { nom })
+            let this = Self { nom };
+            for &case in ClauseCase::ALL {
+                this.inflect(case)?;
+            }
+            Ok(this)
         } else {
             Err(Invalid { nom })?
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::ccase_table(|case| {
-            let inflected = self.inflect(case).phonemes.to_text();
+            let inflected = self
+                .inflect(case)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -114,8 +134,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -139,19 +161,32 @@ impl Word {
         Affix { before, after, coda_outer }
     }
 
-    pub fn inflect(&self, case: ClauseCase) -> conjunction::Inflected {
+    pub fn inflect(
+        &self,
+        case: ClauseCase,
+    ) -> anyhow::Result<conjunction::Inflected> {
         let affix = Self::affix(case);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::IsomorphicConjunction.inflection_error(
+                &self.nom,
+                case,
+                error,
+            )
+        })?;
+
+        Ok(conjunction::Inflected { phonemes, case })
+    }
 
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let mut phonemes = match (affix.before, affix.after) {
             (None, None) => self.nom.clone(),
             (Some(nucleus), None) => {
                 let mut syllables = self.nom.syllables().to_vec();
                 let last = syllables.last_mut().unwrap();
-                let coda =
-                    Coda::new(Some(Phoneme::R), last.coda().outer()).unwrap();
-                *last = Syllable::new(last.onset(), nucleus, coda).unwrap();
+                let coda = Coda::new(Some(Phoneme::R), last.coda().outer())?;
+                *last = Syllable::new(last.onset(), nucleus, coda)?;
 
-                phonology::Word::new(syllables).unwrap()
+                phonology::Word::new(syllables)?
             },
             (None, Some(nucleus)) => {
                 let mut syllables = self.nom.syllables().to_vec();
@@ -160,10 +195,9 @@ impl Word {
                     last.onset().outer(),
                     last.onset().medial(),
                     Some(Phoneme::R),
-                );
-                *last = Syllable::new(onset.unwrap(), nucleus, last.coda())
-                    .unwrap();
-                phonology::Word::new(syllables).unwrap()
+                )?;
+                *last = Syllable::new(onset, nucleus, last.coda())?;
+                phonology::Word::new(syllables)?
             },
 
             (Some(before), Some(after)) => {
@@ -173,37 +207,37 @@ impl Word {
                     last.onset(),
                     before,
                     Coda::parse(&[]).unwrap(),
-                );
+                )?;
                 let new_last = Syllable::new(
                     Onset::parse(&[Phoneme::R]).unwrap(),
                     after,
                     last.coda(),
-                );
-                syllables.push(last_but_one.unwrap());
-                syllables.push(new_last.unwrap());
-                phonology::Word::new(syllables).unwrap()
+                )?;
+                syllables.push(last_but_one);
+                syllables.push(new_last);
+                phonology::Word::new(syllables)?
             },
         };
 
         if let Some(outer) = affix.coda_outer {
             let mut syllables = phonemes.syllables().to_vec();
             let last = syllables.last_mut().unwrap();
-            let coda = Coda::new(last.coda().inner(), Some(outer)).unwrap();
-            *last = Syllable::new(last.onset(), last.nucleus(), coda).unwrap();
-            phonemes = phonology::Word::new(syllables).unwrap();
+            let coda = Coda::new(last.coda().inner(), Some(outer))?;
+            *last = Syllable::new(last.onset(), last.nucleus(), coda)?;
+            phonemes = phonology::Word::new(syllables)?;
         }
 
-        conjunction::Inflected { phonemes, case }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Isomorphic Class",
             entries: component::ccase_table(|case| {
                 let affix = Self::affix(case);
                 UnmarkedList(vec![
                     WithStarAlphabet(affix.to_string()).to_dyn(),
-                    affix.to_string().to_dyn(),
+                    Romanized(scheme, affix.to_string()).to_dyn(),
                 ])
                 .to_dyn()
             }),
@@ -214,8 +248,8 @@ impl Word {
 pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("equals").unwrap(),
-        meanings: vec![Meaning::CompoundConj],
-        notes: "".blocking().to_dyn(),
+        meanings: vec![Meaning::COMPOUND_CONJ],
+        notes: dictionary::Notes::default(),
         word: Word::new(phonology::Word::parse_str("r").unwrap()).unwrap(),
     }]
 }
@@ -1,3 +1,4 @@
+use crate::phonology::prosody::Intonation;
 use std::fmt;
 
 pub trait Agreement<T = Self> {
@@ -283,6 +284,15 @@ impl Mood {
         Mood::Interrogative,
         Mood::Optative,
     ];
+
+    /// Intonation of a clause in this mood.
+    pub fn intonation(self) -> Intonation {
+        match self {
+            Mood::Basic(BasicMood::Imperative) => Intonation::Imperative,
+            Mood::Interrogative => Intonation::Interrogative,
+            _ => Intonation::Declarative,
+        }
+    }
 }
 
 impl fmt::Display for Mood {
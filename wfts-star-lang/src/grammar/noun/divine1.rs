@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{BasicCase, Gender, Number},
         noun,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,14 +32,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::DivineNoun1,
             class: "Divine Class 1".to_owned(),
+            part_of_speech: PartOfSpeech::Noun,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -44,7 +48,11 @@ impl Definition {
                     for &number in Number::ALL {
                         map.insert(
                             format!("{} {} {}", case, Gender::Divine, number),
-                            self.word.inflect(case, number).phonemes.into(),
+                            self.word
+                                .inflect(case, number)
+                                .expect("checked by Word::new")
+                                .phonemes
+                                .into(),
                         );
                     }
                 }
@@ -98,13 +106,29 @@ impl Word {
             Phoneme::E | Phoneme::Ee | Phoneme::I if no_coda => {
                 Err(Invalid { nom_sing })?
             },
-            _ => Ok(Self { nom_sing }),
+            _ => {
+                let this = Self { nom_sing };
+                for &case in BasicCase::ALL {
+                    for &number in Number::ALL {
+                        this.inflect(case, number)?;
+                    }
+                }
+                Ok(this)
+            },
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::bcase_fgender_number_table(Gender::Divine, |case, number| {
-            let inflected = self.inflect(case, number).phonemes.to_text();
+            let inflected = self
+                .inflect(case, number)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -114,8 +138,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -155,20 +181,36 @@ impl Word {
         Affix { coda, suffix }
     }
 
-    pub fn inflect(&self, case: BasicCase, number: Number) -> noun::Inflected {
+    pub fn inflect(
+        &self,
+        case: BasicCase,
+        number: Number,
+    ) -> anyhow::Result<noun::Inflected> {
         let affix = Self::affix(case, number);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::DivineNoun1.inflection_error(
+                &self.nom_sing,
+                format_args!("{} {} {}", case, Gender::Divine, number),
+                error,
+            )
+        })?;
+
+        Ok(noun::Inflected { phonemes, gender: Gender::Divine, case, number })
+    }
+
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let mut phonemes = match affix.coda {
-            Some(coda) => self.nom_sing.replace_final_coda(coda).unwrap(),
+            Some(coda) => self.nom_sing.replace_final_coda(coda)?,
             None => self.nom_sing.clone(),
         };
         if let Some(suffix) = affix.suffix {
-            phonemes = phonemes.append(suffix).unwrap();
+            phonemes = phonemes.append(suffix)?;
         }
 
-        noun::Inflected { phonemes, gender: Gender::Divine, case, number }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Divine Class 1",
             entries: component::bcase_fgender_number_table(
@@ -177,7 +219,7 @@ impl Word {
                     let affix = Self::affix(case, number);
                     UnmarkedList(vec![
                         WithStarAlphabet(affix.to_string()).to_dyn(),
-                        affix.to_string().to_dyn(),
+                        Romanized(scheme, affix.to_string()).to_dyn(),
                     ])
                     .to_dyn()
                 },
@@ -190,15 +232,29 @@ pub fn definitions() -> Vec<Definition> {
     vec![
         Definition {
             id: Id::new("star").unwrap(),
-            meanings: vec![Meaning::Star],
-            notes: "".blocking().to_dyn(),
+            meanings: vec![Meaning::STAR],
+            notes: dictionary::Notes {
+                examples: vec![dictionary::Example {
+                    text: "saŋé wiyíbi pa dsen.".to_owned(),
+                    translation: "The stars walked to the tree.".to_owned(),
+                }],
+                see_also: vec![dictionary::Reference::entry("kef", "fire")],
+                ..dictionary::Notes::default()
+            },
             word: Word::new(phonology::Word::parse_str("saŋ").unwrap())
                 .unwrap(),
         },
         Definition {
             id: Id::new("fire").unwrap(),
-            meanings: vec![Meaning::Fire],
-            notes: "".blocking().to_dyn(),
+            meanings: vec![Meaning::FIRE],
+            notes: dictionary::Notes {
+                examples: vec![dictionary::Example {
+                    text: "kef mací r saŋ.".to_owned(),
+                    translation: "The big fire is a star.".to_owned(),
+                }],
+                see_also: vec![dictionary::Reference::entry("saŋ", "star")],
+                ..dictionary::Notes::default()
+            },
             word: Word::new(phonology::Word::parse_str("kef").unwrap())
                 .unwrap(),
         },
@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{BasicCase, Gender, Number},
         noun,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,14 +32,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::DivineNoun2,
             class: "Divine Class 2".to_owned(),
+            part_of_speech: PartOfSpeech::Noun,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -44,7 +48,11 @@ impl Definition {
                     for &number in Number::ALL {
                         map.insert(
                             format!("{} {} {}", case, Gender::Divine, number),
-                            self.word.inflect(case, number).phonemes.into(),
+                            self.word
+                                .inflect(case, number)
+                                .expect("checked by Word::new")
+                                .phonemes
+                                .into(),
                         );
                     }
                 }
@@ -102,13 +110,29 @@ impl Word {
             | Some(Phoneme::Mg) | Some(Phoneme::Xw) => {
                 Err(Invalid { nom_sing })?
             },
-            _ => Ok(Self { nom_sing }),
+            _ => {
+                let this = Self { nom_sing };
+                for &case in BasicCase::ALL {
+                    for &number in Number::ALL {
+                        this.inflect(case, number)?;
+                    }
+                }
+                Ok(this)
+            },
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::bcase_fgender_number_table(Gender::Divine, |case, number| {
-            let inflected = self.inflect(case, number).phonemes.to_text();
+            let inflected = self
+                .inflect(case, number)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -118,8 +142,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -181,20 +207,34 @@ impl Word {
         }
     }
 
-    pub fn inflect(&self, case: BasicCase, number: Number) -> noun::Inflected {
+    pub fn inflect(
+        &self,
+        case: BasicCase,
+        number: Number,
+    ) -> anyhow::Result<noun::Inflected> {
         let affix = Self::affix(case, number);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::DivineNoun2.inflection_error(
+                &self.nom_sing,
+                format_args!("{} {} {}", case, Gender::Divine, number),
+                error,
+            )
+        })?;
+
+        Ok(noun::Inflected { phonemes, gender: Gender::Divine, case, number })
+    }
+
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let phonemes = match affix {
-            Affix::Coda(coda) => {
-                self.nom_sing.replace_final_coda(coda).unwrap()
-            },
-            Affix::Suffix(suffix) => self.nom_sing.append(suffix).unwrap(),
+            Affix::Coda(coda) => self.nom_sing.replace_final_coda(coda)?,
+            Affix::Suffix(suffix) => self.nom_sing.append(suffix)?,
             Affix::Empty => self.nom_sing.clone(),
         };
 
-        noun::Inflected { phonemes, gender: Gender::Divine, case, number }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Divine Class 2",
             entries: component::bcase_fgender_number_table(
@@ -203,7 +243,7 @@ impl Word {
                     let affix = Self::affix(case, number);
                     UnmarkedList(vec![
                         WithStarAlphabet(affix.to_string()).to_dyn(),
-                        affix.to_string().to_dyn(),
+                        Romanized(scheme, affix.to_string()).to_dyn(),
                     ])
                     .to_dyn()
                 },
@@ -215,8 +255,8 @@ impl Word {
 pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("wind").unwrap(),
-        meanings: vec![Meaning::Wind],
-        notes: "".blocking().to_dyn(),
+        meanings: vec![Meaning::WIND],
+        notes: dictionary::Notes::default(),
         word: Word::new(phonology::Word::parse_str("fwi").unwrap()).unwrap(),
     }]
 }
@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{BasicCase, Gender, Number},
         noun,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,14 +32,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::FullNoun1,
             class: "Full-Inflection Class 1".to_owned(),
+            part_of_speech: PartOfSpeech::Noun,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -47,6 +51,7 @@ impl Definition {
                                 format!("{} {} {}", case, gender, number),
                                 self.word
                                     .inflect(case, gender, number)
+                                    .expect("checked by Word::new")
                                     .phonemes
                                     .into(),
                             );
@@ -114,14 +119,31 @@ impl Word {
             Phoneme::E | Phoneme::Ee | Phoneme::I if no_coda => {
                 Err(Invalid { nom_div_sing })?
             },
-            _ => Ok(Self { nom_div_sing }),
+            _ => {
+                let this = Self { nom_div_sing };
+                for &case in BasicCase::ALL {
+                    for &gender in Gender::ALL {
+                        for &number in Number::ALL {
+                            this.inflect(case, gender, number)?;
+                        }
+                    }
+                }
+                Ok(this)
+            },
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::bcase_gender_number_table(|case, gender, number| {
-            let inflected =
-                self.inflect(case, gender, number).phonemes.to_text();
+            let inflected = self
+                .inflect(case, gender, number)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -131,8 +153,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -195,28 +219,38 @@ impl Word {
         case: BasicCase,
         gender: Gender,
         number: Number,
-    ) -> noun::Inflected {
+    ) -> anyhow::Result<noun::Inflected> {
         let affix = Self::affix(case, gender, number);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::FullNoun1.inflection_error(
+                &self.nom_div_sing,
+                format_args!("{} {} {}", case, gender, number),
+                error,
+            )
+        })?;
+
+        Ok(noun::Inflected { phonemes, case, gender, number })
+    }
+
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let mut phonemes = match (affix.nucleus, affix.coda) {
             (Some(nucleus), Some(coda)) => {
-                self.nom_div_sing.replace_final_rhyme(nucleus, coda).unwrap()
+                self.nom_div_sing.replace_final_rhyme(nucleus, coda)?
             },
             (Some(nucleus), None) => {
-                self.nom_div_sing.replace_final_nucleus(nucleus).unwrap()
-            },
-            (None, Some(coda)) => {
-                self.nom_div_sing.replace_final_coda(coda).unwrap()
+                self.nom_div_sing.replace_final_nucleus(nucleus)?
             },
+            (None, Some(coda)) => self.nom_div_sing.replace_final_coda(coda)?,
             (None, None) => self.nom_div_sing.clone(),
         };
         if let Some(suffix) = affix.suffix {
-            phonemes = phonemes.append(suffix).unwrap();
+            phonemes = phonemes.append(suffix)?;
         }
 
-        noun::Inflected { phonemes, case, gender, number }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Full-Inflection Class 1",
             entries: component::bcase_gender_number_table(
@@ -224,7 +258,7 @@ impl Word {
                     let affix = Self::affix(case, gender, number);
                     UnmarkedList(vec![
                         WithStarAlphabet(affix.to_string()).to_dyn(),
-                        affix.to_string().to_dyn(),
+                        Romanized(scheme, affix.to_string()).to_dyn(),
                     ])
                     .to_dyn()
                 },
@@ -236,8 +270,8 @@ impl Word {
 pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("eye").unwrap(),
-        meanings: vec![Meaning::Eye],
-        notes: "".blocking().to_dyn(),
+        meanings: vec![Meaning::EYE],
+        notes: dictionary::Notes::default(),
         word: Word::new(phonology::Word::parse_str("gas").unwrap()).unwrap(),
     }]
 }
@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{BasicCase, Gender, Number},
         noun,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,14 +32,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::FullNoun2,
             class: "Full-Inflection Class 2".to_owned(),
+            part_of_speech: PartOfSpeech::Noun,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -47,6 +51,7 @@ impl Definition {
                                 format!("{} {} {}", case, gender, number),
                                 self.word
                                     .inflect(case, gender, number)
+                                    .expect("checked by Word::new")
                                     .phonemes
                                     .into(),
                             );
@@ -110,14 +115,31 @@ impl Word {
             | Some(Phoneme::Mg) | Some(Phoneme::Xw) => {
                 Err(Invalid { nom_div_sing })?
             },
-            _ => Ok(Self { nom_div_sing }),
+            _ => {
+                let this = Self { nom_div_sing };
+                for &case in BasicCase::ALL {
+                    for &gender in Gender::ALL {
+                        for &number in Number::ALL {
+                            this.inflect(case, gender, number)?;
+                        }
+                    }
+                }
+                Ok(this)
+            },
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::bcase_gender_number_table(|case, gender, number| {
-            let inflected =
-                self.inflect(case, gender, number).phonemes.to_text();
+            let inflected = self
+                .inflect(case, gender, number)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -127,8 +149,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -306,20 +330,30 @@ impl Word {
         case: BasicCase,
         gender: Gender,
         number: Number,
-    ) -> noun::Inflected {
+    ) -> anyhow::Result<noun::Inflected> {
         let affix = Self::affix(case, gender, number);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::FullNoun2.inflection_error(
+                &self.nom_div_sing,
+                format_args!("{} {} {}", case, gender, number),
+                error,
+            )
+        })?;
+
+        Ok(noun::Inflected { phonemes, case, gender, number })
+    }
+
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let phonemes = match affix {
-            Affix::Coda(coda) => {
-                self.nom_div_sing.replace_final_coda(coda).unwrap()
-            },
-            Affix::Suffix(suffix) => self.nom_div_sing.append(suffix).unwrap(),
+            Affix::Coda(coda) => self.nom_div_sing.replace_final_coda(coda)?,
+            Affix::Suffix(suffix) => self.nom_div_sing.append(suffix)?,
             Affix::Empty => self.nom_div_sing.clone(),
         };
 
-        noun::Inflected { phonemes, case, gender, number }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Full-Inflection Class 2",
             entries: component::bcase_gender_number_table(
@@ -327,7 +361,7 @@ impl Word {
                     let affix = Self::affix(case, gender, number);
                     UnmarkedList(vec![
                         WithStarAlphabet(affix.to_string()).to_dyn(),
-                        affix.to_string().to_dyn(),
+                        Romanized(scheme, affix.to_string()).to_dyn(),
                     ])
                     .to_dyn()
                 },
@@ -339,8 +373,8 @@ impl Word {
 pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("tree").unwrap(),
-        meanings: vec![Meaning::Tree],
-        notes: "".blocking().to_dyn(),
+        meanings: vec![Meaning::TREE],
+        notes: dictionary::Notes::default(),
         word: Word::new(phonology::Word::parse_str("dse").unwrap()).unwrap(),
     }]
 }
@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{BasicCase, Case},
         postposition,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Parse, Phoneme},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,21 +32,26 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::UnaryPostposition,
             class: "Unary Class".to_owned(),
+            part_of_speech: PartOfSpeech::Postposition,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
                 for &case in Case::ALL {
                     map.insert(
                         format!("{}", case),
-                        self.word.inflect(case).phonemes.into(),
+                        self.word
+                            .inflect(case)
+                            .expect("checked by Word::new")
+                            .phonemes
+                            .into(),
                     );
                 }
                 map
@@ -91,13 +99,25 @@ pub struct Word {
 }
 
 impl Word {
-    pub fn new(nom: phonology::Word) -> Self {
-        Self { nom }
+    pub fn new(nom: phonology::Word) -> anyhow::Result<Self> {
+        let this = Self { nom };
+        for &case in Case::ALL {
+            this.inflect(case)?;
+        }
+        Ok(this)
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::case_table(|case| {
-            let inflected = self.inflect(case).phonemes.to_text();
+            let inflected = self
+                .inflect(case)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -107,8 +127,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -132,31 +154,39 @@ impl Word {
         Affix { nucleus, coda }
     }
 
-    pub fn inflect(&self, case: Case) -> postposition::Inflected {
+    pub fn inflect(
+        &self,
+        case: Case,
+    ) -> anyhow::Result<postposition::Inflected> {
         let affix = Self::affix(case);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::UnaryPostposition.inflection_error(&self.nom, case, error)
+        })?;
+
+        Ok(postposition::Inflected { phonemes, case })
+    }
 
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let phonemes = match (affix.nucleus, affix.coda) {
             (Some(nucleus), Some(coda)) => {
-                self.nom.replace_final_rhyme(nucleus, coda).unwrap()
-            },
-            (Some(nucleus), None) => {
-                self.nom.replace_final_nucleus(nucleus).unwrap()
+                self.nom.replace_final_rhyme(nucleus, coda)?
             },
-            (None, Some(coda)) => self.nom.replace_final_coda(coda).unwrap(),
+            (Some(nucleus), None) => self.nom.replace_final_nucleus(nucleus)?,
+            (None, Some(coda)) => self.nom.replace_final_coda(coda)?,
             (None, None) => self.nom.clone(),
         };
 
-        postposition::Inflected { phonemes, case }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Unary Class",
             entries: component::case_table(|case| {
                 let affix = Self::affix(case);
                 UnmarkedList(vec![
                     WithStarAlphabet(affix.to_string()).to_dyn(),
-                    affix.to_string().to_dyn(),
+                    Romanized(scheme, affix.to_string()).to_dyn(),
                 ])
                 .to_dyn()
             }),
@@ -167,8 +197,8 @@ impl Word {
 pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("to").unwrap(),
-        meanings: vec![Meaning::To],
-        notes: "".blocking().to_dyn(),
-        word: Word::new(phonology::Word::parse_str("pa").unwrap()),
+        meanings: vec![Meaning::TO],
+        notes: dictionary::Notes::default(),
+        word: Word::new(phonology::Word::parse_str("pa").unwrap()).unwrap(),
     }]
 }
@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{BasicCase, Case, Gender, Number, Person},
         pronoun,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Onset, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,14 +32,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::DemonstrativePronoun,
             class: "Demonstrative Class".to_owned(),
+            part_of_speech: PartOfSpeech::Pronoun,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -51,6 +55,7 @@ impl Definition {
                                     ),
                                     self.word
                                         .inflect(person, case, gender, number)
+                                        .expect("checked by Word::new")
                                         .phonemes
                                         .into(),
                                 );
@@ -115,15 +120,32 @@ impl Word {
             | Phoneme::M
             | Phoneme::W
             | Phoneme::Mg => Err(Invalid { fst_nom_div_sing })?,
-            _ => Ok(Self { fst_nom_div_sing }),
+            _ => {
+                let this = Self { fst_nom_div_sing };
+                for &person in Person::ALL {
+                    for &case in Case::ALL {
+                        for &gender in Gender::ALL {
+                            for &number in Number::ALL {
+                                this.inflect(person, case, gender, number)?;
+                            }
+                        }
+                    }
+                }
+                Ok(this)
+            },
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::person_case_gender_number_table(
             |person, case, gender, number| {
                 let inflected = self
                     .inflect(person, case, gender, number)
+                    .expect("checked by Word::new")
                     .phonemes
                     .to_text();
                 let link = Link {
@@ -135,8 +157,10 @@ impl Word {
                     )),
                     text: WithStarAlphabet(inflected.clone()),
                 };
-                let component =
-                    UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+                let component = UnmarkedList(vec![
+                    link.to_dyn(),
+                    Romanized(scheme, inflected).to_dyn(),
+                ]);
                 component.blocking().to_dyn()
             },
         )
@@ -236,27 +260,36 @@ impl Word {
         case: Case,
         gender: Gender,
         number: Number,
-    ) -> pronoun::Inflected {
+    ) -> anyhow::Result<pronoun::Inflected> {
         let affix = Self::affix(person, case, gender, number);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::DemonstrativePronoun.inflection_error(
+                &self.fst_nom_div_sing,
+                format_args!("{} {} {} {}", person, case, gender, number),
+                error,
+            )
+        })?;
+
+        Ok(pronoun::Inflected { phonemes, person, case, gender, number })
+    }
+
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let phonemes = match (affix.prefix, affix.nucleus) {
             (Some(prefix), Some(nucleus)) => self
                 .fst_nom_div_sing
-                .prepend(prefix)
-                .unwrap()
-                .replace_final_nucleus(nucleus)
-                .unwrap(),
-            (Some(prefix), None) => {
-                self.fst_nom_div_sing.prepend(prefix).unwrap()
-            },
+                .prepend(prefix)?
+                .replace_final_nucleus(nucleus)?,
+            (Some(prefix), None) => self.fst_nom_div_sing.prepend(prefix)?,
             (None, Some(nucleus)) => {
-                self.fst_nom_div_sing.replace_final_nucleus(nucleus).unwrap()
+                self.fst_nom_div_sing.replace_final_nucleus(nucleus)?
             },
             (None, None) => self.fst_nom_div_sing.clone(),
         };
-        pronoun::Inflected { phonemes, person, case, gender, number }
+
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Demonstrative Class",
             entries: component::person_case_gender_number_table(
@@ -264,7 +297,7 @@ impl Word {
                     let affix = Self::affix(person, case, gender, number);
                     UnmarkedList(vec![
                         WithStarAlphabet(affix.to_string()).to_dyn(),
-                        affix.to_string().to_dyn(),
+                        Romanized(scheme, affix.to_string()).to_dyn(),
                     ])
                     .to_dyn()
                 },
@@ -279,38 +312,65 @@ pub fn definitions() -> Vec<Definition> {
             id: Id::new("this-near-far").unwrap(),
             word: Word::new(phonology::Word::parse_str("nyá").unwrap())
                 .unwrap(),
-            meanings: vec![Meaning::ThisNear, Meaning::ThatFar],
-            notes: "Sense 2 is only used if there is no contrast between near \
-                    and far demonstratives."
-                .blocking()
-                .to_dyn(),
+            meanings: vec![Meaning::THIS_NEAR, Meaning::THAT_FAR],
+            notes: dictionary::Notes {
+                usage: Some(
+                    "Sense 2 is only used if there is no contrast between \
+                     near and far demonstratives."
+                        .blocking()
+                        .to_dyn(),
+                ),
+                examples: vec![dictionary::Example {
+                    text: "nyá gas ḱen saŋ.".to_owned(),
+                    translation: "This is the eye of the star.".to_owned(),
+                }],
+                see_also: vec![
+                    dictionary::Reference::entry("reŋ", "this-far"),
+                    dictionary::Reference::entry("xím", "this-very-far"),
+                ],
+                ..dictionary::Notes::default()
+            },
         },
         Definition {
             id: Id::new("this-very-far").unwrap(),
             word: Word::new(phonology::Word::parse_str("xím").unwrap())
                 .unwrap(),
-            meanings: vec![Meaning::ThatVeryFar],
-            notes: "".blocking().to_dyn(),
+            meanings: vec![Meaning::THAT_VERY_FAR],
+            notes: dictionary::Notes {
+                see_also: vec![
+                    dictionary::Reference::entry("nyá", "this-near-far"),
+                    dictionary::Reference::entry("reŋ", "this-far"),
+                ],
+                ..dictionary::Notes::default()
+            },
         },
         Definition {
             id: Id::new("this-far").unwrap(),
             word: Word::new(phonology::Word::parse_str("reŋ").unwrap())
                 .unwrap(),
-            meanings: vec![Meaning::ThatFar],
-            notes: vec![
-                "Note: only used when contrast is needed with ".to_dyn(),
-                Link {
-                    location: Location::internal(format!(
-                        "{}/dictionary/nyá#this-near-far",
-                        StarLang.path()
-                    )),
-                    text: WithStarAlphabet("nyá"),
-                }
-                .to_dyn(),
-                ".".to_dyn(),
-            ]
-            .blocking()
-            .to_dyn(),
+            meanings: vec![Meaning::THAT_FAR],
+            notes: dictionary::Notes {
+                usage: Some(
+                    vec![
+                        "Only used when contrast is needed with ".to_dyn(),
+                        Link {
+                            location: dictionary::lexeme_location(
+                                &Id::new("this-near-far").unwrap(),
+                            ),
+                            text: WithStarAlphabet("nyá"),
+                        }
+                        .to_dyn(),
+                        ".".to_dyn(),
+                    ]
+                    .blocking()
+                    .to_dyn(),
+                ),
+                see_also: vec![
+                    dictionary::Reference::entry("nyá", "this-near-far"),
+                    dictionary::Reference::entry("xím", "this-very-far"),
+                ],
+                ..dictionary::Notes::default()
+            },
         },
     ]
 }
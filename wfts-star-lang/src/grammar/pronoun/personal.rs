@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{BasicCase, Case, Gender, Number, Person},
         pronoun,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Parse, Phoneme},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,14 +32,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::PersonalPronoun,
             class: "Personal Class".to_owned(),
+            part_of_speech: PartOfSpeech::Pronoun,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -51,6 +55,7 @@ impl Definition {
                                     ),
                                     self.word
                                         .inflect(person, case, gender, number)
+                                        .expect("checked by Word::new")
                                         .phonemes
                                         .into(),
                                 );
@@ -136,15 +141,32 @@ impl Word {
             | (_, Some(Phoneme::H))
             | (_, Some(Phoneme::S))
             | (_, Some(Phoneme::W)) => Err(Invalid { fst_nom_div_sing })?,
-            _ => Ok(Self { fst_nom_div_sing }),
+            _ => {
+                let this = Self { fst_nom_div_sing };
+                for &person in Person::ALL {
+                    for &case in Case::ALL {
+                        for &gender in Gender::ALL {
+                            for &number in Number::ALL {
+                                this.inflect(person, case, gender, number)?;
+                            }
+                        }
+                    }
+                }
+                Ok(this)
+            },
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::person_case_gender_number_table(
             |person, case, gender, number| {
                 let inflected = self
                     .inflect(person, case, gender, number)
+                    .expect("checked by Word::new")
                     .phonemes
                     .to_text();
                 let link = Link {
@@ -156,8 +178,10 @@ impl Word {
                     )),
                     text: WithStarAlphabet(inflected.clone()),
                 };
-                let component =
-                    UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+                let component = UnmarkedList(vec![
+                    link.to_dyn(),
+                    Romanized(scheme, inflected).to_dyn(),
+                ]);
                 component.blocking().to_dyn()
             },
         )
@@ -264,12 +288,12 @@ impl Word {
             | (Second, Basic(Postpositional), Animate, _)
             | (Second, _, _, Nullar)
             | (Second, _, _, Collective) => Some(second2),
-            (Second, _, ..) => Some(second),
+            (Second, ..) => Some(second),
             (Third, _, _, Collective)
             | (Third, _, _, Nullar)
             | (Third, Passive, ..) => Some(third2),
             (Third, _, Animate, _) => Some(third3),
-            (Third, _, ..) => Some(third),
+            (Third, ..) => Some(third),
         };
 
         Affix { onset_outer_medial, onset_inner, nucleus, coda }
@@ -281,28 +305,39 @@ impl Word {
         case: Case,
         gender: Gender,
         number: Number,
-    ) -> pronoun::Inflected {
+    ) -> anyhow::Result<pronoun::Inflected> {
         let affix = Self::affix(person, case, gender, number);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::PersonalPronoun.inflection_error(
+                &self.fst_nom_div_sing,
+                format_args!("{} {} {} {}", person, case, gender, number),
+                error,
+            )
+        })?;
+
+        Ok(pronoun::Inflected { phonemes, person, case, gender, number })
+    }
+
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let onset = self.fst_nom_div_sing.syllables().first().unwrap().onset();
-        let new_onset = onset
-            .replace_keep_plosive(affix.onset_outer_medial, affix.onset_inner)
-            .unwrap();
-        let replaced =
-            self.fst_nom_div_sing.replace_initial_onset(new_onset).unwrap();
+        let new_onset = onset.replace_keep_plosive(
+            affix.onset_outer_medial,
+            affix.onset_inner,
+        )?;
+        let replaced = self.fst_nom_div_sing.replace_initial_onset(new_onset)?;
         let phonemes = match (affix.nucleus, affix.coda) {
             (Some(nucleus), Some(coda)) => {
-                replaced.replace_initial_rhyme(nucleus, coda).unwrap()
+                replaced.replace_initial_rhyme(nucleus, coda)?
             },
-            (Some(nucleus), None) => {
-                replaced.replace_initial_nucleus(nucleus).unwrap()
-            },
-            (None, Some(coda)) => replaced.replace_initial_coda(coda).unwrap(),
+            (Some(nucleus), None) => replaced.replace_initial_nucleus(nucleus)?,
+            (None, Some(coda)) => replaced.replace_initial_coda(coda)?,
             (None, None) => replaced,
         };
-        pronoun::Inflected { phonemes, person, case, gender, number }
+
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Personal Class",
             entries: component::person_case_gender_number_table(
@@ -310,7 +345,7 @@ impl Word {
                     let affix = Self::affix(person, case, gender, number);
                     UnmarkedList(vec![
                         WithStarAlphabet(affix.to_string()).to_dyn(),
-                        affix.to_string().to_dyn(),
+                        Romanized(scheme, affix.to_string()).to_dyn(),
                     ])
                     .to_dyn()
                 },
@@ -324,14 +359,32 @@ pub fn definitions() -> Vec<Definition> {
         Definition {
             id: Id::new("informal-personal").unwrap(),
             word: Word::new(phonology::Word::parse_str("fá").unwrap()).unwrap(),
-            meanings: vec![Meaning::InformalPersonal],
-            notes: "".blocking().to_dyn(),
+            meanings: vec![Meaning::INFORMAL_PERSONAL],
+            notes: dictionary::Notes {
+                register: Some(dictionary::Register::Informal),
+                see_also: vec![dictionary::Reference::entry(
+                    "jí",
+                    "formal-personal",
+                )],
+                ..dictionary::Notes::default()
+            },
         },
         Definition {
             id: Id::new("formal-personal").unwrap(),
             word: Word::new(phonology::Word::parse_str("jí").unwrap()).unwrap(),
-            meanings: vec![Meaning::FormalPersonal],
-            notes: "".blocking().to_dyn(),
+            meanings: vec![Meaning::FORMAL_PERSONAL],
+            notes: dictionary::Notes {
+                register: Some(dictionary::Register::Formal),
+                examples: vec![dictionary::Example {
+                    text: "kef, jí wiyafa pa keys.".to_owned(),
+                    translation: "Fire, let me walk to you.".to_owned(),
+                }],
+                see_also: vec![dictionary::Reference::entry(
+                    "fá",
+                    "informal-personal",
+                )],
+                ..dictionary::Notes::default()
+            },
         },
     ]
 }
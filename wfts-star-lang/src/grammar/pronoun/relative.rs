@@ -1,12 +1,15 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{BasicCase, Case, Gender, Number, Person},
         pronoun,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Coda, Onset, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -29,14 +32,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::RelativePronoun,
             class: "Demonstrative Class".to_owned(),
+            part_of_speech: PartOfSpeech::Pronoun,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -51,6 +55,7 @@ impl Definition {
                                     ),
                                     self.word
                                         .inflect(person, case, gender, number)
+                                        .expect("checked by Word::new")
                                         .phonemes
                                         .into(),
                                 );
@@ -108,15 +113,32 @@ impl Word {
             | Phoneme::E
             | Phoneme::I
             | Phoneme::Ii => Err(Invalid { fst_nom_div_sing })?,
-            _ => Ok(Self { fst_nom_div_sing }),
+            _ => {
+                let this = Self { fst_nom_div_sing };
+                for &person in Person::ALL {
+                    for &case in Case::ALL {
+                        for &gender in Gender::ALL {
+                            for &number in Number::ALL {
+                                this.inflect(person, case, gender, number)?;
+                            }
+                        }
+                    }
+                }
+                Ok(this)
+            },
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::person_case_gender_number_table(
             |person, case, gender, number| {
                 let inflected = self
                     .inflect(person, case, gender, number)
+                    .expect("checked by Word::new")
                     .phonemes
                     .to_text();
                 let link = Link {
@@ -128,8 +150,10 @@ impl Word {
                     )),
                     text: WithStarAlphabet(inflected.clone()),
                 };
-                let component =
-                    UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+                let component = UnmarkedList(vec![
+                    link.to_dyn(),
+                    Romanized(scheme, inflected).to_dyn(),
+                ]);
                 component.blocking().to_dyn()
             },
         )
@@ -256,16 +280,29 @@ impl Word {
         case: Case,
         gender: Gender,
         number: Number,
-    ) -> pronoun::Inflected {
+    ) -> anyhow::Result<pronoun::Inflected> {
         let affix = Self::affix(person, case, gender, number);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::RelativePronoun.inflection_error(
+                &self.fst_nom_div_sing,
+                format_args!("{} {} {} {}", person, case, gender, number),
+                error,
+            )
+        })?;
+
+        Ok(pronoun::Inflected { phonemes, person, case, gender, number })
+    }
+
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let mut phonemes = self.fst_nom_div_sing.clone();
         for &syllable in &affix.suffix {
-            phonemes = phonemes.append(syllable).unwrap();
+            phonemes = phonemes.append(syllable)?;
         }
-        pronoun::Inflected { phonemes, person, case, gender, number }
+
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Relative Class",
             entries: component::person_case_gender_number_table(
@@ -273,7 +310,7 @@ impl Word {
                     let affix = Self::affix(person, case, gender, number);
                     UnmarkedList(vec![
                         WithStarAlphabet(affix.to_string()).to_dyn(),
-                        affix.to_string().to_dyn(),
+                        Romanized(scheme, affix.to_string()).to_dyn(),
                     ])
                     .to_dyn()
                 },
@@ -286,7 +323,7 @@ pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("what").unwrap(),
         word: Word::new(phonology::Word::parse_str("kas").unwrap()).unwrap(),
-        meanings: vec![Meaning::What, Meaning::ThatRelative],
-        notes: "".blocking().to_dyn(),
+        meanings: vec![Meaning::WHAT, Meaning::THAT_RELATIVE],
+        notes: dictionary::Notes::default(),
     }]
 }
@@ -1,6 +1,6 @@
 use crate::{
     component,
-    component::WithStarAlphabet,
+    component::{Romanized, WithStarAlphabet},
     dictionary,
     grammar::{
         grammemes::{
@@ -10,8 +10,11 @@ use crate::{
             Tense,
         },
         verb,
+        Paradigm,
+        PartOfSpeech,
     },
     phonology::{self, Parse, Phoneme, Syllable},
+    romanization::Scheme,
     StarLang,
 };
 use indexmap::IndexMap;
@@ -34,14 +37,15 @@ pub struct Definition {
     pub id: Id,
     pub word: Word,
     pub meanings: Vec<Meaning>,
-    pub notes: DynComponent,
+    pub notes: dictionary::Notes,
 }
 
 impl Definition {
     pub fn to_dict_entry(self) -> dictionary::Entry {
         dictionary::Entry {
-            inflection_table: self.word.table(&self.id),
+            paradigm: Paradigm::RegularVerb1,
             class: "Regular Class 1".to_owned(),
+            part_of_speech: PartOfSpeech::Verb,
             id: self.id,
             inflections: {
                 let mut map = IndexMap::new();
@@ -49,7 +53,11 @@ impl Definition {
                     for &tense in Tense::ALL {
                         map.insert(
                             format!("{} {}", person, tense),
-                            self.word.inflect(person, tense).phonemes.into(),
+                            self.word
+                                .inflect(person, tense)
+                                .expect("checked by Word::new")
+                                .phonemes
+                                .into(),
                         );
                     }
                 }
@@ -103,13 +111,27 @@ impl Word {
         if fst_ind_pres.phonemes().next_back() != Some(Phoneme::A) {
             Err(Invalid { fst_ind_pres })?
         } else {
-            Ok(Self { fst_ind_pres })
+            let this = Self { fst_ind_pres };
+            for &person in Person::ALL {
+                for &tense in Tense::ALL {
+                    this.inflect(person, tense)?;
+                }
+            }
+            Ok(this)
         }
     }
 
-    pub fn table(&self, entry_id: &Id) -> table::Entries<DynComponent> {
+    pub fn table(
+        &self,
+        entry_id: &Id,
+        scheme: Scheme,
+    ) -> table::Entries<DynComponent> {
         component::person_tense_table(|person, tense| {
-            let inflected = self.inflect(person, tense).phonemes.to_text();
+            let inflected = self
+                .inflect(person, tense)
+                .expect("checked by Word::new")
+                .phonemes
+                .to_text();
             let link = Link {
                 location: Location::internal(format!(
                     "{}/dictionary/{}#{}",
@@ -119,8 +141,10 @@ impl Word {
                 )),
                 text: WithStarAlphabet(inflected.clone()),
             };
-            let component =
-                UnmarkedList(vec![link.to_dyn(), inflected.to_dyn()]);
+            let component = UnmarkedList(vec![
+                link.to_dyn(),
+                Romanized(scheme, inflected).to_dyn(),
+            ]);
             component.blocking().to_dyn()
         })
     }
@@ -157,30 +181,45 @@ impl Word {
         Affix { nucleus, suffix }
     }
 
-    pub fn inflect(&self, person: Person, tense: Tense) -> verb::Inflected {
+    pub fn inflect(
+        &self,
+        person: Person,
+        tense: Tense,
+    ) -> anyhow::Result<verb::Inflected> {
         let affix = Self::affix(person, tense);
+        let phonemes = self.apply(affix).map_err(|error| {
+            Paradigm::RegularVerb1.inflection_error(
+                &self.fst_ind_pres,
+                format_args!("{} {}", person, tense),
+                error,
+            )
+        })?;
+
+        Ok(verb::Inflected { person, tense, phonemes })
+    }
 
+    fn apply(&self, affix: Affix) -> anyhow::Result<phonology::Word> {
         let mut phonemes = self.fst_ind_pres.clone();
 
         if let Some(nucleus) = affix.nucleus {
-            phonemes = phonemes.replace_final_nucleus(nucleus).unwrap();
+            phonemes = phonemes.replace_final_nucleus(nucleus)?;
         }
 
         if let Some(suffix) = affix.suffix {
-            phonemes = phonemes.append(suffix).unwrap();
+            phonemes = phonemes.append(suffix)?;
         }
 
-        verb::Inflected { person, tense, phonemes }
+        Ok(phonemes)
     }
 
-    pub fn affix_table() -> Table<&'static str, DynComponent> {
+    pub fn affix_table(scheme: Scheme) -> Table<&'static str, DynComponent> {
         Table {
             title: "Inflection For Regular Class 1",
             entries: component::person_tense_table(|person, tense| {
                 let affix = Self::affix(person, tense);
                 UnmarkedList(vec![
                     WithStarAlphabet(affix.to_string()).to_dyn(),
-                    affix.to_string().to_dyn(),
+                    Romanized(scheme, affix.to_string()).to_dyn(),
                 ])
                 .to_dyn()
             }),
@@ -191,8 +230,15 @@ impl Word {
 pub fn definitions() -> Vec<Definition> {
     vec![Definition {
         id: Id::new("to-walk").unwrap(),
-        meanings: vec![Meaning::ToWalk],
-        notes: "".blocking().to_dyn(),
+        meanings: vec![Meaning::TO_WALK],
+        notes: dictionary::Notes {
+            examples: vec![dictionary::Example {
+                text: "fwi eta kef wiyíbi pa saysé.".to_owned(),
+                translation: "The wind and the fire walked to the stars."
+                    .to_owned(),
+            }],
+            ..dictionary::Notes::default()
+        },
         word: Word::new(phonology::Word::parse_str("wiya").unwrap()).unwrap(),
     }]
 }
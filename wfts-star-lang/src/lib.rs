@@ -1,13 +1,23 @@
 pub mod component;
 pub mod phonology;
+pub mod collation;
 pub mod grammar;
 pub mod morphology;
 pub mod dictionary;
+pub mod generator;
+pub mod romanization;
+pub mod script;
+pub mod audio;
+pub mod corpus;
+pub mod messages;
 pub mod pages;
 
-use std::collections::HashMap;
-use wfts_lang::{Lang, LangCode};
-use wfts_pedia_ssg::site::Directory;
+#[cfg(test)]
+mod properties;
+
+use romanization::Scheme;
+use wfts_lang::{semantics::registry::Registry, Lang, LangCode};
+use wfts_pedia_ssg::{i18n::UiLang, site::Directory};
 
 #[derive(Debug, Clone)]
 pub struct StarLang;
@@ -17,13 +27,36 @@ impl Lang for StarLang {
         LangCode::parse("str-cls").unwrap()
     }
 
-    fn subsite(&self) -> Directory {
-        let mut dir = Directory { contents: HashMap::new() };
-        pages::index::make(&mut dir);
-        pages::phonology::make(&mut dir);
-        pages::grammar::make(&mut dir);
-        pages::writing::make(&mut dir);
-        pages::dictionary::make(&mut dir);
+    fn register_meanings(&self, registry: &mut Registry) -> anyhow::Result<()> {
+        for entry in dictionary::Entry::all() {
+            for meaning in entry.meanings {
+                registry.register(meaning)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn subsite(&self, ui_lang: UiLang) -> Directory {
+        self.romanized_subsite(ui_lang, Scheme::default())
+    }
+}
+
+impl StarLang {
+    /// The pages of the language, with the words shown next to the star
+    /// alphabet romanized in the given scheme. Pages still link to the
+    /// words by their standard romanization.
+    pub fn romanized_subsite(
+        &self,
+        ui_lang: UiLang,
+        scheme: Scheme,
+    ) -> Directory {
+        let mut dir = Directory::default();
+        pages::index::make(&mut dir, ui_lang);
+        pages::phonology::make(&mut dir, ui_lang);
+        pages::grammar::make(&mut dir, ui_lang, scheme);
+        pages::writing::make(&mut dir, ui_lang);
+        pages::dictionary::make(&mut dir, ui_lang, scheme);
+        pages::texts::make(&mut dir, ui_lang, scheme);
         dir
     }
 }
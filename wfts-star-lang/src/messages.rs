@@ -0,0 +1,776 @@
+//! Text of the fixed parts of the pages, such as titles, headings and
+//! boilerplate paragraphs, in each language the encyclopedia is written in.
+//! Articles other than the phonology are written in English only, for now.
+
+use crate::{
+    corpus::Kind,
+    grammar::PartOfSpeech,
+    phonology::{
+        features::{Height, Laryngeal, Manner, Place},
+        prosody::{Intonation, Weight},
+        PhonemeClass,
+    },
+};
+use wfts_lang::semantics::{Relation, SynLevel};
+use wfts_pedia_ssg::i18n::UiLang;
+
+#[derive(Debug, Clone)]
+pub struct Messages {
+    pub ui_lang: UiLang,
+
+    pub lang_name: &'static str,
+    pub index_intro: [&'static str; 2],
+    pub see_article: &'static str,
+    pub phonology: &'static str,
+    pub writing_system: &'static str,
+    pub grammar: &'static str,
+    pub dictionary: &'static str,
+    pub texts: &'static str,
+
+    pub phonology_title: &'static str,
+    pub writing_title: &'static str,
+    pub grammar_title: &'static str,
+    pub dictionary_title: &'static str,
+    pub texts_title: &'static str,
+
+    pub dictionary_intro: &'static str,
+    pub meaning_index_intro: &'static str,
+    pub meaning_index_link: &'static str,
+    pub list_of_entries: &'static str,
+    pub list_of_words: &'static str,
+    pub downloads: &'static str,
+
+    pub meaning_index_title: &'static str,
+    pub meaning_index_body: &'static str,
+    pub no_words: &'static str,
+    pub related_meanings: &'static str,
+    pub semantic_network: &'static str,
+    pub semantic_network_body: &'static str,
+    pub other_relations: &'static str,
+    pub semantic_network_download: &'static str,
+
+    pub definition: &'static str,
+    pub inflected_for: &'static str,
+    pub inflection: &'static str,
+    pub inflection_for: &'static str,
+    pub attested_in: &'static str,
+    pub not_attested: &'static str,
+    pub line: &'static str,
+    pub or: &'static str,
+    pub see_the: &'static str,
+    pub full_entry: &'static str,
+    pub romanization: &'static str,
+    pub pronunciation: &'static str,
+    pub phonemic: &'static str,
+    pub early_accents: &'static str,
+    pub late_accents: &'static str,
+    pub sound_changes: &'static str,
+    pub derivation_of: &'static str,
+    pub stage: &'static str,
+    pub rule: &'static str,
+    pub result: &'static str,
+    pub etymology: &'static str,
+    pub from: &'static str,
+    pub usage: &'static str,
+    pub register: &'static str,
+    pub this_word_is: &'static str,
+    pub examples: &'static str,
+    pub see_also: &'static str,
+
+    pub text: &'static str,
+    pub text_in: &'static str,
+    pub text_intro: &'static str,
+    pub texts_intro: &'static str,
+    pub show_gloss: &'static str,
+
+    pub synthesized: &'static str,
+
+    pub phonology_intro: [&'static str; 3],
+    pub consonants: &'static str,
+    pub consonant_chart: &'static str,
+    pub allophonic_variation: &'static str,
+    pub retraction_body: &'static str,
+    pub retraction_table: &'static str,
+    pub palatalization_body: &'static str,
+    pub palatalization_table: &'static str,
+    pub palatalization_spread: &'static str,
+    pub vowels: &'static str,
+    pub vowel_chart: &'static str,
+    pub vowel_allophony_body: &'static str,
+    pub vowel_allophone_table: &'static str,
+    pub vowel_trapezoid_alt: &'static str,
+    pub vowel_trapezoid_legend: &'static str,
+    pub phonotactics: &'static str,
+    pub phonotactics_body: &'static str,
+    pub syllabic_structure: &'static str,
+    pub outer_onset: &'static str,
+    pub medial_onset: &'static str,
+    pub inner_onset: &'static str,
+    pub nucleus: &'static str,
+    pub inner_coda: &'static str,
+    pub outer_coda: &'static str,
+    pub valid_clusters: &'static str,
+    pub outer_and_medial_onsets: &'static str,
+    pub codas: &'static str,
+    pub clusters: &'static str,
+    pub prosody: &'static str,
+    pub stress_body: &'static str,
+    pub stress: &'static str,
+    pub intonation_body: &'static str,
+    pub intonation: &'static str,
+    pub connected_speech: &'static str,
+    pub connected_speech_body: &'static str,
+    pub sandhi: &'static str,
+    pub phoneme: &'static str,
+    pub before: &'static str,
+    pub after: &'static str,
+    pub elsewhere: &'static str,
+    pub palatalized: &'static str,
+    pub short: &'static str,
+    pub long: &'static str,
+    pub default_case: &'static str,
+    pub word: &'static str,
+    pub weights: &'static str,
+    pub transcription: &'static str,
+    pub phrase: &'static str,
+    pub isolated_words: &'static str,
+    pub early_accent: &'static str,
+    pub late_accent: &'static str,
+}
+
+impl Messages {
+    pub fn get(ui_lang: UiLang) -> &'static Self {
+        match ui_lang {
+            UiLang::English => &ENGLISH,
+            UiLang::BrazilianPortuguese => &BRAZILIAN_PORTUGUESE,
+        }
+    }
+
+    pub fn part_of_speech(&self, part_of_speech: PartOfSpeech) -> &'static str {
+        match self.ui_lang {
+            UiLang::English => match part_of_speech {
+                PartOfSpeech::Noun => "noun",
+                PartOfSpeech::Adjective => "adjective",
+                PartOfSpeech::Pronoun => "pronoun",
+                PartOfSpeech::Postposition => "postposition",
+                PartOfSpeech::Conjunction => "conjunction",
+                PartOfSpeech::Verb => "verb",
+            },
+            UiLang::BrazilianPortuguese => match part_of_speech {
+                PartOfSpeech::Noun => "substantivo",
+                PartOfSpeech::Adjective => "adjetivo",
+                PartOfSpeech::Pronoun => "pronome",
+                PartOfSpeech::Postposition => "posposição",
+                PartOfSpeech::Conjunction => "conjunção",
+                PartOfSpeech::Verb => "verbo",
+            },
+        }
+    }
+
+    pub fn relation(&self, relation: Relation) -> String {
+        let level = |level| match level {
+            SynLevel::L1 => 1,
+            SynLevel::L2 => 2,
+            SynLevel::L3 => 3,
+            SynLevel::L4 => 4,
+            SynLevel::L5 => 5,
+        };
+        match self.ui_lang {
+            UiLang::English => relation.to_string(),
+            UiLang::BrazilianPortuguese => match relation {
+                Relation::Unrelated => "sem relação".to_owned(),
+                Relation::Synonym(SynLevel::L5) => "sinônimo".to_owned(),
+                Relation::Synonym(syn_level) => {
+                    format!("sinônimo (nível {})", level(syn_level))
+                },
+                Relation::Antonym => "antônimo".to_owned(),
+                Relation::Hiponym => "hipônimo".to_owned(),
+                Relation::Hyperonym => "hiperônimo".to_owned(),
+                Relation::Coordinate => "coordenado".to_owned(),
+            },
+        }
+    }
+
+    /// A text of the given kind, as the subject of a sentence.
+    pub fn text_kind(&self, kind: Kind) -> &'static str {
+        match (self.ui_lang, kind) {
+            (UiLang::English, Kind::Story) => "A story",
+            (UiLang::English, Kind::Prayer) => "A prayer",
+            (UiLang::BrazilianPortuguese, Kind::Story) => "Uma história",
+            (UiLang::BrazilianPortuguese, Kind::Prayer) => "Uma oração",
+        }
+    }
+
+    /// Credit of a recording.
+    pub fn recorded_by(&self, speaker: &str, date: Option<&str>) -> String {
+        match (self.ui_lang, date) {
+            (UiLang::English, Some(date)) => {
+                format!("Recorded by {} on {}", speaker, date)
+            },
+            (UiLang::English, None) => format!("Recorded by {}", speaker),
+            (UiLang::BrazilianPortuguese, Some(date)) => {
+                format!("Gravado por {} em {}", speaker, date)
+            },
+            (UiLang::BrazilianPortuguese, None) => {
+                format!("Gravado por {}", speaker)
+            },
+        }
+    }
+
+    pub fn consonant_count(&self, count: usize) -> String {
+        match self.ui_lang {
+            UiLang::English => format!(
+                "Classical Star Language had {} phonemic consonants.",
+                count
+            ),
+            UiLang::BrazilianPortuguese => format!(
+                "A Língua Estelar Clássica tinha {} consoantes fonêmicas.",
+                count
+            ),
+        }
+    }
+
+    pub fn vowel_count(&self, count: usize) -> String {
+        match self.ui_lang {
+            UiLang::English => format!(
+                "Classical Star Language had {} phonemic vowels. The vowels \
+                 do not contrast on backness or rounding, only on height and \
+                 length.",
+                count
+            ),
+            UiLang::BrazilianPortuguese => format!(
+                "A Língua Estelar Clássica tinha {} vogais fonêmicas. As \
+                 vogais não contrastam em anterioridade nem em \
+                 arredondamento, apenas em altura e duração.",
+                count
+            ),
+        }
+    }
+
+    /// How many onsets, codas and syllables there are, and the inner onsets
+    /// any onset may be followed by.
+    pub fn cluster_counts(
+        &self,
+        onsets: usize,
+        codas: usize,
+        syllables: usize,
+        inner_onsets: &str,
+    ) -> String {
+        match self.ui_lang {
+            UiLang::English => format!(
+                "Enumerating the restrictions above gives {} valid onsets, {} \
+                 valid codas and {} valid syllables. Any onset below may be \
+                 followed by an inner onset: {}.",
+                onsets, codas, syllables, inner_onsets
+            ),
+            UiLang::BrazilianPortuguese => format!(
+                "Enumerar as restrições acima dá {} ataques válidos, {} \
+                 codas válidas e {} sílabas válidas. Qualquer ataque abaixo \
+                 pode ser seguido por um ataque interno: {}.",
+                onsets, codas, syllables, inner_onsets
+            ),
+        }
+    }
+
+    pub fn place(&self, place: Place) -> &'static str {
+        match self.ui_lang {
+            UiLang::English => match place {
+                Place::Labial => "labial",
+                Place::Alveolar => "alveolar",
+                Place::Palatal => "palatal",
+                Place::Velar => "velar",
+                Place::LabioVelar => "labio-velar",
+                Place::Pharyngeal => "pharyngeal",
+            },
+            UiLang::BrazilianPortuguese => match place {
+                Place::Labial => "labial",
+                Place::Alveolar => "alveolar",
+                Place::Palatal => "palatal",
+                Place::Velar => "velar",
+                Place::LabioVelar => "labiovelar",
+                Place::Pharyngeal => "faríngea",
+            },
+        }
+    }
+
+    pub fn manner(&self, manner: Manner) -> &'static str {
+        match self.ui_lang {
+            UiLang::English => match manner {
+                Manner::Stop => "stop",
+                Manner::Nasal => "nasal",
+                Manner::Fricative => "fricative",
+                Manner::Approximant => "approximant",
+                Manner::Vowel => "vowel",
+            },
+            UiLang::BrazilianPortuguese => match manner {
+                Manner::Stop => "oclusiva",
+                Manner::Nasal => "nasal",
+                Manner::Fricative => "fricativa",
+                Manner::Approximant => "aproximante",
+                Manner::Vowel => "vogal",
+            },
+        }
+    }
+
+    pub fn laryngeal(&self, laryngeal: Laryngeal) -> &'static str {
+        match self.ui_lang {
+            UiLang::English => match laryngeal {
+                Laryngeal::Ejective => "ejective",
+                Laryngeal::Aspirated => "aspirated",
+                Laryngeal::Voiceless => "voiceless",
+                Laryngeal::Voiced => "voiced",
+            },
+            UiLang::BrazilianPortuguese => match laryngeal {
+                Laryngeal::Ejective => "ejetiva",
+                Laryngeal::Aspirated => "aspirada",
+                Laryngeal::Voiceless => "surda",
+                Laryngeal::Voiced => "sonora",
+            },
+        }
+    }
+
+    pub fn height(&self, height: Height) -> &'static str {
+        match self.ui_lang {
+            UiLang::English => match height {
+                Height::High => "high",
+                Height::Mid => "mid",
+                Height::Low => "low",
+            },
+            UiLang::BrazilianPortuguese => match height {
+                Height::High => "alta",
+                Height::Mid => "média",
+                Height::Low => "baixa",
+            },
+        }
+    }
+
+    /// A kind of phoneme, as in the syllable structure table.
+    pub fn phoneme_class(&self, class: PhonemeClass) -> &'static str {
+        match self.ui_lang {
+            UiLang::English => match class {
+                PhonemeClass::Vowel => "vowel",
+                PhonemeClass::Approximant => "approximant",
+                PhonemeClass::Nasal => "nasal",
+                PhonemeClass::Fricative => "fricative",
+                PhonemeClass::Ejective => "ejective",
+                PhonemeClass::Aspirated => "aspirated",
+            },
+            UiLang::BrazilianPortuguese => match class {
+                PhonemeClass::Vowel => "vogal",
+                PhonemeClass::Approximant => "aproximante",
+                PhonemeClass::Nasal => "nasal",
+                PhonemeClass::Fricative => "fricativa",
+                PhonemeClass::Ejective => "ejetiva",
+                PhonemeClass::Aspirated => "aspirada",
+            },
+        }
+    }
+
+    pub fn weight(&self, weight: Weight) -> &'static str {
+        match (self.ui_lang, weight) {
+            (UiLang::English, Weight::Light) => "light",
+            (UiLang::English, Weight::Heavy) => "heavy",
+            (UiLang::BrazilianPortuguese, Weight::Light) => "leve",
+            (UiLang::BrazilianPortuguese, Weight::Heavy) => "pesada",
+        }
+    }
+
+    pub fn intonation(&self, intonation: Intonation) -> &'static str {
+        match self.ui_lang {
+            UiLang::English => match intonation {
+                Intonation::Declarative => "declarative",
+                Intonation::Interrogative => "interrogative",
+                Intonation::Imperative => "imperative",
+            },
+            UiLang::BrazilianPortuguese => match intonation {
+                Intonation::Declarative => "declarativa",
+                Intonation::Interrogative => "interrogativa",
+                Intonation::Imperative => "imperativa",
+            },
+        }
+    }
+
+    /// Heading of the texts of the given kind.
+    pub fn text_kind_plural(&self, kind: Kind) -> &'static str {
+        match (self.ui_lang, kind) {
+            (UiLang::English, Kind::Story) => "Stories",
+            (UiLang::English, Kind::Prayer) => "Prayers",
+            (UiLang::BrazilianPortuguese, Kind::Story) => "Histórias",
+            (UiLang::BrazilianPortuguese, Kind::Prayer) => "Orações",
+        }
+    }
+}
+
+const ENGLISH: Messages = Messages {
+    ui_lang: UiLang::English,
+
+    lang_name: "Classical Star Language",
+    index_intro: [
+        "This article is about the classical dialect of the Star Language, \
+         spoken by the Star Folk people. It is the earlier form of the Star \
+         Language, and was spoken during the first to third century (circa 0 \
+         ─ 250). Classical Star Language is the first human language ever, \
+         and the ancestor of any other human language.",
+        "As the world started with the Star Folk people, the gods gave them \
+         the insights required to communicate with each other and form a \
+         language. The classical period goes up to year 250, when the single \
+         Star Folk people began to split itself in three major groups.",
+    ],
+    see_article: "See this article.",
+    phonology: "Phonology",
+    writing_system: "Writing System",
+    grammar: "Grammar",
+    dictionary: "Dictionary",
+    texts: "Texts",
+
+    phonology_title: "Classical Star Language Phonology",
+    writing_title: "Classical Star Language Writing System",
+    grammar_title: "Classical Star Language Grammar",
+    dictionary_title: "Classical Star Language Dictionary",
+    texts_title: "Classical Star Language Texts",
+
+    dictionary_intro: "This page is a list of Classical Star Language words.",
+    meaning_index_intro: "To look up a word by its meaning in English, see \
+                          the ",
+    meaning_index_link: "English index",
+    list_of_entries: "List of Entries",
+    list_of_words: "List of Words",
+    downloads: "Downloads",
+
+    meaning_index_title: "English Index",
+    meaning_index_body: "This page lists English meanings and the Classical \
+                         Star Language words that express them.",
+    no_words: "No words with this meaning yet.",
+    related_meanings: "Related meanings:",
+    semantic_network: "Semantic Network",
+    semantic_network_body: "The relations between meanings. Each meaning is \
+                            listed with its hyponyms under it.",
+    other_relations: "Other relations:",
+    semantic_network_download: "The network as a Graphviz DOT graph",
+
+    definition: "Definition",
+    inflected_for: "inflected for",
+    inflection: "Inflection",
+    inflection_for: "Inflection for ",
+    attested_in: "Attested In",
+    not_attested: "Not attested in the corpus.",
+    line: "line",
+    or: "or",
+    see_the: "See the ",
+    full_entry: "full entry",
+    romanization: "Romanization",
+    pronunciation: "Pronunciation",
+    phonemic: "Phonemic",
+    early_accents: "Early CSL Accents",
+    late_accents: "Some Late CSL Accents",
+    sound_changes: "Sound Changes",
+    derivation_of: "Derivation of ",
+    stage: "Stage",
+    rule: "Rule",
+    result: "Result",
+    etymology: "Etymology",
+    from: "From ",
+    usage: "Usage",
+    register: "Register",
+    this_word_is: "This word is ",
+    examples: "Examples",
+    see_also: "See Also",
+
+    text: "Text",
+    text_in: " in ",
+    text_intro: ", shown in the star alphabet, in romanization with its \
+                 pronunciation and in English. Each known word links to its \
+                 dictionary entry; words in italics are not in the \
+                 dictionary yet.",
+    texts_intro: "This page lists the stories and prayers written in \
+                  Classical Star Language.",
+    show_gloss: "Show interlinear gloss",
+
+    synthesized: "Synthesized, not a recording",
+
+    phonology_intro: [
+        "This article is about the phonology of the ",
+        "classical dialect of the Star Language",
+        ". The dialect had a very stable phonology, whose only big change \
+         was the allophonic palatalization of fricatives in the final years.",
+    ],
+    consonants: "Consonants",
+    consonant_chart: "Classical Star Language Consonants",
+    allophonic_variation: "Allophonic Variation",
+    retraction_body: "Velar consonants might become uvular before /a(ː)/. \
+                      Labiovelar nasal /ŋʷ/ might be pronounced as a doubly \
+                      articulated nasal [ŋ͡m].",
+    retraction_table: "Velar/Uvular Allophones",
+    palatalization_body: "At later times, when adjacent to palatal sounds, \
+                          many speakers pronounce the alveolar fricative /s/ \
+                          as an alveolo-palatal sibilant [ɕ], the velar \
+                          fricative /x/ as a palatal non-sibilant fricative \
+                          [ç], and the labiovelar fricative /xʷ/ as a \
+                          labiopalatal non-sibilant fricative [çʷ]. \
+                          Adjacent sounds are not deleted (yet).",
+    palatalization_table: "Palatalized Allophones",
+    palatalization_spread: "The geographic distribution of this change \
+                            seems to be non-uniform and quite widespread. \
+                            Generally, a single speaker might or might not \
+                            feature this variation, depending on the context \
+                            and the influence of other speakers. It is also \
+                            possible for a speaker to always feature the \
+                            variation, or never feature this at all.",
+    vowels: "Vowels",
+    vowel_chart: "Classical Star Language Vowels",
+    vowel_allophony_body: "Vowels backness and roundness vary depending on \
+                           the consonant before them.",
+    vowel_allophone_table: "Vowel Allophones",
+    vowel_trapezoid_alt: "Vowel allophones",
+    vowel_trapezoid_legend: "Trapezoid of vowel allophones.",
+    phonotactics: "Phonotactics",
+    phonotactics_body: "In general, Classical Star Language follows this \
+                        syllable structure: (C)(C)(C)V(C)(C). There are, \
+                        however, a few restrictions on the combination of \
+                        kinds of phonemes. For instance, geminated (long) \
+                        consonants are forbidden, and so, any sequence of \
+                        repeated consonant simplifies to a single simple \
+                        consonant; there is no length distinction. Vowels \
+                        in hiatus cannot be the same as well. The table \
+                        below lists the restrictions based on the kind of \
+                        the consonants",
+    syllabic_structure: "Syllabic Structure",
+    outer_onset: "Outer Onset",
+    medial_onset: "Medial Onset",
+    inner_onset: "Inner Onset",
+    nucleus: "Nucleus",
+    inner_coda: "Inner Coda",
+    outer_coda: "Outer Coda",
+    valid_clusters: "Valid Clusters",
+    outer_and_medial_onsets: "Outer and Medial Onsets",
+    codas: "Codas",
+    clusters: "Clusters",
+    prosody: "Prosody",
+    stress_body: "Stress is sensitive to syllable weight. A syllable is \
+                  heavy if its vowel is long or if it has a coda, and light \
+                  otherwise. The primary stress falls on the first syllable, \
+                  unless the first syllable is light and the second is \
+                  heavy; then the second syllable is stressed. Every other \
+                  syllable after the primary stress has a secondary stress, \
+                  except for the last syllable of the word.",
+    stress: "Stress",
+    intonation_body: "Declarative clauses end in a global fall, \
+                      interrogative clauses end in a global rise, and \
+                      imperative clauses start with an upstep and end in a \
+                      global fall.",
+    intonation: "Intonation",
+    connected_speech: "Connected Speech",
+    connected_speech_body: "Words spoken together behave as a single word \
+                            regarding clusters and allophony. A coda \
+                            followed by a word with a shorter onset moves to \
+                            the next word, as it would between syllables. \
+                            Retraction and vowel allophones are conditioned \
+                            by the neighbouring word, and in the late accent \
+                            palatalization spreads across word boundaries.",
+    sandhi: "Sandhi",
+    phoneme: "Phoneme",
+    before: "Before ",
+    after: "After ",
+    elsewhere: "Elsewhere",
+    palatalized: "Palatalized",
+    short: "Short",
+    long: "Long",
+    default_case: "Default Case",
+    word: "Word",
+    weights: "Weights",
+    transcription: "Transcription",
+    phrase: "Phrase",
+    isolated_words: "Isolated Words",
+    early_accent: "Early Accent",
+    late_accent: "Late Accent",
+};
+
+const BRAZILIAN_PORTUGUESE: Messages = Messages {
+    ui_lang: UiLang::BrazilianPortuguese,
+
+    lang_name: "Língua Estelar Clássica",
+    index_intro: [
+        "Este artigo é sobre o dialeto clássico da Língua Estelar, falado \
+         pelo Povo Estelar. É a forma mais antiga da Língua Estelar, e foi \
+         falado do primeiro ao terceiro século (por volta de 0 ─ 250). A \
+         Língua Estelar Clássica é a primeira língua humana de todas, e a \
+         ancestral de todas as outras línguas humanas.",
+        "Como o mundo começou com o Povo Estelar, os deuses lhe deram as \
+         percepções necessárias para se comunicar e formar uma língua. O \
+         período clássico vai até o ano 250, quando o Povo Estelar, até \
+         então único, começou a se dividir em três grandes grupos.",
+    ],
+    see_article: "Veja este artigo.",
+    phonology: "Fonologia",
+    writing_system: "Sistema de Escrita",
+    grammar: "Gramática",
+    dictionary: "Dicionário",
+    texts: "Textos",
+
+    phonology_title: "Fonologia da Língua Estelar Clássica",
+    writing_title: "Sistema de Escrita da Língua Estelar Clássica",
+    grammar_title: "Gramática da Língua Estelar Clássica",
+    dictionary_title: "Dicionário da Língua Estelar Clássica",
+    texts_title: "Textos da Língua Estelar Clássica",
+
+    dictionary_intro: "Esta página é uma lista de palavras da Língua \
+                       Estelar Clássica.",
+    meaning_index_intro: "Para procurar uma palavra pelo seu significado em \
+                          português, veja o ",
+    meaning_index_link: "índice em português",
+    list_of_entries: "Lista de Entradas",
+    list_of_words: "Lista de Palavras",
+    downloads: "Downloads",
+
+    meaning_index_title: "Índice em Português",
+    meaning_index_body: "Esta página lista significados em português e as \
+                         palavras da Língua Estelar Clássica que os \
+                         expressam.",
+    no_words: "Ainda não há palavras com este significado.",
+    related_meanings: "Significados relacionados:",
+    semantic_network: "Rede Semântica",
+    semantic_network_body: "As relações entre os significados. Cada \
+                            significado é listado com os seus hipônimos \
+                            abaixo dele.",
+    other_relations: "Outras relações:",
+    semantic_network_download: "A rede como um grafo DOT do Graphviz",
+
+    definition: "Definição",
+    inflected_for: "flexionada para",
+    inflection: "Flexão",
+    inflection_for: "Flexão de ",
+    attested_in: "Atestações",
+    not_attested: "Não atestada no corpus.",
+    line: "linha",
+    or: "ou",
+    see_the: "Veja a ",
+    full_entry: "entrada completa",
+    romanization: "Romanização",
+    pronunciation: "Pronúncia",
+    phonemic: "Fonêmica",
+    early_accents: "Sotaques Antigos da LEC",
+    late_accents: "Alguns Sotaques Tardios da LEC",
+    sound_changes: "Mudanças Sonoras",
+    derivation_of: "Derivação de ",
+    stage: "Estágio",
+    rule: "Regra",
+    result: "Resultado",
+    etymology: "Etimologia",
+    from: "De ",
+    usage: "Uso",
+    register: "Registro",
+    this_word_is: "Esta palavra é ",
+    examples: "Exemplos",
+    see_also: "Veja Também",
+
+    text: "Texto",
+    text_in: " em ",
+    text_intro: ", apresentado no alfabeto estelar, em romanização com a \
+                 pronúncia e com tradução em inglês. Cada palavra conhecida \
+                 leva à sua entrada no dicionário; palavras em itálico ainda \
+                 não estão no dicionário.",
+    texts_intro: "Esta página lista as histórias e orações escritas em \
+                  Língua Estelar Clássica.",
+    show_gloss: "Mostrar glosa interlinear",
+
+    synthesized: "Sintetizado, não é uma gravação",
+
+    phonology_intro: [
+        "Este artigo é sobre a fonologia do ",
+        "dialeto clássico da Língua Estelar",
+        ". O dialeto teve uma fonologia muito estável, cuja única grande \
+         mudança foi a palatalização alofônica das fricativas nos últimos \
+         anos.",
+    ],
+    consonants: "Consoantes",
+    consonant_chart: "Consoantes da Língua Estelar Clássica",
+    allophonic_variation: "Variação Alofônica",
+    retraction_body: "Consoantes velares podiam se tornar uvulares antes de \
+                      /a(ː)/. A nasal labiovelar /ŋʷ/ podia ser pronunciada \
+                      como uma nasal de articulação dupla [ŋ͡m].",
+    retraction_table: "Alofones Velares/Uvulares",
+    palatalization_body: "Em tempos mais tardios, quando adjacentes a sons \
+                          palatais, muitos falantes pronunciam a fricativa \
+                          alveolar /s/ como uma sibilante alveolopalatal \
+                          [ɕ], a fricativa velar /x/ como uma fricativa \
+                          palatal não sibilante [ç], e a fricativa \
+                          labiovelar /xʷ/ como uma fricativa labiopalatal \
+                          não sibilante [çʷ]. Os sons adjacentes não são \
+                          apagados (ainda).",
+    palatalization_table: "Alofones Palatalizados",
+    palatalization_spread: "A distribuição geográfica desta mudança parece \
+                            ser irregular e bastante difundida. Em geral, um \
+                            mesmo falante pode ou não apresentar esta \
+                            variação, dependendo do contexto e da influência \
+                            de outros falantes. Também é possível que um \
+                            falante sempre apresente a variação, ou nunca a \
+                            apresente.",
+    vowels: "Vogais",
+    vowel_chart: "Vogais da Língua Estelar Clássica",
+    vowel_allophony_body: "A anterioridade e o arredondamento das vogais \
+                           variam conforme a consoante antes delas.",
+    vowel_allophone_table: "Alofones Vocálicos",
+    vowel_trapezoid_alt: "Alofones vocálicos",
+    vowel_trapezoid_legend: "Trapézio dos alofones vocálicos.",
+    phonotactics: "Fonotática",
+    phonotactics_body: "Em geral, a Língua Estelar Clássica segue esta \
+                        estrutura silábica: (C)(C)(C)V(C)(C). Há, no \
+                        entanto, algumas restrições na combinação de tipos \
+                        de fonemas. Por exemplo, consoantes geminadas \
+                        (longas) são proibidas, e assim qualquer sequência \
+                        de consoantes repetidas se simplifica em uma única \
+                        consoante simples; não há distinção de duração. \
+                        Vogais em hiato também não podem ser iguais. A \
+                        tabela abaixo lista as restrições conforme o tipo \
+                        das consoantes",
+    syllabic_structure: "Estrutura Silábica",
+    outer_onset: "Ataque Externo",
+    medial_onset: "Ataque Medial",
+    inner_onset: "Ataque Interno",
+    nucleus: "Núcleo",
+    inner_coda: "Coda Interna",
+    outer_coda: "Coda Externa",
+    valid_clusters: "Encontros Válidos",
+    outer_and_medial_onsets: "Ataques Externos e Mediais",
+    codas: "Codas",
+    clusters: "Encontros",
+    prosody: "Prosódia",
+    stress_body: "O acento é sensível ao peso silábico. Uma sílaba é pesada \
+                  se sua vogal é longa ou se tem coda, e leve caso \
+                  contrário. O acento primário cai na primeira sílaba, a \
+                  menos que a primeira sílaba seja leve e a segunda pesada; \
+                  nesse caso a segunda sílaba é acentuada. Sílabas \
+                  alternadas depois do acento primário têm acento \
+                  secundário, exceto a última sílaba da palavra.",
+    stress: "Acento",
+    intonation_body: "Orações declarativas terminam em uma queda global, \
+                      orações interrogativas terminam em uma subida global, \
+                      e orações imperativas começam com um degrau ascendente \
+                      e terminam em uma queda global.",
+    intonation: "Entonação",
+    connected_speech: "Fala Encadeada",
+    connected_speech_body: "Palavras faladas juntas se comportam como uma \
+                            única palavra quanto aos encontros e à alofonia. \
+                            Uma coda seguida de uma palavra com ataque mais \
+                            curto passa para a palavra seguinte, como \
+                            aconteceria entre sílabas. A retração e os \
+                            alofones vocálicos dependem da palavra vizinha, \
+                            e no sotaque tardio a palatalização se espalha \
+                            através das fronteiras de palavra.",
+    sandhi: "Sândi",
+    phoneme: "Fonema",
+    before: "Antes de ",
+    after: "Depois de ",
+    elsewhere: "Nos Demais Casos",
+    palatalized: "Palatalizado",
+    short: "Breves",
+    long: "Longas",
+    default_case: "Caso Padrão",
+    word: "Palavra",
+    weights: "Pesos",
+    transcription: "Transcrição",
+    phrase: "Frase",
+    isolated_words: "Palavras Isoladas",
+    early_accent: "Sotaque Antigo",
+    late_accent: "Sotaque Tardio",
+};
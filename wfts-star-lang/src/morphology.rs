@@ -31,6 +31,14 @@ impl Morpheme {
     pub fn to_text(&self) -> String {
         format!("{}", self)
     }
+
+    /// The phonemes of the morpheme, leaving out the holes of templates.
+    pub fn phonemes(&self) -> Vec<Phoneme> {
+        match self {
+            Morpheme::Template(temp) => temp.phonemes().to_vec(),
+            Morpheme::Word(word) => word.phonemes().collect(),
+        }
+    }
 }
 
 impl fmt::Display for Morpheme {
@@ -84,10 +92,18 @@ impl Template {
             Err(InvalidTemplateHole { template: self.clone(), hole })?;
         }
 
-        let prev_len = self.phonemes.len();
-        self.phonemes.extend_from_slice(phonemes);
-        let (left, right) = self.phonemes.split_at_mut(prev_len);
-        left[hole .. hole + phonemes.len()].swap_with_slice(right);
+        self.phonemes.splice(hole .. hole, phonemes.iter().copied());
+        self.holes = self
+            .holes
+            .iter()
+            .map(|&other| {
+                if other > hole {
+                    other + phonemes.len()
+                } else {
+                    other
+                }
+            })
+            .collect();
 
         Ok(())
     }
@@ -109,14 +125,14 @@ impl Template {
         let mut output = String::new();
 
         for (i, ch) in self.phonemes().iter().enumerate() {
-            if holes.peek().map_or(false, |&&hole| i == hole) {
-                output.push_str("-");
+            if holes.peek().is_some_and(|&&hole| i == hole) {
+                output.push('-');
                 holes.next();
             }
             write!(output, "{}", ch.to_broad_ipa()).unwrap();
         }
-        if let Some(_) = holes.next() {
-            output.push_str("-");
+        if holes.next().is_some() {
+            output.push('-');
         }
 
         output
@@ -128,13 +144,13 @@ impl fmt::Display for Template {
         let mut holes = self.holes.iter().peekable();
 
         for (i, ch) in self.phonemes().iter().enumerate() {
-            if holes.peek().map_or(false, |&&hole| i == hole) {
+            if holes.peek().is_some_and(|&&hole| i == hole) {
                 fmt.write_str("-")?;
                 holes.next();
             }
             write!(fmt, "{}", ch.to_text())?;
         }
-        if let Some(_) = holes.next() {
+        if holes.next().is_some() {
             fmt.write_str("-")?;
         }
 
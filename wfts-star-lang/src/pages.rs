@@ -3,3 +3,4 @@ pub mod phonology;
 pub mod writing;
 pub mod grammar;
 pub mod dictionary;
+pub mod texts;
@@ -1,51 +1,393 @@
-use crate::{dictionary::Dictionary, morphology::Morpheme, StarLang};
-use wfts_lang::Lang;
+use crate::{
+    collation::Collation,
+    component::Romanized,
+    dictionary::{
+        self,
+        export,
+        Dictionary,
+        Entry,
+        FormPage,
+        Label,
+        LexemePage,
+    },
+    messages::Messages,
+    morphology::Morpheme,
+    phonology::Phoneme,
+    romanization::Scheme,
+    StarLang,
+};
+use wfts_lang::{
+    semantics::{
+        network::SemanticNetwork,
+        registry::Registry,
+        Meaning,
+        Relation,
+    },
+    Lang,
+};
 use wfts_pedia_ssg::{
-    component::{list::UnorderedList, text::Link, Component},
+    component::{
+        list::UnorderedList,
+        text::{Link, Paragraph},
+        Component,
+        DynComponent,
+    },
+    i18n::UiLang,
     location::{Id, InternalPath, Location},
     page::{Page, Section},
     site::{Directory, Node},
 };
 
-pub fn make(dir: &mut Directory) {
-    let dict = Dictionary::with_all_entries();
-    let mut morphemes = dict.sections.keys().cloned().collect::<Vec<_>>();
-    morphemes.sort();
-    make_index(dir, morphemes);
-    make_words(dir, dict);
+pub fn make(dir: &mut Directory, ui_lang: UiLang, scheme: Scheme) {
+    let entries = Entry::all();
+    make_reverse_index(dir, &entries, ui_lang, scheme);
+    let dict = Dictionary::from_entries(&entries, ui_lang, scheme).unwrap();
+    let morphemes =
+        dict.forms.iter().map(|page| page.morpheme.clone()).collect();
+    make_index(dir, &dict.lexemes, morphemes, ui_lang, scheme);
+    make_lexemes(dir, &dict.lexemes, ui_lang);
+    make_words(dir, dict.forms, ui_lang);
+}
+
+/// Id of the section of the index listing the words starting with a letter.
+fn letter_section_id(letter: Phoneme) -> Id {
+    Id::new(format!("letter-{}", Scheme::Ascii.render_phoneme(letter)))
+        .unwrap()
 }
 
-fn make_index(dir: &mut Directory, morphemes: Vec<Morpheme>) {
-    let list = morphemes
+/// Groups the sorted morphemes by their first letter, keeping the order.
+fn group_by_letter(morphemes: Vec<Morpheme>) -> Vec<(Phoneme, Vec<Morpheme>)> {
+    let mut groups = Vec::<(Phoneme, Vec<Morpheme>)>::new();
+    for morpheme in morphemes {
+        let letter = match morpheme.phonemes().first() {
+            Some(&phoneme) => Collation::TRADITIONAL.letter(phoneme),
+            None => continue,
+        };
+        match groups.last_mut() {
+            Some((last, group)) if *last == letter => group.push(morpheme),
+            _ => groups.push((letter, vec![morpheme])),
+        }
+    }
+    groups
+}
+
+fn make_index(
+    dir: &mut Directory,
+    lexemes: &[LexemePage],
+    morphemes: Vec<Morpheme>,
+    ui_lang: UiLang,
+    scheme: Scheme,
+) {
+    let messages = Messages::get(ui_lang);
+    let mut lexemes = lexemes.iter().collect::<Vec<_>>();
+    lexemes.sort_by_cached_key(|lexeme| {
+        let phonemes = lexeme.label.headword.phonemes();
+        (Collation::TRADITIONAL.key(phonemes), lexeme.label.homograph)
+    });
+    let lexeme_list = lexemes
         .into_iter()
-        .map(|morpheme| Link {
-            location: Location::internal(format!(
-                "{}/dictionary/{}",
-                StarLang.path(),
-                morpheme.to_text()
-            )),
-            text: morpheme.to_text(),
+        .map(|lexeme| {
+            vec![
+                Link {
+                    location: dictionary::lexeme_location(&lexeme.id),
+                    text: lexeme.label.clone(),
+                }
+                .to_dyn(),
+                Romanized(scheme, format!(" ({})", lexeme.label.to_text()))
+                    .to_dyn(),
+            ]
+        })
+        .collect();
+    let groups = group_by_letter(morphemes);
+    let jump_links = groups
+        .iter()
+        .flat_map(|(letter, _)| {
+            let link = Link {
+                location: Location::internal(format!(
+                    "{}/dictionary#{}",
+                    StarLang.path(),
+                    letter_section_id(*letter)
+                )),
+                text: Romanized(scheme, letter.to_text()),
+            };
+            vec![link.to_dyn(), " ".to_dyn()]
+        })
+        .collect::<Vec<_>>();
+    let letter_sections = groups
+        .into_iter()
+        .map(|(letter, morphemes)| {
+            let list = morphemes
+                .into_iter()
+                .map(|morpheme| Link {
+                    location: Location::internal(format!(
+                        "{}/dictionary/{}",
+                        StarLang.path(),
+                        morpheme.to_text()
+                    )),
+                    text: Romanized(scheme, morpheme.to_text()),
+                })
+                .collect();
+            Section {
+                title: Romanized(scheme, letter.to_text()).to_dyn(),
+                id: letter_section_id(letter),
+                body: UnorderedList(list).to_dyn(),
+                children: vec![],
+            }
+        })
+        .collect();
+    let downloads = export::Format::ALL
+        .iter()
+        .flat_map(|format| {
+            format.files().into_iter().map(move |file| Link {
+                location: Location::shared(format!(
+                    "{}/{}/{}",
+                    StarLang.path(),
+                    export::DIR,
+                    file
+                )),
+                text: format!("{} ({})", format.name(), file),
+            })
         })
         .collect();
     dir.insert(
         InternalPath::parse("dictionary/index.html").unwrap(),
         Node::Page(Page {
-            title: "Classical Star Language Dictionary".to_owned(),
-            body: "This page is a list of Classical Star Language words."
-                .blocking()
+            title: messages.dictionary_title.to_owned(),
+            body: vec![
+                Paragraph(messages.dictionary_intro).to_dyn(),
+                Paragraph(vec![
+                    messages.meaning_index_intro.to_dyn(),
+                    Link {
+                        location: Location::internal(format!(
+                            "{}/dictionary/english",
+                            StarLang.path()
+                        )),
+                        text: messages.meaning_index_link,
+                    }
+                    .to_dyn(),
+                    ".".to_dyn(),
+                ])
                 .to_dyn(),
-            sections: vec![Section {
-                title: "List of Words".to_dyn(),
-                id: Id::new("list-of-words").unwrap(),
-                body: UnorderedList(list).to_dyn(),
+            ]
+            .to_dyn(),
+            sections: vec![
+                Section {
+                    title: messages.list_of_entries.to_dyn(),
+                    id: Id::new("list-of-entries").unwrap(),
+                    body: UnorderedList(lexeme_list).to_dyn(),
+                    children: vec![],
+                },
+                Section {
+                    title: messages.list_of_words.to_dyn(),
+                    id: Id::new("list-of-words").unwrap(),
+                    body: Paragraph(jump_links).to_dyn(),
+                    children: letter_sections,
+                },
+                Section {
+                    title: messages.downloads.to_dyn(),
+                    id: Id::new("downloads").unwrap(),
+                    body: UnorderedList(downloads).to_dyn(),
+                    children: vec![],
+                },
+            ],
+        }),
+    );
+}
+
+fn make_reverse_index(
+    dir: &mut Directory,
+    entries: &[Entry],
+    ui_lang: UiLang,
+    scheme: Scheme,
+) {
+    let messages = Messages::get(ui_lang);
+    let path = InternalPath::parse("dictionary/english/index.html").unwrap();
+    let labels = Label::of_all(entries);
+
+    let mut registry = Registry::with_shared();
+    StarLang.register_meanings(&mut registry).unwrap();
+    let network = registry.network();
+    let mut meanings = registry.iter().collect::<Vec<_>>();
+    meanings.sort_by_cached_key(|meaning| {
+        ui_lang.sort_key(meaning.identifier_in(ui_lang))
+    });
+
+    let sections = meanings
+        .iter()
+        .map(|&meaning| {
+            let words = entries
+                .iter()
+                .zip(&labels)
+                .filter(|(entry, _)| entry.meanings.contains(&meaning))
+                .map(|(entry, label)| {
+                    vec![
+                        Link {
+                            location: dictionary::lexeme_location(&entry.id),
+                            text: label.clone(),
+                        }
+                        .to_dyn(),
+                        Romanized(scheme, format!(" ({})", label.to_text()))
+                            .to_dyn(),
+                    ]
+                })
+                .collect::<Vec<_>>();
+
+            let related = meanings
+                .iter()
+                .filter_map(|&other| match network.relation(other, meaning) {
+                    Relation::Unrelated => None,
+                    _ if other == meaning => None,
+                    relation => Some(vec![
+                        meaning_link(other, ui_lang).to_dyn(),
+                        format!(" ({})", messages.relation(relation))
+                            .to_dyn(),
+                    ]),
+                })
+                .collect::<Vec<_>>();
+
+            let mut body = vec![
+                Paragraph(meaning.description_in(ui_lang)).to_dyn(),
+                if words.is_empty() {
+                    Paragraph(messages.no_words).to_dyn()
+                } else {
+                    UnorderedList(words).to_dyn()
+                },
+            ];
+            if !related.is_empty() {
+                body.push(Paragraph(messages.related_meanings).to_dyn());
+                body.push(UnorderedList(related).to_dyn());
+            }
+
+            Section {
+                title: meaning.identifier_in(ui_lang).to_dyn(),
+                id: meaning.to_id(),
+                body: body.to_dyn(),
                 children: vec![],
-            }],
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let roots = meanings
+        .iter()
+        .filter(|&&meaning| {
+            network.hyperonyms(meaning).is_empty()
+                && !network.hyponyms(meaning).is_empty()
+        })
+        .map(|&meaning| hyponym_tree(network, &meanings, meaning, ui_lang))
+        .collect::<Vec<_>>();
+    let others = network
+        .declared()
+        .filter(|&(_, _, relation)| relation != Relation::Hyperonym)
+        .map(|(first, second, relation)| {
+            vec![
+                meaning_link(first, ui_lang).to_dyn(),
+                " — ".to_dyn(),
+                meaning_link(second, ui_lang).to_dyn(),
+                format!(" ({})", messages.relation(relation)).to_dyn(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut body = vec![
+        Paragraph(messages.semantic_network_body).to_dyn(),
+        UnorderedList(roots).to_dyn(),
+    ];
+    if !others.is_empty() {
+        body.push(Paragraph(messages.other_relations).to_dyn());
+        body.push(UnorderedList(others).to_dyn());
+    }
+    body.push(
+        Paragraph(Link {
+            location: Location::shared(format!(
+                "{}/{}/{}",
+                StarLang.path(),
+                export::DIR,
+                export::NETWORK_FILE,
+            )),
+            text: messages.semantic_network_download,
+        })
+        .to_dyn(),
+    );
+    let network = Section {
+        title: messages.semantic_network.to_dyn(),
+        id: Id::new("semantic-network").unwrap(),
+        body: body.to_dyn(),
+        children: vec![],
+    };
+
+    dir.insert(
+        path,
+        Node::Page(Page {
+            title: format!(
+                "{} — {}",
+                messages.dictionary_title, messages.meaning_index_title
+            ),
+            body: Paragraph(messages.meaning_index_body).to_dyn(),
+            sections: sections.into_iter().chain(Some(network)).collect(),
         }),
     );
 }
 
-fn make_words(dir: &mut Directory, dict: Dictionary) {
-    for (morpheme, sections) in dict.sections {
+fn meaning_link(meaning: Meaning, ui_lang: UiLang) -> Link<&'static str> {
+    Link {
+        location: Location::internal(format!(
+            "{}/dictionary/english#{}",
+            StarLang.path(),
+            meaning.to_id(),
+        )),
+        text: meaning.identifier_in(ui_lang),
+    }
+}
+
+/// Links to `meaning`, followed by a list of its declared hyponyms, in the
+/// order of `meanings`, each with its own hyponyms.
+fn hyponym_tree(
+    network: &SemanticNetwork,
+    meanings: &[Meaning],
+    meaning: Meaning,
+    ui_lang: UiLang,
+) -> Vec<DynComponent> {
+    let hyponyms = network.direct_hyponyms(meaning).collect::<Vec<_>>();
+    let children = meanings
+        .iter()
+        .filter(|meaning| hyponyms.contains(meaning))
+        .map(|&hyponym| hyponym_tree(network, meanings, hyponym, ui_lang))
+        .collect::<Vec<_>>();
+
+    let mut item = vec![meaning_link(meaning, ui_lang).blocking().to_dyn()];
+    if !children.is_empty() {
+        item.push(UnorderedList(children).to_dyn());
+    }
+    item
+}
+
+fn make_lexemes(dir: &mut Directory, lexemes: &[LexemePage], ui_lang: UiLang) {
+    let messages = Messages::get(ui_lang);
+    for lexeme in lexemes {
+        let path = InternalPath::parse(format!(
+            "dictionary/{}/{}/index.html",
+            dictionary::LEXEME_DIR,
+            lexeme.id,
+        ))
+        .unwrap();
+        dir.insert(
+            path,
+            Node::Page(Page {
+                title: format!(
+                    "{} — {}",
+                    lexeme.label.to_text(),
+                    messages.dictionary_title
+                ),
+                body: "".blocking().to_dyn(),
+                sections: lexeme.sections.clone(),
+            }),
+        );
+    }
+}
+
+fn make_words(dir: &mut Directory, forms: Vec<FormPage>, ui_lang: UiLang) {
+    let messages = Messages::get(ui_lang);
+    for FormPage { morpheme, sections } in forms {
         let path = InternalPath::parse(format!(
             "dictionary/{}/index.html",
             morpheme.to_text(),
@@ -53,15 +395,10 @@ fn make_words(dir: &mut Directory, dict: Dictionary) {
         .unwrap();
         dir.insert(
             path,
-            Node::Page({
-                Page {
-                    title: format!(
-                        "{} — Classical Star Language Dictionary",
-                        morpheme
-                    ),
-                    body: "".blocking().to_dyn(),
-                    sections,
-                }
+            Node::Page(Page {
+                title: format!("{} — {}", morpheme, messages.dictionary_title),
+                body: "".blocking().to_dyn(),
+                sections,
             }),
         );
     }
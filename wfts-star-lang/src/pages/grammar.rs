@@ -1,5 +1,7 @@
 use crate::{
     grammar::{adjective, conjunction, noun, postposition, pronoun, verb},
+    messages::Messages,
+    romanization::Scheme,
     StarLang,
 };
 use wfts_lang::Lang;
@@ -9,16 +11,17 @@ use wfts_pedia_ssg::{
         text::{Link, Paragraph},
         Component,
     },
+    i18n::UiLang,
     location::{Id, InternalPath},
     page::{Page, Section},
     site::{Directory, Node},
 };
 
-pub fn make(dir: &mut Directory) {
+pub fn make(dir: &mut Directory, ui_lang: UiLang, scheme: Scheme) {
     dir.insert(
         InternalPath::parse("grammar/index.html").unwrap(),
         Node::Page(Page {
-            title: "Classical Star Language Grammar".to_owned(),
+            title: Messages::get(ui_lang).grammar_title.to_owned(),
             body: vec![Paragraph(vec![
                 "This article is about the grammar of the ".to_dyn(),
                 Link {
@@ -114,10 +117,10 @@ pub fn make(dir: &mut Directory) {
                         title: "Inflection Classes".to_dyn(),
                         id: Id::new("noun-classes").unwrap(),
                         body: vec![
-                            noun::full1::Word::affix_table(),
-                            noun::full2::Word::affix_table(),
-                            noun::divine1::Word::affix_table(),
-                            noun::divine2::Word::affix_table(),
+                            noun::full1::Word::affix_table(scheme),
+                            noun::full2::Word::affix_table(scheme),
+                            noun::divine1::Word::affix_table(scheme),
+                            noun::divine2::Word::affix_table(scheme),
                         ]
                         .to_dyn(),
                         children: vec![],
@@ -141,8 +144,10 @@ pub fn make(dir: &mut Directory) {
                     children: vec![Section {
                         title: "Inflection Classes".to_dyn(),
                         id: Id::new("adjective-classes").unwrap(),
-                        body: vec![adjective::regular::Word::affix_table()]
-                            .to_dyn(),
+                        body: vec![
+                            adjective::regular::Word::affix_table(scheme),
+                        ]
+                        .to_dyn(),
                         children: vec![],
                     }],
                 },
@@ -192,9 +197,9 @@ pub fn make(dir: &mut Directory) {
                         title: "Inflection Classes".to_dyn(),
                         id: Id::new("pronoun-classes").unwrap(),
                         body: vec![
-                            pronoun::demonstrative::Word::affix_table(),
-                            pronoun::personal::Word::affix_table(),
-                            pronoun::relative::Word::affix_table(),
+                            pronoun::demonstrative::Word::affix_table(scheme),
+                            pronoun::personal::Word::affix_table(scheme),
+                            pronoun::relative::Word::affix_table(scheme),
                         ]
                         .to_dyn(),
                         children: vec![],
@@ -220,8 +225,8 @@ pub fn make(dir: &mut Directory) {
                         title: "Inflection Classes".to_dyn(),
                         id: Id::new("postposition-classes").unwrap(),
                         body: vec![
-                            postposition::unary::Word::affix_table(),
-                            postposition::binary::Word::affix_table(),
+                            postposition::unary::Word::affix_table(scheme),
+                            postposition::binary::Word::affix_table(scheme),
                         ]
                         .to_dyn(),
                         children: vec![],
@@ -249,8 +254,8 @@ pub fn make(dir: &mut Directory) {
                         title: "Inflection Classes".to_dyn(),
                         id: Id::new("conjunction-classes").unwrap(),
                         body: vec![
-                            conjunction::additive::Word::affix_table(),
-                            conjunction::isomorphic::Word::affix_table(),
+                            conjunction::additive::Word::affix_table(scheme),
+                            conjunction::isomorphic::Word::affix_table(scheme),
                         ]
                         .to_dyn(),
                         children: vec![],
@@ -307,7 +312,7 @@ pub fn make(dir: &mut Directory) {
                     children: vec![Section {
                         title: "Inflection Classes".to_dyn(),
                         id: Id::new("verb-classes").unwrap(),
-                        body: vec![verb::regular1::Word::affix_table()]
+                        body: vec![verb::regular1::Word::affix_table(scheme)]
                             .to_dyn(),
                         children: vec![],
                     }],
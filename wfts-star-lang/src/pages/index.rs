@@ -1,43 +1,33 @@
-use crate::StarLang;
+use crate::{messages::Messages, StarLang};
 use wfts_lang::Lang;
 use wfts_pedia_ssg::{
     component::{
         text::{Link, Paragraph},
         Component,
     },
+    i18n::UiLang,
     location::{Fragment, Id, InternalPath, Location},
     page::{Page, Section},
     site::{Directory, Node},
 };
 
-pub fn make(dir: &mut Directory) {
+pub fn make(dir: &mut Directory, ui_lang: UiLang) {
+    let messages = Messages::get(ui_lang);
     dir.insert(
         InternalPath::parse("index.html").unwrap(),
         Node::Page(Page {
-            title: "Classical Star Language".to_owned(),
-            body: vec![
-                Paragraph(
-                    "This article is about the classical dialect of the Star \
-                     Language, spoken by the Star Folk people. It is the \
-                     earlier form of the Star Language, and was spoken during \
-                     the first to third century (circa 0 ─ 250). Classical \
-                     Star Language is the first human language ever, and the \
-                     ancestor of any other human language.",
-                ),
-                Paragraph(
-                    "As the world started with the Star Folk people, the gods \
-                     gave them the insights required to communicate with each \
-                     other and form a language. The classical period goes up \
-                     to year 250, when the single Star Folk people began to \
-                     split itself in three major groups.",
-                ),
-            ]
-            .to_dyn(),
+            title: messages.lang_name.to_owned(),
+            body: messages
+                .index_intro
+                .iter()
+                .map(|&paragraph| Paragraph(paragraph))
+                .collect::<Vec<_>>()
+                .to_dyn(),
             sections: vec![
                 Section {
-                    title: "Phonology".to_dyn(),
+                    title: messages.phonology.to_dyn(),
                     body: Paragraph(Link {
-                        text: "See this article.",
+                        text: messages.see_article,
                         location: Location::from(
                             StarLang
                                 .path()
@@ -49,9 +39,9 @@ pub fn make(dir: &mut Directory) {
                     id: Id::new("phonology").unwrap(),
                 },
                 Section {
-                    title: "Writing System".to_dyn(),
+                    title: messages.writing_system.to_dyn(),
                     body: Paragraph(Link {
-                        text: "See this article.",
+                        text: messages.see_article,
                         location: Location::from(
                             StarLang
                                 .path()
@@ -63,9 +53,9 @@ pub fn make(dir: &mut Directory) {
                     id: Id::new("phonology").unwrap(),
                 },
                 Section {
-                    title: "Grammar".to_dyn(),
+                    title: messages.grammar.to_dyn(),
                     body: Paragraph(Link {
-                        text: "See this article.",
+                        text: messages.see_article,
                         location: Location::from(
                             StarLang
                                 .path()
@@ -77,9 +67,9 @@ pub fn make(dir: &mut Directory) {
                     id: Id::new("grammar").unwrap(),
                 },
                 Section {
-                    title: "Dictionary".to_dyn(),
+                    title: messages.dictionary.to_dyn(),
                     body: Paragraph(Link {
-                        text: "See this article.",
+                        text: messages.see_article,
                         location: Location::from(
                             StarLang
                                 .path()
@@ -90,6 +80,20 @@ pub fn make(dir: &mut Directory) {
                     children: vec![],
                     id: Id::new("dictionary").unwrap(),
                 },
+                Section {
+                    title: messages.texts.to_dyn(),
+                    body: Paragraph(Link {
+                        text: messages.see_article,
+                        location: Location::from(
+                            StarLang
+                                .path()
+                                .append(Fragment::new("texts").unwrap()),
+                        ),
+                    })
+                    .to_dyn(),
+                    children: vec![],
+                    id: Id::new("texts").unwrap(),
+                },
             ],
         }),
     );
@@ -1,473 +1,123 @@
-use crate::StarLang;
+use crate::{
+    messages::Messages,
+    phonology::{
+        features::{Feature, Laryngeal, Manner, Place},
+        phrase::Phrase,
+        prosody::{Intonation, Utterance},
+        sound_change::realize,
+        Coda,
+        Onset,
+        Parse,
+        Phoneme,
+        PhonemeClass,
+        Syllable,
+        Word,
+    },
+    StarLang,
+};
+use std::iter;
 use wfts_lang::Lang;
 use wfts_pedia_ssg::{
     component::{
         img::{Figure, Image},
         list::UnorderedList,
-        table::{Entry, Table},
+        table::{self, Entry, Table},
         text::{Link, Paragraph},
         Component,
     },
+    fmt::StrExt,
+    i18n::UiLang,
     location::{Fragment, Id, InternalPath},
     page::{Page, Section},
     site::{Directory, Node},
 };
 
-pub fn make(dir: &mut Directory) {
+pub fn make(dir: &mut Directory, ui_lang: UiLang) {
+    use PhonemeClass::*;
+
+    let messages = Messages::get(ui_lang);
+    let [intro_start, intro_link, intro_end] = messages.phonology_intro;
+    let class = |class| messages.phoneme_class(class).capitalize_first();
     dir.insert(
         InternalPath::parse("phonology/index.html").unwrap(),
         Node::Page(Page {
-            title: "Classical Star Language Phonology".to_owned(),
+            title: messages.phonology_title.to_owned(),
             body: vec![Paragraph(vec![
-                "This article is about the phonology of the ".to_dyn(),
-                Link {
-                    location: StarLang.path().into(),
-                    text: "classical dialect of the Star Language",
-                }
-                .to_dyn(),
-                ". The dialect had a very stable phonology, whose only big \
-                 change was the allophonic palatalization of fricatives in \
-                 the final years."
+                intro_start.to_dyn(),
+                Link { location: StarLang.path().into(), text: intro_link }
                     .to_dyn(),
+                intro_end.to_dyn(),
             ])]
             .to_dyn(),
             sections: vec![
                 Section {
-                    title: "Consonants".to_dyn(),
+                    title: messages.consonants.to_dyn(),
                     id: Id::new("consonants").unwrap(),
                     body: vec![
                         Paragraph(
-                            "Classical Star Language had 24 phonemic \
-                             consonants.",
+                            messages.consonant_count(consonants().count()),
                         )
                         .to_dyn(),
                         Table {
-                            title: "Classical Star Language Consonants"
-                                .to_owned(),
-                            entries: vec![
-                                vec![
-                                    Entry {
-                                        colspan: 2,
-                                        rowspan: 2,
-                                        header: true,
-                                        data: "",
-                                    },
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 2,
-                                        header: true,
-                                        data: "Labial",
-                                    },
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 2,
-                                        header: true,
-                                        data: "Alveolar",
-                                    },
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 2,
-                                        header: true,
-                                        data: "Palatal",
-                                    },
-                                    Entry {
-                                        colspan: 2,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Velar/Uvular",
-                                    },
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 2,
-                                        header: true,
-                                        data: "Pharyngeal",
-                                    },
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Plain",
-                                    },
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Labial",
-                                    },
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 3,
-                                        header: true,
-                                        data: "Occlusive",
-                                    },
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Aspirated",
-                                    },
-                                    Entry::new("pʰ"),
-                                    Entry::new("tʰ"),
-                                    Entry::new("cʰ"),
-                                    Entry::new("kʰ"),
-                                    Entry::new("kʷʰ"),
-                                    Entry::default(),
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Ejective",
-                                    },
-                                    Entry::new("pʼ"),
-                                    Entry::new("tʼ"),
-                                    Entry::new("cʼ"),
-                                    Entry::new("kʼ"),
-                                    Entry::new("kʷʼ"),
-                                    Entry::default(),
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Nasal",
-                                    },
-                                    Entry::new("m"),
-                                    Entry::new("n"),
-                                    Entry::new("ɲ"),
-                                    Entry::new("ŋ"),
-                                    Entry::new("ŋʷ"),
-                                    Entry::default(),
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 2,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Fricative",
-                                    },
-                                    Entry::new("ɸ"),
-                                    Entry::new("s"),
-                                    Entry::new(""),
-                                    Entry::new("x"),
-                                    Entry::new("xʷ"),
-                                    Entry::new("ħ"),
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 2,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Approximant",
-                                    },
-                                    Entry::new(""),
-                                    Entry::new("ɹ"),
-                                    Entry::new("j"),
-                                    Entry::new(""),
-                                    Entry::new("w"),
-                                    Entry::new("ʕ"),
-                                ],
-                            ],
+                            title: messages.consonant_chart.to_owned(),
+                            entries: consonant_chart(messages),
                         }
                         .to_dyn(),
                     ]
                     .to_dyn(),
                     children: vec![Section {
-                        title: "Allophonic Variation".to_dyn(),
+                        title: messages.allophonic_variation.to_dyn(),
                         id: Id::new("consonant-allophony").unwrap(),
                         body: vec![
-                            Paragraph(
-                                "Velar consonants might become uvular before \
-                                 /a(ː)/. Labiovelar nasal /ŋʷ/ might be \
-                                 pronounced as a doubly articulated nasal \
-                                 [ŋ͡m].",
-                            )
-                            .to_dyn(),
+                            Paragraph(messages.retraction_body).to_dyn(),
                             Table {
-                                title: "Velar/Uvular Allophones".to_owned(),
-                                entries: vec![
-                                    vec![
-                                        Entry {
-                                            colspan: 1,
-                                            rowspan: 1,
-                                            header: true,
-                                            data: "Phoneme",
-                                        },
-                                        Entry {
-                                            colspan: 1,
-                                            rowspan: 1,
-                                            header: true,
-                                            data: "Before /a(ː)/",
-                                        },
-                                        Entry {
-                                            colspan: 1,
-                                            rowspan: 1,
-                                            header: true,
-                                            data: "Elsewhere",
-                                        },
-                                    ],
-                                    vec![
-                                        Entry::new("/kʰ/"),
-                                        Entry::new("[qʰ]"),
-                                        Entry::new("[kʰ]"),
-                                    ],
-                                    vec![
-                                        Entry::new("/kʷʰ/"),
-                                        Entry::new("[qʷʰ]"),
-                                        Entry::new("[kʷʰ]"),
-                                    ],
-                                    vec![
-                                        Entry::new("/kʼ/"),
-                                        Entry::new("[qʼ]"),
-                                        Entry::new("[kʼ]"),
-                                    ],
-                                    vec![
-                                        Entry::new("/kʷʼ/"),
-                                        Entry::new("[qʷʼ]"),
-                                        Entry::new("[kʷʼ]"),
-                                    ],
-                                    vec![
-                                        Entry::new("/ŋ/"),
-                                        Entry::new("[ɴ]"),
-                                        Entry::new("[ŋ]"),
-                                    ],
-                                    vec![
-                                        Entry::new("/ŋʷ/"),
-                                        Entry::new("[ɴʷ ~ ɴ͡m]"),
-                                        Entry::new("[ŋʷ ~ ŋ͡m]"),
-                                    ],
-                                    vec![
-                                        Entry::new("/x/"),
-                                        Entry::new("[χ]"),
-                                        Entry::new("[x]"),
-                                    ],
-                                    vec![
-                                        Entry::new("/xʷ/"),
-                                        Entry::new("[χʷ]"),
-                                        Entry::new("[xʷ]"),
-                                    ],
-                                    vec![
-                                        Entry::new("/w/"),
-                                        Entry::new("[w̠]"),
-                                        Entry::new("[w]"),
-                                    ],
-                                ],
+                                title: messages.retraction_table.to_owned(),
+                                entries: retraction_table(messages),
                             }
                             .to_dyn(),
-                            Paragraph(
-                                "At later times, when adjacent to palatal \
-                                 sounds, many speakers pronounce the alveolar \
-                                 fricative /s/ as an alveolo-palatal sibilant \
-                                 [ɕ], the velar fricative /x/ as a palatal \
-                                 non-sibilant fricative [ç], and the \
-                                 labiovelar fricative /xʷ/ as a labiopalatal \
-                                 non-sibilant fricative [çʷ]. Adjacent sounds \
-                                 are not deleted (yet).",
-                            )
-                            .to_dyn(),
-                            Paragraph(
-                                "The geographic distribution of this change \
-                                 seems to be non-uniform and quite \
-                                 widespread. Generally, a single speaker \
-                                 might or might not feature this variation, \
-                                 depending on the context and the influence \
-                                 of other speakers. It is also possible for a \
-                                 speaker to always feature the variation, or \
-                                 never feature this at all.",
-                            )
+                            Paragraph(messages.palatalization_body).to_dyn(),
+                            Table {
+                                title: messages.palatalization_table.to_owned(),
+                                entries: palatalization_table(messages),
+                            }
                             .to_dyn(),
+                            Paragraph(messages.palatalization_spread)
+                                .to_dyn(),
                         ]
                         .to_dyn(),
                         children: vec![],
                     }],
                 },
                 Section {
-                    title: "Vowels".to_dyn(),
+                    title: messages.vowels.to_dyn(),
                     id: Id::new("vowels").unwrap(),
                     body: vec![
-                        Paragraph(
-                            "Classical Star Language had 6 phonemic vowels. \
-                             The vowels do not contrast on backness or \
-                             rounding, only on height and length.",
-                        )
-                        .to_dyn(),
+                        Paragraph(messages.vowel_count(vowels().count()))
+                            .to_dyn(),
                         Table {
-                            title: "Classical Star Language Vowels".to_owned(),
-                            entries: vec![
-                                vec![
-                                    Entry::default(),
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Short",
-                                    },
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Long",
-                                    },
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Close",
-                                    },
-                                    Entry::new("i"),
-                                    Entry::new("iː"),
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Mid",
-                                    },
-                                    Entry::new("e"),
-                                    Entry::new("eː"),
-                                ],
-                                vec![
-                                    Entry {
-                                        colspan: 1,
-                                        rowspan: 1,
-                                        header: true,
-                                        data: "Open",
-                                    },
-                                    Entry::new("a"),
-                                    Entry::new("aː"),
-                                ],
-                            ],
+                            title: messages.vowel_chart.to_owned(),
+                            entries: vowel_chart(messages),
                         }
                         .to_dyn(),
                     ]
                     .to_dyn(),
                     children: vec![Section {
-                        title: "Allophonic Variation".to_dyn(),
+                        title: messages.allophonic_variation.to_dyn(),
                         id: Id::new("vowel-allophony").unwrap(),
                         body: vec![
-                            Paragraph(
-                                "Vowels backness and roundness vary depending \
-                                 on the consonant before them.",
-                            )
-                            .to_dyn(),
+                            Paragraph(messages.vowel_allophony_body).to_dyn(),
                             Table {
-                                title: "Vowel Allophones".to_owned(),
-                                entries: vec![
-                                    vec![
-                                        Entry::default(),
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "After /cʰ, cʼ, ɲ, j/ (and \
-                                                   [ɕ, ç, çʷ])",
-                                        },
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "Default Case",
-                                        },
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "After /kʰ, kʼ, ŋ, x/",
-                                        },
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "After /kʷʰ, kʷʼ, ŋʷ, xʷ, w/",
-                                        },
-                                    ],
-                                    vec![
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "/i/",
-                                        },
-                                        Entry::new("[i ~ ɪ]"),
-                                        Entry::new("[ɨ ~ ɨ̞]"),
-                                        Entry::new("[ɯ ~ ɯ̽]"),
-                                        Entry::new("[u ~ ʊ]"),
-                                    ],
-                                    vec![
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "/iː/",
-                                        },
-                                        Entry::new("[iː]"),
-                                        Entry::new("[ɨː]"),
-                                        Entry::new("[ɯə̯]"),
-                                        Entry::new("[uː]"),
-                                    ],
-                                    vec![
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "/e/",
-                                        },
-                                        Entry::new("[e̞ ~ e̽]"),
-                                        Entry::new("[ə]"),
-                                        Entry::new("[ɤ̞ ~ ɤ̽]"),
-                                        Entry::new("[o̞ ~ o̽]"),
-                                    ],
-                                    vec![
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "/eː/",
-                                        },
-                                        Entry::new("[e̞ː]"),
-                                        Entry::new("[əː ~ ɘː]"),
-                                        Entry::new("[ɤ̞ə̯]"),
-                                        Entry::new("[o̞ː]"),
-                                    ],
-                                    vec![
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "/a/",
-                                        },
-                                        Entry::new("[æ ~ æ̽]"),
-                                        Entry::new("[ä ~ ɐ]"),
-                                        Entry::new("[ɑ ~ ɑ̽]"),
-                                        Entry::new("[ɒ ~ ɒ̝]"),
-                                    ],
-                                    vec![
-                                        Entry {
-                                            rowspan: 1,
-                                            colspan: 1,
-                                            header: true,
-                                            data: "/aː/",
-                                        },
-                                        Entry::new("[æː]"),
-                                        Entry::new("[äː]"),
-                                        Entry::new("[ɑː]"),
-                                        Entry::new("[ɒɔ̯]"),
-                                    ],
-                                ],
+                                title: messages
+                                    .vowel_allophone_table
+                                    .to_owned(),
+                                entries: vowel_allophone_table(messages),
                             }
                             .to_dyn(),
                             Figure {
                                 img: Image {
-                                    alt: "Vowel allophones".to_owned(),
+                                    alt: messages
+                                        .vowel_trapezoid_alt
+                                        .to_owned(),
                                     src: StarLang
                                         .path()
                                         .append(
@@ -476,7 +126,7 @@ pub fn make(dir: &mut Directory) {
                                         )
                                         .into(),
                                 },
-                                legend: "Trapezoid of vowel allophones.",
+                                legend: messages.vowel_trapezoid_legend,
                             }
                             .to_dyn(),
                         ]
@@ -485,37 +135,29 @@ pub fn make(dir: &mut Directory) {
                     }],
                 },
                 Section {
-                    title: "Phonotactics".to_dyn(),
+                    title: messages.phonotactics.to_dyn(),
                     id: Id::new("phonotactics").unwrap(),
                     body: vec![
-                        Paragraph(
-                            "In general, Classical Star Language follows this \
-                             syllable structure: (C)(C)(C)V(C)(C). There are, \
-                             however, a few restrictions on the combination \
-                             of kinds of phonemes. For instance, geminated \
-                             (long) consonants are forbidden, and so, any \
-                             sequence of repeated consonant simplifies to a \
-                             single simple consonant; there is no length \
-                             distinction. Vowels in hiatus cannot be the same \
-                             as well. The table below lists the restrictions \
-                             based on the kind of the consonants",
-                        )
-                        .to_dyn(),
+                        Paragraph(messages.phonotactics_body).to_dyn(),
                         Table {
-                            title: "Syllabic Structure".to_owned(),
+                            title: messages.syllabic_structure.to_owned(),
                             entries: vec![
                                 vec![
                                     Entry {
                                         colspan: 1,
                                         rowspan: 1,
                                         header: true,
-                                        data: "Outer Onset".blocking().to_dyn(),
+                                        data: messages
+                                            .outer_onset
+                                            .blocking()
+                                            .to_dyn(),
                                     },
                                     Entry {
                                         colspan: 1,
                                         rowspan: 1,
                                         header: true,
-                                        data: "Medial Onset"
+                                        data: messages
+                                            .medial_onset
                                             .blocking()
                                             .to_dyn(),
                                     },
@@ -523,38 +165,50 @@ pub fn make(dir: &mut Directory) {
                                         colspan: 1,
                                         rowspan: 1,
                                         header: true,
-                                        data: "Inner Onset".blocking().to_dyn(),
+                                        data: messages
+                                            .inner_onset
+                                            .blocking()
+                                            .to_dyn(),
                                     },
                                     Entry {
                                         colspan: 1,
                                         rowspan: 1,
                                         header: true,
-                                        data: "Nucleus".blocking().to_dyn(),
+                                        data: messages
+                                            .nucleus
+                                            .blocking()
+                                            .to_dyn(),
                                     },
                                     Entry {
                                         colspan: 1,
                                         rowspan: 1,
                                         header: true,
-                                        data: "Inner Coda".blocking().to_dyn(),
+                                        data: messages
+                                            .inner_coda
+                                            .blocking()
+                                            .to_dyn(),
                                     },
                                     Entry {
                                         colspan: 1,
                                         rowspan: 1,
                                         header: true,
-                                        data: "Outer Coda".blocking().to_dyn(),
+                                        data: messages
+                                            .outer_coda
+                                            .blocking()
+                                            .to_dyn(),
                                     },
                                 ],
                                 vec![
                                     Entry::new(
-                                        UnorderedList(vec!["Aspirated"])
+                                        UnorderedList(vec![class(Aspirated)])
                                             .to_dyn(),
                                     ),
                                     Entry::new(
                                         UnorderedList(vec![
-                                            "Aspirated",
-                                            "Fricative",
-                                            "Nasal",
-                                            "∅",
+                                            class(Aspirated),
+                                            class(Fricative),
+                                            class(Nasal),
+                                            "∅".to_owned(),
                                         ])
                                         .to_dyn(),
                                     ),
@@ -563,8 +217,8 @@ pub fn make(dir: &mut Directory) {
                                         colspan: 1,
                                         header: false,
                                         data: UnorderedList(vec![
-                                            "Approximant",
-                                            "∅",
+                                            class(Approximant),
+                                            "∅".to_owned(),
                                         ])
                                         .to_dyn(),
                                     },
@@ -572,7 +226,7 @@ pub fn make(dir: &mut Directory) {
                                         rowspan: 3,
                                         colspan: 1,
                                         header: false,
-                                        data: UnorderedList(vec!["Vowel"])
+                                        data: UnorderedList(vec![class(Vowel)])
                                             .to_dyn(),
                                     },
                                     Entry {
@@ -580,8 +234,8 @@ pub fn make(dir: &mut Directory) {
                                         colspan: 1,
                                         header: false,
                                         data: UnorderedList(vec![
-                                            "Approximant",
-                                            "∅",
+                                            class(Approximant),
+                                            "∅".to_owned(),
                                         ])
                                         .to_dyn(),
                                     },
@@ -590,49 +244,55 @@ pub fn make(dir: &mut Directory) {
                                         colspan: 1,
                                         header: false,
                                         data: UnorderedList(vec![
-                                            "Fricative",
-                                            "Nasal",
-                                            "∅",
+                                            class(Fricative),
+                                            class(Nasal),
+                                            "∅".to_owned(),
                                         ])
                                         .to_dyn(),
                                     },
                                 ],
                                 vec![
                                     Entry::new(
-                                        UnorderedList(vec!["Ejective"])
+                                        UnorderedList(vec![class(Ejective)])
                                             .to_dyn(),
                                     ),
                                     Entry::new(
                                         UnorderedList(vec![
-                                            "Ejective",
-                                            "Fricative",
-                                            "Nasal",
-                                            "∅",
+                                            class(Ejective),
+                                            class(Fricative),
+                                            class(Nasal),
+                                            "∅".to_owned(),
                                         ])
                                         .to_dyn(),
                                     ),
                                 ],
                                 vec![
                                     Entry::new(
-                                        UnorderedList(vec!["Fricative", "∅"])
-                                            .to_dyn(),
+                                        UnorderedList(vec![
+                                            class(Fricative),
+                                            "∅".to_owned(),
+                                        ])
+                                        .to_dyn(),
                                     ),
                                     Entry::new(
-                                        UnorderedList(vec!["Nasal", "∅"])
-                                            .to_dyn(),
+                                        UnorderedList(vec![
+                                            class(Nasal),
+                                            "∅".to_owned(),
+                                        ])
+                                        .to_dyn(),
                                     ),
                                 ],
                                 vec![
                                     Entry::new(
-                                        UnorderedList(vec!["Aspirated"])
+                                        UnorderedList(vec![class(Aspirated)])
                                             .to_dyn(),
                                     ),
                                     Entry::new(
                                         UnorderedList(vec![
-                                            "Aspirated",
-                                            "Fricative",
-                                            "Nasal",
-                                            "∅",
+                                            class(Aspirated),
+                                            class(Fricative),
+                                            class(Nasal),
+                                            "∅".to_owned(),
                                         ])
                                         .to_dyn(),
                                     ),
@@ -658,27 +318,33 @@ pub fn make(dir: &mut Directory) {
                                 ],
                                 vec![
                                     Entry::new(
-                                        UnorderedList(vec!["Ejective"])
+                                        UnorderedList(vec![class(Ejective)])
                                             .to_dyn(),
                                     ),
                                     Entry::new(
                                         UnorderedList(vec![
-                                            "Ejective",
-                                            "Fricative",
-                                            "Nasal",
-                                            "∅",
+                                            class(Ejective),
+                                            class(Fricative),
+                                            class(Nasal),
+                                            "∅".to_owned(),
                                         ])
                                         .to_dyn(),
                                     ),
                                 ],
                                 vec![
                                     Entry::new(
-                                        UnorderedList(vec!["Fricative", "∅"])
-                                            .to_dyn(),
+                                        UnorderedList(vec![
+                                            class(Fricative),
+                                            "∅".to_owned(),
+                                        ])
+                                        .to_dyn(),
                                     ),
                                     Entry::new(
-                                        UnorderedList(vec!["Nasal", "∅"])
-                                            .to_dyn(),
+                                        UnorderedList(vec![
+                                            class(Nasal),
+                                            "∅".to_owned(),
+                                        ])
+                                        .to_dyn(),
                                     ),
                                 ],
                             ],
@@ -686,9 +352,427 @@ pub fn make(dir: &mut Directory) {
                         .to_dyn(),
                     ]
                     .to_dyn(),
+                    children: vec![Section {
+                        title: messages.valid_clusters.to_dyn(),
+                        id: Id::new("valid-clusters").unwrap(),
+                        body: vec![
+                            Paragraph(messages.cluster_counts(
+                                Onset::all().count(),
+                                Coda::all().count(),
+                                Syllable::count(),
+                                &inner_onsets(),
+                            ))
+                            .to_dyn(),
+                            Table {
+                                title: messages
+                                    .outer_and_medial_onsets
+                                    .to_owned(),
+                                entries: onset_cluster_table(messages),
+                            }
+                            .to_dyn(),
+                            Table {
+                                title: messages.codas.to_owned(),
+                                entries: coda_cluster_table(messages),
+                            }
+                            .to_dyn(),
+                        ]
+                        .to_dyn(),
+                        children: vec![],
+                    }],
+                },
+                Section {
+                    title: messages.prosody.to_dyn(),
+                    id: Id::new("prosody").unwrap(),
+                    body: vec![
+                        Paragraph(messages.stress_body).to_dyn(),
+                        Table {
+                            title: messages.stress.to_owned(),
+                            entries: stress_table(messages),
+                        }
+                        .to_dyn(),
+                        Paragraph(messages.intonation_body).to_dyn(),
+                        Table {
+                            title: messages.intonation.to_owned(),
+                            entries: intonation_table(messages),
+                        }
+                        .to_dyn(),
+                    ]
+                    .to_dyn(),
+                    children: vec![],
+                },
+                Section {
+                    title: messages.connected_speech.to_dyn(),
+                    id: Id::new("connected-speech").unwrap(),
+                    body: vec![
+                        Paragraph(messages.connected_speech_body).to_dyn(),
+                        Table {
+                            title: messages.sandhi.to_owned(),
+                            entries: sandhi_table(messages),
+                        }
+                        .to_dyn(),
+                    ]
+                    .to_dyn(),
                     children: vec![],
                 },
             ],
         }),
     );
 }
+
+fn consonants() -> impl Iterator<Item = Phoneme> {
+    Phoneme::ALL.iter().copied().filter(|ph| ph.has(Feature::Consonantal))
+}
+
+fn vowels() -> impl Iterator<Item = Phoneme> {
+    Phoneme::ALL.iter().copied().filter(|ph| !ph.has(Feature::Consonantal))
+}
+
+fn header(data: String) -> Entry<String> {
+    Entry { header: true, ..Entry::new(data) }
+}
+
+fn phonemic(phoneme: Phoneme) -> Entry<String> {
+    Entry::new(format!("/{}/", phoneme.to_broad_ipa()))
+}
+
+fn allophone(ipa: &str) -> Entry<String> {
+    Entry::new(format!("[{}]", ipa))
+}
+
+fn broad_list<I>(phonemes: I) -> String
+where
+    I: IntoIterator<Item = Phoneme>,
+{
+    phonemes
+        .into_iter()
+        .map(|ph| ph.to_broad_ipa())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Consonant chart with places of articulation as columns and manners of
+/// articulation (split by laryngeal setting when they contrast) as rows.
+fn consonant_chart(messages: &Messages) -> table::Entries<String> {
+    let places = Place::ALL
+        .iter()
+        .copied()
+        .filter(|&place| {
+            consonants().any(|ph| ph.features().place == Some(place))
+        })
+        .collect::<Vec<_>>();
+
+    let mut entries = vec![place_header_row(&places, messages)];
+
+    for &manner in Manner::ALL {
+        let laryngeals = Laryngeal::ALL
+            .iter()
+            .copied()
+            .filter(|&laryngeal| {
+                consonants().any(|ph| {
+                    let features = ph.features();
+                    features.manner == manner && features.laryngeal == laryngeal
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for (i, &laryngeal) in laryngeals.iter().enumerate() {
+            let mut row = Vec::new();
+            if laryngeals.len() > 1 {
+                if i == 0 {
+                    row.push(Entry {
+                        rowspan: laryngeals.len() as u32,
+                        ..header(messages.manner(manner).capitalize_first())
+                    });
+                }
+                row.push(header(
+                    messages.laryngeal(laryngeal).capitalize_first(),
+                ));
+            } else {
+                row.push(Entry {
+                    colspan: 2,
+                    ..header(messages.manner(manner).capitalize_first())
+                });
+            }
+
+            for &place in &places {
+                let cell = consonants().filter(|ph| {
+                    let features = ph.features();
+                    features.place == Some(place)
+                        && features.manner == manner
+                        && features.laryngeal == laryngeal
+                });
+                row.push(Entry::new(broad_list(cell)));
+            }
+            entries.push(row);
+        }
+    }
+
+    entries
+}
+
+fn place_header_row(
+    places: &[Place],
+    messages: &Messages,
+) -> Vec<Entry<String>> {
+    let mut row = vec![Entry { colspan: 2, ..header(String::new()) }];
+    row.extend(
+        places
+            .iter()
+            .map(|&place| header(messages.place(place).capitalize_first())),
+    );
+    row
+}
+
+/// Dorsal consonants before low vowels and elsewhere.
+fn retraction_table(messages: &Messages) -> table::Entries<String> {
+    let low = Phoneme::ALL
+        .iter()
+        .copied()
+        .filter(|ph| ph.triggers_retraction())
+        .collect::<Vec<_>>();
+
+    let mut entries = vec![vec![
+        header(messages.phoneme.to_owned()),
+        header(format!(
+            "{}/{}/",
+            messages.before,
+            broad_list(low.iter().copied())
+        )),
+        header(messages.elsewhere.to_owned()),
+    ]];
+
+    for phoneme in consonants().filter(|ph| ph.has(Feature::Dorsal)) {
+        entries.push(vec![
+            phonemic(phoneme),
+            allophone(&realize(phoneme, None, low.first().copied(), false)),
+            allophone(&realize(phoneme, None, None, false)),
+        ]);
+    }
+
+    entries
+}
+
+/// Fricatives affected by the late palatal spreading.
+fn palatalization_table(messages: &Messages) -> table::Entries<String> {
+    let mut entries = vec![vec![
+        header(messages.phoneme.to_owned()),
+        header(messages.palatalized.to_owned()),
+        header(messages.elsewhere.to_owned()),
+    ]];
+
+    for phoneme in consonants().filter(|ph| ph.can_be_palatalized_regress()) {
+        entries.push(vec![
+            phonemic(phoneme),
+            allophone(&realize(phoneme, None, None, true)),
+            allophone(&realize(phoneme, None, None, false)),
+        ]);
+    }
+
+    entries
+}
+
+/// Vowel chart with height as rows and length as columns.
+fn vowel_chart(messages: &Messages) -> table::Entries<String> {
+    let mut entries = vec![vec![
+        Entry::default(),
+        header(messages.short.to_owned()),
+        header(messages.long.to_owned()),
+    ]];
+
+    let mut heights =
+        vowels().filter_map(|ph| ph.features().height).collect::<Vec<_>>();
+    heights.sort();
+    heights.dedup();
+
+    for height in heights {
+        let mut row = vec![header(messages.height(height).capitalize_first())];
+        for long in [false, true] {
+            let cell = vowels().filter(|ph| {
+                let features = ph.features();
+                features.height == Some(height) && features.long == long
+            });
+            row.push(Entry::new(broad_list(cell)));
+        }
+        entries.push(row);
+    }
+
+    entries
+}
+
+/// Vowels after each class of consonant that colors them.
+fn vowel_allophone_table(messages: &Messages) -> table::Entries<String> {
+    let context = |triggers: fn(Phoneme) -> bool| {
+        let class = consonants().filter(|&ph| triggers(ph)).collect::<Vec<_>>();
+        let title = format!(
+            "{}/{}/",
+            messages.after,
+            broad_list(class.iter().copied())
+        );
+        (title, class.first().copied())
+    };
+    let contexts = [
+        context(Phoneme::triggers_front),
+        (messages.default_case.to_owned(), None),
+        context(Phoneme::triggers_back),
+        context(Phoneme::triggers_back_rounded),
+    ];
+
+    let mut entries = vec![iter::once(Entry::default())
+        .chain(contexts.iter().map(|(title, _)| header(title.clone())))
+        .collect::<Vec<_>>()];
+
+    let mut vowels = vowels().collect::<Vec<_>>();
+    vowels.sort_by_key(|ph| (ph.features().height, ph.features().long));
+
+    for vowel in vowels {
+        let mut row = vec![Entry { header: true, ..phonemic(vowel) }];
+        for &(_, prev) in &contexts {
+            row.push(allophone(&realize(vowel, prev, None, false)));
+        }
+        entries.push(row);
+    }
+
+    entries
+}
+
+fn cluster(phonemes: impl Iterator<Item = Phoneme>) -> String {
+    let ipa = phonemes.map(|ph| ph.to_broad_ipa()).collect::<String>();
+    if ipa.is_empty() {
+        "∅".to_owned()
+    } else {
+        format!("/{}/", ipa)
+    }
+}
+
+fn inner_onsets() -> String {
+    let mut inners =
+        Onset::all().map(|onset| onset.inner()).collect::<Vec<_>>();
+    inners.sort();
+    inners.dedup();
+    inners
+        .into_iter()
+        .map(|inner| cluster(inner.into_iter()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Groups clusters by their first slot, one row per value of the slot.
+fn cluster_table<I>(
+    title: &str,
+    clusters: I,
+    messages: &Messages,
+) -> table::Entries<String>
+where
+    I: IntoIterator<Item = (Option<Phoneme>, String)>,
+{
+    let mut rows = Vec::<(Option<Phoneme>, Vec<String>)>::new();
+    for (first, cluster) in clusters {
+        match rows.last_mut() {
+            Some((prev, clusters)) if *prev == first => clusters.push(cluster),
+            _ => rows.push((first, vec![cluster])),
+        }
+    }
+
+    let mut entries = vec![vec![
+        header(title.to_owned()),
+        header(messages.clusters.to_owned()),
+    ]];
+    for (first, clusters) in rows {
+        entries.push(vec![
+            header(cluster(first.into_iter())),
+            Entry::new(clusters.join(", ")),
+        ]);
+    }
+    entries
+}
+
+fn onset_cluster_table(messages: &Messages) -> table::Entries<String> {
+    let clusters = Onset::all()
+        .filter(|onset| onset.inner().is_none())
+        .map(|onset| (onset.outer(), cluster(onset.phonemes())));
+    cluster_table(messages.outer_onset, clusters, messages)
+}
+
+fn coda_cluster_table(messages: &Messages) -> table::Entries<String> {
+    let clusters =
+        Coda::all().map(|coda| (coda.inner(), cluster(coda.phonemes())));
+    cluster_table(messages.inner_coda, clusters, messages)
+}
+
+/// Example words with the weight of their syllables and stress placement.
+fn stress_table(messages: &Messages) -> table::Entries<String> {
+    let mut entries = vec![vec![
+        header(messages.word.to_owned()),
+        header(messages.weights.to_owned()),
+        header(messages.transcription.to_owned()),
+    ]];
+
+    for text in &["saŋ", "wiya", "mací", "wiyakapa"] {
+        let word = Word::parse_str(text).unwrap();
+        let weights = word
+            .syllables()
+            .iter()
+            .map(|syllable| messages.weight(syllable.weight()))
+            .collect::<Vec<_>>();
+        entries.push(vec![
+            Entry::new(word.to_text()),
+            Entry::new(weights.join(", ")),
+            Entry::new(format!("/{}/", word.to_broad_ipa())),
+        ]);
+    }
+
+    entries
+}
+
+/// The same phrase transcribed with each intonation.
+fn intonation_table(messages: &Messages) -> table::Entries<String> {
+    let words = ["kas", "wiya"]
+        .iter()
+        .map(|text| Word::parse_str(text).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut entries = vec![vec![
+        header(messages.intonation.to_owned()),
+        header(messages.transcription.to_owned()),
+    ]];
+
+    for &intonation in Intonation::ALL {
+        let utterance = Utterance { words: words.clone(), intonation };
+        entries.push(vec![
+            header(messages.intonation(intonation).capitalize_first()),
+            Entry::new(format!("/{}/", utterance.to_broad_ipa())),
+        ]);
+    }
+
+    entries
+}
+
+/// Example phrases, transcribed word by word and as connected speech.
+fn sandhi_table(messages: &Messages) -> table::Entries<String> {
+    let mut entries = vec![vec![
+        header(messages.phrase.to_owned()),
+        header(messages.isolated_words.to_owned()),
+        header(messages.early_accent.to_owned()),
+        header(messages.late_accent.to_owned()),
+    ]];
+
+    for texts in &[["kas", "eta"], ["kas", "jí"], ["saŋ", "eta"]] {
+        let words = texts
+            .iter()
+            .map(|text| Word::parse_str(text).unwrap())
+            .collect::<Vec<_>>();
+        let isolated = words
+            .iter()
+            .map(|word| word.to_late_narrow_ipa())
+            .collect::<Vec<_>>();
+        let phrase = Phrase::new(&words);
+        entries.push(vec![
+            Entry::new(texts.join(" ")),
+            allophone(&isolated.join(" ")),
+            allophone(&phrase.to_early_narrow_ipa()),
+            allophone(&phrase.to_late_narrow_ipa()),
+        ]);
+    }
+
+    entries
+}
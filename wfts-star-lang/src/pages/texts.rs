@@ -0,0 +1,259 @@
+use crate::{
+    component::{Romanized, WithStarAlphabet},
+    corpus::{self, Corpus, Kind, Line, Text, Token},
+    dictionary::{Analyzer, Entry},
+    messages::Messages,
+    romanization::Scheme,
+    StarLang,
+};
+use std::fmt;
+use wfts_lang::Lang;
+use wfts_pedia_ssg::{
+    component::{
+        list::UnorderedList,
+        text::{Link, Paragraph},
+        Component,
+        Context,
+        InlineComponent,
+    },
+    i18n::UiLang,
+    location::{Id, InternalPath, Location},
+    page::{Page, Section},
+    site::{Directory, Node},
+};
+
+/// Location of a line of a text, for other pages to link to.
+pub fn line_location(text: &Text, line: &Line) -> Location {
+    Location::internal(format!(
+        "{}/texts/{}#line-{}",
+        StarLang.path(),
+        text.id,
+        line.number
+    ))
+}
+
+/// Location of the dictionary page of a word, if the word is in the
+/// dictionary.
+fn word_location(token: &Token) -> Option<Location> {
+    if Analyzer::get().analyze(&token.word).is_empty() {
+        None
+    } else {
+        Some(Location::internal(format!(
+            "{}/dictionary/{}",
+            StarLang.path(),
+            token.word
+        )))
+    }
+}
+
+/// Gloss of a word: the meaning of the entry and the grammemes, for every
+/// reading of the word.
+fn gloss(token: &Token, entries: &[Entry], ui_lang: UiLang) -> String {
+    let analyses = Analyzer::get().analyze(&token.word);
+    if analyses.is_empty() {
+        return "?".to_owned();
+    }
+    analyses
+        .iter()
+        .map(|analysis| {
+            let meaning = entries
+                .iter()
+                .find(|entry| entry.id == analysis.entry)
+                .and_then(|entry| entry.meanings.first())
+                .map_or("", |meaning| meaning.identifier_in(ui_lang));
+            format!("‘{}’ {}", meaning, analysis.inflection)
+        })
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// A text in parallel columns: star alphabet, romanization with the
+/// pronunciation, and translation. An interlinear gloss of each line is
+/// shown when its toggle is checked.
+#[derive(Debug, Clone)]
+struct ParallelText {
+    text: &'static Text,
+    scheme: Scheme,
+    /// Early narrow transcription of every line, spoken as one phrase.
+    pronunciations: Vec<String>,
+    /// Gloss of every token, line by line.
+    glosses: Vec<Vec<String>>,
+}
+
+impl ParallelText {
+    fn new(
+        text: &'static Text,
+        entries: &[Entry],
+        ui_lang: UiLang,
+        scheme: Scheme,
+    ) -> Self {
+        let glosses = text
+            .lines
+            .iter()
+            .map(|line| {
+                line.tokens
+                    .iter()
+                    .map(|token| gloss(token, entries, ui_lang))
+                    .collect()
+            })
+            .collect();
+        let pronunciations = text
+            .lines
+            .iter()
+            .map(|line| corpus::phrase(&line.tokens).to_early_narrow_ipa())
+            .collect();
+        Self { text, scheme, pronunciations, glosses }
+    }
+
+    /// The romanized line, with each word linking to the dictionary.
+    fn romanization(
+        &self,
+        line: &Line,
+        fmt: &mut fmt::Formatter,
+        ctx: Context,
+    ) -> fmt::Result {
+        let mut end = 0;
+        for token in &line.tokens {
+            write!(fmt, "{}", ctx.renderer(&line.text[end .. token.start]))?;
+            let word = Romanized(self.scheme, token.surface.clone());
+            match word_location(token) {
+                Some(location) => write!(
+                    fmt,
+                    "{}",
+                    ctx.renderer(Link { location, text: word })
+                )?,
+                None => write!(
+                    fmt,
+                    "<span class=\"unknown-word\">{}</span>",
+                    ctx.renderer(word)
+                )?,
+            }
+            end = token.start + token.surface.len();
+        }
+        write!(fmt, "{}", ctx.renderer(&line.text[end ..]))
+    }
+}
+
+impl Component for ParallelText {
+    type Kind = InlineComponent;
+
+    fn to_html(&self, fmt: &mut fmt::Formatter, ctx: Context) -> fmt::Result {
+        write!(
+            fmt,
+            "<input type=\"checkbox\" id=\"gloss-toggle\" \
+             class=\"gloss-toggle\"><label \
+             for=\"gloss-toggle\">{}</label><div class=\"parallel-text\">",
+            ctx.renderer(Messages::get(ctx.site().ui_lang).show_gloss)
+        )?;
+
+        let lines = self.text.lines.iter().zip(&self.pronunciations);
+        for ((line, pronunciation), glosses) in lines.zip(&self.glosses) {
+            write!(
+                fmt,
+                "<div class=\"parallel-line\" id=\"line-{number}\"><span \
+                 class=\"line-number\">{number}</span><div \
+                 class=\"parallel-script\">{script}</div><div \
+                 class=\"parallel-romanization\">",
+                number = line.number,
+                script = ctx.renderer(WithStarAlphabet(line.text.clone())),
+            )?;
+            self.romanization(line, fmt, ctx)?;
+            write!(
+                fmt,
+                "<div class=\"line-pronunciation\">[{}]</div></div><div \
+                 class=\"parallel-translation\">{}</div><div \
+                 class=\"gloss\">",
+                ctx.renderer(pronunciation),
+                ctx.renderer(&line.translation)
+            )?;
+            for (token, gloss) in line.tokens.iter().zip(glosses) {
+                write!(
+                    fmt,
+                    "<span class=\"gloss-word\"><span>{}</span><span>{}\
+                     </span></span>",
+                    ctx.renderer(Romanized(self.scheme, token.surface.clone())),
+                    ctx.renderer(gloss)
+                )?;
+            }
+            write!(fmt, "</div></div>")?;
+        }
+
+        write!(fmt, "</div>")
+    }
+}
+
+pub fn make(dir: &mut Directory, ui_lang: UiLang, scheme: Scheme) {
+    let messages = Messages::get(ui_lang);
+    let corpus = Corpus::get();
+    make_index(dir, corpus, messages);
+
+    let entries = Entry::all();
+    for text in &corpus.texts {
+        dir.insert(
+            InternalPath::parse(format!("texts/{}/index.html", text.id))
+                .unwrap(),
+            Node::Page(Page {
+                title: format!("{} — {}", text.title, messages.lang_name),
+                body: Paragraph(vec![
+                    messages.text_kind(text.kind).to_dyn(),
+                    messages.text_in.to_dyn(),
+                    Link {
+                        location: StarLang.path().into(),
+                        text: messages.lang_name,
+                    }
+                    .to_dyn(),
+                    messages.text_intro.to_dyn(),
+                ])
+                .to_dyn(),
+                sections: vec![Section {
+                    title: messages.text.to_dyn(),
+                    id: Id::new("text").unwrap(),
+                    body: ParallelText::new(text, &entries, ui_lang, scheme)
+                        .blocking()
+                        .to_dyn(),
+                    children: vec![],
+                }],
+            }),
+        );
+    }
+}
+
+fn make_index(
+    dir: &mut Directory,
+    corpus: &'static Corpus,
+    messages: &Messages,
+) {
+    let sections = Kind::ALL
+        .iter()
+        .map(|&kind| {
+            let links = corpus
+                .texts
+                .iter()
+                .filter(|text| text.kind == kind)
+                .map(|text| Link {
+                    location: Location::internal(format!(
+                        "{}/texts/{}",
+                        StarLang.path(),
+                        text.id
+                    )),
+                    text: text.title.clone(),
+                })
+                .collect::<Vec<_>>();
+            Section {
+                title: messages.text_kind_plural(kind).to_dyn(),
+                id: Id::new(kind.name()).unwrap(),
+                body: UnorderedList(links).to_dyn(),
+                children: vec![],
+            }
+        })
+        .collect();
+
+    dir.insert(
+        InternalPath::parse("texts/index.html").unwrap(),
+        Node::Page(Page {
+            title: messages.texts_title.to_owned(),
+            body: Paragraph(messages.texts_intro).to_dyn(),
+            sections,
+        }),
+    );
+}
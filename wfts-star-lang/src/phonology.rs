@@ -1,9 +1,33 @@
-use crate::StarLang;
+pub mod features;
+pub mod phrase;
+pub mod prosody;
+pub mod sound_change;
+pub mod synthesis;
+
+use crate::{
+    audio::{Accent, Clip, Manifest, Recording},
+    collation::Collation,
+};
 use anyhow::Context;
-use std::{borrow::Cow, cmp::Ordering, fmt, iter};
+use features::{
+    Backness,
+    Feature,
+    Features,
+    Height,
+    Laryngeal,
+    Manner,
+    Place,
+};
+use phrase::Phrase;
+use prosody::{Stress, Weight};
+use std::{borrow::Cow, cmp::Ordering, fmt, iter, slice};
 use thiserror::Error;
-use wfts_lang::Lang;
-use wfts_pedia_ssg::{component::audio::Audio, location::Location};
+
+/// No phoneme, followed by every phoneme; the possible fillings of an
+/// optional slot.
+fn optional_phonemes() -> impl Iterator<Item = Option<Phoneme>> + Clone {
+    iter::once(None).chain(Phoneme::ALL.iter().copied().map(Some))
+}
 
 pub fn balance_cluster(coda: &mut Coda, onset: &mut Onset) {
     let done = matches!(
@@ -22,7 +46,7 @@ pub fn balance_cluster(coda: &mut Coda, onset: &mut Onset) {
                     if let Some(outer) = coda.outer {
                         if outer.classify() == PhonemeClass::Fricative {
                             onset.outer = coda.outer.take();
-                        } else if !onset.medial.is_some() {
+                        } else if onset.medial.is_none() {
                             onset.medial = coda.outer.take();
                         }
                     } else if coda.inner.is_some()
@@ -68,7 +92,7 @@ impl Word {
         let mut prev = None;
         let mut prev_coda_len = 0;
 
-        if syllables.len() == 0 {
+        if syllables.is_empty() {
             Err(InvalidWord { syllables: syllables.clone() })?;
         }
 
@@ -83,9 +107,18 @@ impl Word {
 
                 let wrong_dist = onset_len
                     .checked_sub(prev_coda_len)
-                    .map_or(true, |diff| diff > 1);
-
-                if prev == first || !bypass_dist && wrong_dist {
+                    .is_none_or(|diff| diff > 1);
+
+                // A syllabic /ɹ/ next to another nucleus would be read as
+                // part of that syllable instead.
+                let hiatus = prev_coda_len == 0 && onset_len == 0;
+                let syllabic_r_hiatus =
+                    hiatus && (prev == Phoneme::R || first == Phoneme::R);
+
+                if prev == first
+                    || !bypass_dist && wrong_dist
+                    || syllabic_r_hiatus
+                {
                     Err(InvalidWord { syllables: syllables.clone() })?;
                 }
             }
@@ -106,19 +139,24 @@ impl Word {
         &self.syllables
     }
 
+    /// Stress of each syllable, as assigned by `prosody::stress`.
+    pub fn stresses(&self) -> Vec<Stress> {
+        prosody::stress(&self.syllables)
+    }
+
     pub fn to_broad_ipa(&self) -> String {
-        let mut output = String::from("ˈ");
-        let mut first = true;
+        let stresses = self.stresses();
+        let mut output = String::new();
 
-        for syllable in &self.syllables {
-            if first {
-                first = false;
+        for (i, syllable) in self.syllables.iter().enumerate() {
+            if i == 0 {
+                output.push_str(stresses[i].initial_mark());
             } else {
-                output.push('.');
+                output.push_str(stresses[i].mark());
             }
 
             for phoneme in syllable.phonemes() {
-                output.push_str(&phoneme.to_broad_ipa())
+                output.push_str(phoneme.to_broad_ipa())
             }
         }
 
@@ -126,94 +164,11 @@ impl Word {
     }
 
     pub fn to_early_narrow_ipa(&self) -> String {
-        let mut output = String::from("ˈ");
-        let mut prev = None;
-        let mut last = None;
-
-        for syllable in &self.syllables {
-            let mut iter = syllable.phonemes();
-            let mut curr = last.or_else(|| iter.next()).unwrap();
-            let mut first = true;
-            for next in iter {
-                output.push_str(curr.to_narrow_ipa(prev, Some(next), false));
-                if first {
-                    if last.is_some() {
-                        output.push('.');
-                    }
-                    first = false;
-                }
-                prev = Some(curr);
-                curr = next;
-            }
-            last = Some(curr);
-        }
-        output.push_str(last.unwrap().to_narrow_ipa(prev, None, false));
-
-        output
+        Phrase::new(slice::from_ref(self)).to_early_narrow_ipa()
     }
 
     pub fn to_late_narrow_ipa(&self) -> String {
-        let is_palatal = self.map_patalization();
-        let mut output = String::from("ˈ");
-
-        let mut prev = None;
-        let mut last = None;
-        let mut i = 0;
-        for syllable in &self.syllables {
-            let mut iter = syllable.phonemes();
-            let mut curr = last.or_else(|| iter.next()).unwrap();
-            let mut first = true;
-            for next in iter {
-                output.push_str(curr.to_narrow_ipa(
-                    prev,
-                    Some(next),
-                    is_palatal[i],
-                ));
-                if first {
-                    if last.is_some() {
-                        output.push('.');
-                    }
-                    first = false;
-                }
-                prev = Some(curr);
-                curr = next;
-                i += 1;
-            }
-            last = Some(curr);
-        }
-        output.push_str(last.unwrap().to_narrow_ipa(prev, None, is_palatal[i]));
-
-        output
-    }
-
-    fn map_patalization(&self) -> Vec<bool> {
-        let mut is_palatal = Vec::new();
-        let mut prev = None;
-        for phoneme in self.phonemes() {
-            let curr_palatal = if let Some(prev) = prev {
-                let can_be = phoneme.can_be_palatalized_progress(prev);
-                let prev_palatal = is_palatal.last().cloned().unwrap_or(false);
-                let curr_palatal = phoneme.is_palatal();
-                can_be && prev_palatal || curr_palatal
-            } else {
-                false
-            };
-            is_palatal.push(curr_palatal);
-            prev = Some(phoneme);
-        }
-
-        let mut prev = None;
-        for (i, phoneme) in self.phonemes().rev().enumerate() {
-            let i = is_palatal.len() - 1 - i;
-            let can_be = phoneme.can_be_palatalized_regress();
-            let prev_palatal = prev.map_or(false, |is| is);
-            if can_be && prev_palatal {
-                is_palatal[i] = true;
-            }
-            prev = Some(is_palatal[i]);
-        }
-
-        is_palatal
+        Phrase::new(slice::from_ref(self)).to_late_narrow_ipa()
     }
 
     pub fn to_text(&self) -> String {
@@ -317,55 +272,16 @@ impl Word {
         Self::new(syllables)
     }
 
-    fn same_audio(text: &str) -> Option<bool> {
-        match text {
-            "saŋ" | "dse" | "gas" | "kef" | "fwi" | "mací" | "reŋ" | "nyá"
-            | "xim" | "xím" | "kas" | "fá" | "jí" | "ḱen" | "pa" | "eta"
-            | "r" | "etif" | "etiŕ" | "wiya" | "wiyabi" => Some(true),
-            "saysen" | "says" | "saysé" | "sayiẋ" | "dseńix" | "macis"
-            | "éyxám" => Some(false),
-            _ => None,
-        }
+    /// Recording of this word in the given accent, if the audio manifest
+    /// lists one.
+    pub fn recording(&self, accent: Accent) -> Option<&'static Recording> {
+        Manifest::get().find(&self.to_text(), accent)
     }
 
-    pub fn audio_early(&self) -> Option<Audio> {
-        let text = self.to_text();
-        let suffix = match Self::same_audio(&text) {
-            Some(true) => Some(""),
-            Some(false) => Some("-early"),
-            None => None,
-        };
-        if let Some(suffix) = suffix {
-            let location = Location::internal(format!(
-                "{}/audio/{}{}.ogg",
-                StarLang.path(),
-                text,
-                suffix,
-            ));
-            Some(Audio(location))
-        } else {
-            None
-        }
-    }
-
-    pub fn audio_late(&self) -> Option<Audio> {
-        let text = self.to_text();
-        let suffix = match Self::same_audio(&text) {
-            Some(true) => Some(""),
-            Some(false) => Some("-late"),
-            None => None,
-        };
-        if let Some(suffix) = suffix {
-            let location = Location::internal(format!(
-                "{}/audio/{}{}.ogg",
-                StarLang.path(),
-                text,
-                suffix,
-            ));
-            Some(Audio(location))
-        } else {
-            None
-        }
+    /// Recording of this word in the given accent, or a synthesized clip if
+    /// there is none.
+    pub fn clip(&self, accent: Accent) -> Clip {
+        Clip::of(self, accent)
     }
 
     fn find_nucleus(phonemes: &[Phoneme], initial: bool) -> Option<usize> {
@@ -387,13 +303,15 @@ impl Word {
         pos
     }
 
-    fn find_boundary(phonemes: &[Phoneme]) -> usize {
-        phonemes
+    /// Where the onset starts in the cluster between two nuclei. A stop can
+    /// only start an onset, so the first one does; otherwise the cluster is
+    /// split evenly, with the onset taking the extra phoneme, as `Word::new`
+    /// requires.
+    fn find_boundary(cluster: &[Phoneme]) -> usize {
+        cluster
             .iter()
-            .map(|ph| ph.classify())
-            .enumerate()
-            .max_by_key(|&(_, cls)| cls)
-            .map_or(0, |(i, _)| i)
+            .position(|ph| ph.has(Feature::Stop))
+            .unwrap_or(cluster.len() / 2)
     }
 }
 
@@ -405,23 +323,7 @@ impl PartialOrd for Word {
 
 impl Ord for Word {
     fn cmp(&self, other: &Self) -> Ordering {
-        let mut self_iter = self.phonemes();
-        let mut other_iter = other.phonemes();
-
-        loop {
-            match (self_iter.next(), other_iter.next()) {
-                (Some(self_ph), Some(other_ph)) => {
-                    let ordering = self_ph.cmp(&other_ph);
-                    if ordering != Ordering::Equal {
-                        break ordering;
-                    }
-                },
-
-                (Some(_), None) => break Ordering::Greater,
-                (None, Some(_)) => break Ordering::Less,
-                (None, None) => break Ordering::Equal,
-            }
-        }
+        Collation::TRADITIONAL.compare(self.phonemes(), other.phonemes())
     }
 }
 
@@ -446,8 +348,8 @@ impl Parse for Word {
             match Self::find_nucleus(&slice[nucleus + 1 ..], false) {
                 Some(relative_next) => {
                     let next = nucleus + 1 + relative_next;
-                    let boundary =
-                        nucleus + Self::find_boundary(&slice[nucleus .. next]);
+                    let cluster = &slice[nucleus + 1 .. next];
+                    let boundary = nucleus + 1 + Self::find_boundary(cluster);
 
                     let syllable = Syllable::parse(&slice[.. boundary])?;
                     output.push(syllable);
@@ -492,22 +394,22 @@ impl Syllable {
         nucleus: Phoneme,
         coda: Coda,
     ) -> anyhow::Result<Self> {
-        use Phoneme::*;
-        use PhonemeClass::*;
-
-        if nucleus.classify() != Vowel {
-            if nucleus != R || onset.inner == Some(R) || coda.inner == Some(R) {
-                Err(InvalidSyllable {
-                    onset: onset.clone(),
-                    nucleus,
-                    coda: coda.clone(),
-                })?;
-            }
+        if !Self::valid(onset, nucleus, coda) {
+            Err(InvalidSyllable { onset, nucleus, coda })?;
         }
 
         Ok(Self { onset, nucleus, coda })
     }
 
+    /// A nucleus is a vowel, or R when neither the onset nor the coda has an
+    /// inner R.
+    fn valid(onset: Onset, nucleus: Phoneme, coda: Coda) -> bool {
+        use Phoneme::*;
+
+        nucleus.classify() == PhonemeClass::Vowel
+            || nucleus == R && onset.inner != Some(R) && coda.inner != Some(R)
+    }
+
     pub fn onset(&self) -> Onset {
         self.onset
     }
@@ -529,11 +431,59 @@ impl Syllable {
             .chain(self.coda.phonemes())
     }
 
-    pub fn to_broad_ipa(&self) -> Cow<str> {
+    /// Every valid syllable.
+    pub fn all() -> impl Iterator<Item = Self> {
+        let codas = Coda::all().collect::<Vec<_>>();
+        Onset::all().flat_map(move |onset| {
+            let codas = codas.clone();
+            Phoneme::ALL
+                .iter()
+                .copied()
+                .filter(|ph| ph.can_be_nucleus())
+                .flat_map(move |nucleus| {
+                    codas
+                        .clone()
+                        .into_iter()
+                        .filter(move |&coda| Self::valid(onset, nucleus, coda))
+                        .map(move |coda| Self { onset, nucleus, coda })
+                })
+        })
+    }
+
+    /// Number of valid syllables, counted without enumerating them: any
+    /// onset and coda go with a vowel, and those without an inner R with R.
+    pub fn count() -> usize {
+        let vowels = Phoneme::ALL
+            .iter()
+            .filter(|ph| ph.classify() == PhonemeClass::Vowel)
+            .count();
+        let onsets = Onset::all().map(|onset| onset.inner).collect::<Vec<_>>();
+        let codas = Coda::all().map(|coda| coda.inner).collect::<Vec<_>>();
+        let without_r = |inners: &[Option<Phoneme>]| {
+            inners.iter().filter(|&&inner| inner != Some(Phoneme::R)).count()
+        };
+
+        onsets.len() * codas.len() * vowels
+            + without_r(&onsets) * without_r(&codas)
+    }
+
+    pub fn to_text(&self) -> String {
+        format!("{}", self)
+    }
+
+    pub fn weight(&self) -> Weight {
+        if self.nucleus.has(Feature::Long) || self.coda.phonemes().count() > 0 {
+            Weight::Heavy
+        } else {
+            Weight::Light
+        }
+    }
+
+    pub fn to_broad_ipa(&self) -> Cow<'_, str> {
         let mut output = String::new();
 
         for phoneme in self.phonemes() {
-            output.push_str(&phoneme.to_broad_ipa());
+            output.push_str(phoneme.to_broad_ipa());
         }
 
         Cow::from(output)
@@ -584,6 +534,16 @@ impl Ord for Syllable {
     }
 }
 
+impl fmt::Display for Syllable {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for ph in self.phonemes() {
+            write!(fmt, "{}", ph.to_text())?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Parse for Syllable {
     fn parse(phonemes: &[Phoneme]) -> anyhow::Result<Self> {
         let nucleus = Self::find_nucleus(phonemes).ok_or_else(|| {
@@ -626,20 +586,23 @@ impl Onset {
         medial: Option<Phoneme>,
         inner: Option<Phoneme>,
     ) -> anyhow::Result<Self> {
-        let outer_cls = outer.map(|ph| ph.classify());
-        let medial_cls = medial.map(|ph| ph.classify());
-        let inner_cls = inner.map(|ph| ph.classify());
-
-        if !Self::valid_outer_medial(outer_cls, medial_cls)
-            || !Self::valid_inner(inner_cls)
-            || outer.is_some() && outer == medial
-        {
+        if !Self::valid(outer, medial, inner) {
             Err(InvalidOnset { outer, medial, inner })?
         }
 
         Ok(Self { outer, medial, inner })
     }
 
+    fn valid(
+        outer: Option<Phoneme>,
+        medial: Option<Phoneme>,
+        inner: Option<Phoneme>,
+    ) -> bool {
+        Self::valid_outer_medial(outer, medial)
+            && Self::valid_inner(inner)
+            && !(outer.is_some() && outer == medial)
+    }
+
     pub fn replace_keep_plosive(
         self,
         outer_medial: Option<Phoneme>,
@@ -677,29 +640,48 @@ impl Onset {
         self.inner
     }
 
+    /// Every valid onset, including the empty one.
+    pub fn all() -> impl Iterator<Item = Self> {
+        optional_phonemes().flat_map(|outer| {
+            optional_phonemes().flat_map(move |medial| {
+                optional_phonemes()
+                    .filter(move |&inner| Self::valid(outer, medial, inner))
+                    .map(move |inner| Self { outer, medial, inner })
+            })
+        })
+    }
+
+    pub fn to_text(&self) -> String {
+        format!("{}", self)
+    }
+
+    /// A stop may be followed by a fricative, a nasal or a stop of the same
+    /// laryngeal setting; a fricative, or nothing, only by a nasal.
     pub fn valid_outer_medial(
-        outer: Option<PhonemeClass>,
-        medial: Option<PhonemeClass>,
+        outer: Option<Phoneme>,
+        medial: Option<Phoneme>,
     ) -> bool {
-        use PhonemeClass::*;
-
-        match outer {
-            Some(Ejective) => matches!(
-                medial,
-                Some(Ejective) | Some(Fricative) | Some(Nasal) | None
-            ),
-            Some(Aspirated) => matches!(
-                medial,
-                Some(Aspirated) | Some(Fricative) | Some(Nasal) | None
-            ),
-            Some(Fricative) | None => matches!(medial, Some(Nasal) | None),
+        let outer = outer.map(Phoneme::features);
+        let medial = medial.map(Phoneme::features);
+        let medial_is = |manner| medial.is_some_and(|f| f.manner == manner);
+
+        match outer.map(|f| (f.manner, f.laryngeal)) {
+            Some((Manner::Stop, laryngeal)) => {
+                medial.is_none()
+                    || medial_is(Manner::Fricative)
+                    || medial_is(Manner::Nasal)
+                    || medial_is(Manner::Stop)
+                        && medial.is_some_and(|f| f.laryngeal == laryngeal)
+            },
+            Some((Manner::Fricative, _)) | None => {
+                medial.is_none() || medial_is(Manner::Nasal)
+            },
             _ => false,
         }
     }
 
-    pub fn valid_inner(inner: Option<PhonemeClass>) -> bool {
-        use PhonemeClass::*;
-        matches!(inner, Some(Approximant) | None)
+    pub fn valid_inner(inner: Option<Phoneme>) -> bool {
+        inner.is_none_or(|ph| ph.has(Feature::Approximant))
     }
 
     pub fn phonemes<'this>(
@@ -708,7 +690,7 @@ impl Onset {
         iter::once(self.outer)
             .chain(iter::once(self.medial))
             .chain(iter::once(self.inner))
-            .filter_map(|opt| opt)
+            .flatten()
     }
 }
 
@@ -740,32 +722,39 @@ impl Ord for Onset {
     }
 }
 
+impl fmt::Display for Onset {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for ph in self.phonemes() {
+            write!(fmt, "{}", ph.to_text())?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Parse for Onset {
     fn parse(phonemes: &[Phoneme]) -> anyhow::Result<Self> {
-        match phonemes {
-            &[] => Self::new(None, None, None),
-            &[first] => {
-                let class = first.classify();
-                if Self::valid_outer_medial(Some(class), None) {
+        match *phonemes {
+            [] => Self::new(None, None, None),
+            [first] => {
+                if Self::valid_outer_medial(Some(first), None) {
                     Self::new(Some(first), None, None)
-                } else if Self::valid_outer_medial(None, Some(class)) {
+                } else if Self::valid_outer_medial(None, Some(first)) {
                     Self::new(None, Some(first), None)
                 } else {
                     Self::new(None, None, Some(first))
                 }
             },
-            &[first, second] => {
-                let first_cls = first.classify();
-                let second_cls = first.classify();
-                if Self::valid_outer_medial(Some(first_cls), Some(second_cls)) {
+            [first, second] => {
+                if Self::valid_outer_medial(Some(first), Some(second)) {
                     Self::new(Some(first), Some(second), None)
-                } else if Self::valid_outer_medial(Some(first_cls), None) {
+                } else if Self::valid_outer_medial(Some(first), None) {
                     Self::new(Some(first), None, Some(second))
                 } else {
                     Self::new(None, Some(first), Some(second))
                 }
             },
-            &[first, second, third] => {
+            [first, second, third] => {
                 Self::new(Some(first), Some(second), Some(third))
             },
             _ => Err(OnsetParseError { phonemes: phonemes.to_vec() })?,
@@ -800,10 +789,7 @@ impl Coda {
         inner: Option<Phoneme>,
         outer: Option<Phoneme>,
     ) -> anyhow::Result<Self> {
-        let inner_cls = inner.map(|ph| ph.classify());
-        let outer_cls = outer.map(|ph| ph.classify());
-
-        if !Self::valid_inner(inner_cls) || !Self::valid_outer(outer_cls) {
+        if !Self::valid_inner(inner) || !Self::valid_outer(outer) {
             Err(InvalidCoda { inner, outer })?
         }
         Ok(Self { inner, outer })
@@ -817,14 +803,29 @@ impl Coda {
         self.outer
     }
 
-    pub fn valid_outer(outer: Option<PhonemeClass>) -> bool {
-        use PhonemeClass::*;
-        matches!(outer, Some(Fricative) | Some(Nasal) | None)
+    /// Every valid coda, including the empty one.
+    pub fn all() -> impl Iterator<Item = Self> {
+        optional_phonemes().flat_map(|inner| {
+            optional_phonemes()
+                .filter(move |&outer| {
+                    Self::valid_inner(inner) && Self::valid_outer(outer)
+                })
+                .map(move |outer| Self { inner, outer })
+        })
     }
 
-    pub fn valid_inner(inner: Option<PhonemeClass>) -> bool {
-        use PhonemeClass::*;
-        matches!(inner, Some(Approximant) | None)
+    pub fn to_text(&self) -> String {
+        format!("{}", self)
+    }
+
+    pub fn valid_outer(outer: Option<Phoneme>) -> bool {
+        outer.is_none_or(|ph| {
+            ph.has(Feature::Fricative) || ph.has(Feature::Nasal)
+        })
+    }
+
+    pub fn valid_inner(inner: Option<Phoneme>) -> bool {
+        inner.is_none_or(|ph| ph.has(Feature::Approximant))
     }
 
     pub fn phonemes<'this>(
@@ -832,7 +833,7 @@ impl Coda {
     ) -> impl DoubleEndedIterator<Item = Phoneme> + 'this {
         iter::once(self.inner)
             .chain(iter::once(self.outer))
-            .filter_map(|opt| opt)
+            .flatten()
     }
 }
 
@@ -864,18 +865,28 @@ impl Ord for Coda {
     }
 }
 
+impl fmt::Display for Coda {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for ph in self.phonemes() {
+            write!(fmt, "{}", ph.to_text())?;
+        }
+
+        Ok(())
+    }
+}
+
 impl Parse for Coda {
     fn parse(phonemes: &[Phoneme]) -> anyhow::Result<Self> {
-        match phonemes {
-            &[] => Self::new(None, None),
-            &[first] => {
-                if Self::valid_inner(Some(first.classify())) {
+        match *phonemes {
+            [] => Self::new(None, None),
+            [first] => {
+                if Self::valid_inner(Some(first)) {
                     Self::new(Some(first), None)
                 } else {
                     Self::new(None, Some(first))
                 }
             },
-            &[first, second] => Self::new(Some(first), Some(second)),
+            [first, second] => Self::new(Some(first), Some(second)),
             _ => Err(CodaParseError { phonemes: phonemes.to_vec() })?,
         }
     }
@@ -894,7 +905,9 @@ pub struct CodaParseError {
     pub phonemes: Vec<Phoneme>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A phoneme, which is also a letter of the alphabet. Phonemes are ordered
+/// as in `collation::ALPHABET`, not as declared here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Phoneme {
     B,
     Gw,
@@ -929,6 +942,39 @@ pub enum Phoneme {
 }
 
 impl Phoneme {
+    pub const ALL: &'static [Self] = &[
+        Phoneme::B,
+        Phoneme::Gw,
+        Phoneme::D,
+        Phoneme::J,
+        Phoneme::G,
+        Phoneme::P,
+        Phoneme::Kw,
+        Phoneme::T,
+        Phoneme::C,
+        Phoneme::K,
+        Phoneme::M,
+        Phoneme::Mg,
+        Phoneme::N,
+        Phoneme::Nj,
+        Phoneme::Ng,
+        Phoneme::F,
+        Phoneme::Xw,
+        Phoneme::W,
+        Phoneme::S,
+        Phoneme::R,
+        Phoneme::Y,
+        Phoneme::Ii,
+        Phoneme::X,
+        Phoneme::I,
+        Phoneme::Ee,
+        Phoneme::H,
+        Phoneme::E,
+        Phoneme::Rr,
+        Phoneme::A,
+        Phoneme::Aa,
+    ];
+
     pub fn new(ch: char) -> anyhow::Result<Self> {
         use Phoneme::*;
 
@@ -969,141 +1015,116 @@ impl Phoneme {
         Ok(phoneme)
     }
 
-    pub fn is_palatal(self) -> bool {
+    pub fn features(self) -> Features {
+        use Height::*;
+        use Laryngeal::*;
+        use Manner::*;
         use Phoneme::*;
-        matches!(self, C | J | Nj | Y)
+        use Place::*;
+
+        match self {
+            B => Features::consonant(Labial, Stop, Ejective),
+            Gw => Features::consonant(LabioVelar, Stop, Ejective),
+            D => Features::consonant(Alveolar, Stop, Ejective),
+            J => Features::consonant(Palatal, Stop, Ejective),
+            G => Features::consonant(Velar, Stop, Ejective),
+            P => Features::consonant(Labial, Stop, Aspirated),
+            Kw => Features::consonant(LabioVelar, Stop, Aspirated),
+            T => Features::consonant(Alveolar, Stop, Aspirated),
+            C => Features::consonant(Palatal, Stop, Aspirated),
+            K => Features::consonant(Velar, Stop, Aspirated),
+            M => Features::consonant(Labial, Nasal, Voiced),
+            Mg => Features::consonant(LabioVelar, Nasal, Voiced),
+            N => Features::consonant(Alveolar, Nasal, Voiced),
+            Nj => Features::consonant(Palatal, Nasal, Voiced),
+            Ng => Features::consonant(Velar, Nasal, Voiced),
+            F => Features::consonant(Labial, Fricative, Voiceless),
+            Xw => Features::consonant(LabioVelar, Fricative, Voiceless),
+            W => Features::consonant(LabioVelar, Approximant, Voiced),
+            S => Features::consonant(Alveolar, Fricative, Voiceless),
+            R => Features::consonant(Alveolar, Approximant, Voiced),
+            Y => Features::consonant(Palatal, Approximant, Voiced),
+            Ii => Features::vowel(High, Backness::Front, true),
+            X => Features::consonant(Velar, Fricative, Voiceless),
+            I => Features::vowel(High, Backness::Front, false),
+            Ee => Features::vowel(Mid, Backness::Front, true),
+            H => Features::consonant(Pharyngeal, Fricative, Voiceless),
+            E => Features::vowel(Mid, Backness::Front, false),
+            Rr => Features::consonant(Pharyngeal, Approximant, Voiced),
+            A => Features::vowel(Low, Backness::Central, false),
+            Aa => Features::vowel(Low, Backness::Central, true),
+        }
+    }
+
+    pub fn has(self, feature: Feature) -> bool {
+        self.features().has(feature)
+    }
+
+    pub fn is_palatal(self) -> bool {
+        self.has(Feature::Consonantal) && self.has(Feature::Palatal)
     }
 
     pub fn can_be_palatalized_regress(self) -> bool {
-        use Phoneme::*;
-        matches!(self, S | X | Xw)
+        self.has(Feature::Fricative)
+            && (self.has(Feature::Coronal) || self.has(Feature::Dorsal))
     }
 
     pub fn can_be_palatalized_progress(self, prev: Self) -> bool {
-        use Phoneme::*;
-        match self {
-            S | X | Xw => true,
-            A | Aa | E | Ee | I | Ii => prev.classify() != PhonemeClass::Vowel,
-            _ => false,
-        }
+        self.can_be_palatalized_regress()
+            || !self.has(Feature::Consonantal) && prev.has(Feature::Consonantal)
     }
 
     pub fn triggers_retraction(self) -> bool {
-        use Phoneme::*;
-        matches!(self, A | Aa)
+        self.has(Feature::Low)
     }
 
     pub fn triggers_front(self) -> bool {
-        use Phoneme::*;
-        matches!(self, C | J | Nj | Y | Rr | H)
+        self.has(Feature::Palatal) || self.has(Feature::Pharyngeal)
     }
 
     pub fn triggers_back(self) -> bool {
-        use Phoneme::*;
-        matches!(self, K | G | Ng | X)
+        self.has(Feature::Dorsal) && !self.has(Feature::Round)
     }
 
     pub fn triggers_back_rounded(self) -> bool {
-        use Phoneme::*;
-        matches!(self, Kw | Gw | Mg | Xw | W)
+        self.has(Feature::Dorsal) && self.has(Feature::Round)
     }
 
-    #[allow(dead_code)]
     pub fn can_be_nucleus(self) -> bool {
         use Phoneme::*;
         self.classify() == PhonemeClass::Vowel || self == R
     }
 
     pub fn classify(self) -> PhonemeClass {
-        use Phoneme::*;
-        match self {
-            A | Aa | E | Ee | I | Ii => PhonemeClass::Vowel,
-            R | Y | W | Rr => PhonemeClass::Approximant,
-            M | N | Nj | Ng | Mg => PhonemeClass::Nasal,
-            F | S | X | Xw | H => PhonemeClass::Fricative,
-            B | D | J | G | Gw => PhonemeClass::Ejective,
-            P | T | C | K | Kw => PhonemeClass::Aspirated,
+        let features = self.features();
+        match features.manner {
+            Manner::Vowel => PhonemeClass::Vowel,
+            Manner::Approximant => PhonemeClass::Approximant,
+            Manner::Nasal => PhonemeClass::Nasal,
+            Manner::Fricative => PhonemeClass::Fricative,
+            Manner::Stop => match features.laryngeal {
+                Laryngeal::Ejective => PhonemeClass::Ejective,
+                _ => PhonemeClass::Aspirated,
+            },
         }
     }
 
-    pub fn to_narrow_ipa(
-        &self,
-        prev: Option<Self>,
-        next: Option<Self>,
-        palatalized: bool,
-    ) -> &'static str {
-        use Phoneme::*;
-
-        let triggers_front =
-            prev.map_or(false, Phoneme::triggers_front) || palatalized;
-        let triggers_back = prev.map_or(false, Phoneme::triggers_back);
-        let triggers_back_rounded =
-            prev.map_or(false, Phoneme::triggers_back_rounded);
-        let triggers_retraction =
-            next.map_or(false, Phoneme::triggers_retraction);
-
-        match self {
-            B => "pʼ",
-            Gw if triggers_retraction => "qʷʼ",
-            Gw => "kʷʼ",
-            D => "tʼ",
-            J => "cʼ",
-            G if triggers_retraction => "qʼ",
-            G => "kʼ",
-            P => "pʰ",
-            Kw if triggers_retraction => "qʷʰ",
-            Kw => "kʷʰ",
-            T => "tʰ",
-            C => "cʰ",
-            K if triggers_retraction => "qʰ",
-            K => "kʰ",
-            M => "m",
-            Mg if triggers_retraction => "ɴ͡mʷ",
-            Mg => "ŋ͡mʷ",
-            N => "n",
-            Nj => "ɲ",
-            Ng if triggers_retraction => "ɴ",
-            Ng => "ŋ",
-            F => "ɸ",
-            Xw if palatalized => "çʷ",
-            Xw if triggers_retraction => "χʷ",
-            Xw => "xʷ",
-            W if triggers_retraction => "w̠",
-            W => "w",
-            S if palatalized => "ɕ",
-            S => "s",
-            R => "ɹ",
-            Y => "j",
-            Ii if triggers_front => "iː",
-            Ii if triggers_back => "ɯə̯",
-            Ii if triggers_back_rounded => "uː",
-            Ii => "ɨː",
-            X if palatalized => "ç",
-            X if triggers_retraction => "χ",
-            X => "x",
-            I if triggers_front => "i",
-            I if triggers_back => "ɯ",
-            I if triggers_back_rounded => "u",
-            I => "ɨ",
-            Ee if triggers_front => "e̞ː",
-            Ee if triggers_back => "ɤ̞ə̯",
-            Ee if triggers_back_rounded => "o̞ː",
-            Ee => "əː",
-            H => "ħ",
-            E if triggers_front => "e̞",
-            E if triggers_back => "ɤ̞",
-            E if triggers_back_rounded => "o̞",
-            E => "ə",
-            Rr => "ʕ",
-            A if triggers_front => "æ",
-            A if triggers_back => "ɑ",
-            A if triggers_back_rounded => "ɒ",
-            A => "ä",
-            Aa if triggers_front => "æː",
-            Aa if triggers_back => "ɑː",
-            Aa if triggers_back_rounded => "ɒɔ̯",
-            Aa => "äː",
+    /// Every narrow realization of this phoneme, found by realizing it in
+    /// every context. The isolated realization comes first.
+    pub fn allophones(self) -> Vec<String> {
+        let mut allophones = Vec::new();
+        for palatalized in [false, true] {
+            for prev in optional_phonemes() {
+                for next in optional_phonemes() {
+                    let allophone =
+                        sound_change::realize(self, prev, next, palatalized);
+                    if !allophones.contains(&allophone) {
+                        allophones.push(allophone);
+                    }
+                }
+            }
         }
+        allophones
     }
 
     pub fn to_text(&self) -> &'static str {
@@ -1291,6 +1312,23 @@ mod test {
         Coda::new(Some(M), Some(Y)).unwrap_err();
     }
 
+    #[test]
+    fn inventory_counts() {
+        assert_eq!(Onset::all().count(), 930);
+        assert_eq!(Coda::all().count(), 55);
+        assert_eq!(Syllable::count(), 339636);
+        assert_eq!(Syllable::all().count(), Syllable::count());
+    }
+
+    #[test]
+    fn enumerated_syllables_round_trip() {
+        for syllable in Syllable::all() {
+            let text = syllable.to_text();
+            let parsed = Syllable::parse_str(&text).unwrap();
+            assert_eq!(parsed, syllable, "{}", text);
+        }
+    }
+
     #[test]
     fn syllables() {
         use Phoneme::*;
@@ -1389,4 +1427,13 @@ mod test {
         ])
         .unwrap_err();
     }
+
+    #[test]
+    fn allophones() {
+        use Phoneme::*;
+        assert_eq!(B.allophones(), ["pʼ"]);
+        assert_eq!(G.allophones(), ["kʼ", "qʼ"]);
+        assert_eq!(S.allophones(), ["s", "ɕ"]);
+        assert_eq!(A.allophones().len(), 4);
+    }
 }
@@ -0,0 +1,434 @@
+use super::Phoneme;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+#[derive(Debug, Clone, Error)]
+#[error("Unknown phonological feature {name:?}")]
+pub struct UnknownFeature {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Invalid natural class {class:?}")]
+pub struct InvalidNaturalClass {
+    pub class: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Place {
+    Labial,
+    Alveolar,
+    Palatal,
+    Velar,
+    LabioVelar,
+    Pharyngeal,
+}
+
+impl Place {
+    pub const ALL: &'static [Self] = &[
+        Place::Labial,
+        Place::Alveolar,
+        Place::Palatal,
+        Place::Velar,
+        Place::LabioVelar,
+        Place::Pharyngeal,
+    ];
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Place::Labial => "labial",
+            Place::Alveolar => "alveolar",
+            Place::Palatal => "palatal",
+            Place::Velar => "velar",
+            Place::LabioVelar => "labio-velar",
+            Place::Pharyngeal => "pharyngeal",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Manner {
+    Stop,
+    Nasal,
+    Fricative,
+    Approximant,
+    Vowel,
+}
+
+impl Manner {
+    pub const ALL: &'static [Self] = &[
+        Manner::Stop,
+        Manner::Nasal,
+        Manner::Fricative,
+        Manner::Approximant,
+        Manner::Vowel,
+    ];
+}
+
+impl fmt::Display for Manner {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Manner::Stop => "stop",
+            Manner::Nasal => "nasal",
+            Manner::Fricative => "fricative",
+            Manner::Approximant => "approximant",
+            Manner::Vowel => "vowel",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Laryngeal {
+    Ejective,
+    Aspirated,
+    Voiceless,
+    Voiced,
+}
+
+impl Laryngeal {
+    pub const ALL: &'static [Self] = &[
+        Laryngeal::Ejective,
+        Laryngeal::Aspirated,
+        Laryngeal::Voiceless,
+        Laryngeal::Voiced,
+    ];
+}
+
+impl fmt::Display for Laryngeal {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Laryngeal::Ejective => "ejective",
+            Laryngeal::Aspirated => "aspirated",
+            Laryngeal::Voiceless => "voiceless",
+            Laryngeal::Voiced => "voiced",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Height {
+    High,
+    Mid,
+    Low,
+}
+
+impl Height {
+    pub const ALL: &'static [Self] = &[Height::High, Height::Mid, Height::Low];
+}
+
+impl fmt::Display for Height {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Height::High => "high",
+            Height::Mid => "mid",
+            Height::Low => "low",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Backness {
+    Front,
+    Central,
+    Back,
+}
+
+impl Backness {
+    pub const ALL: &'static [Self] =
+        &[Backness::Front, Backness::Central, Backness::Back];
+}
+
+impl fmt::Display for Backness {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Backness::Front => "front",
+            Backness::Central => "central",
+            Backness::Back => "back",
+        })
+    }
+}
+
+/// Row of the feature matrix of a phoneme. Consonants have a place and no
+/// height or backness; vowels have height and backness and no place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Features {
+    pub place: Option<Place>,
+    pub manner: Manner,
+    pub laryngeal: Laryngeal,
+    pub round: bool,
+    pub height: Option<Height>,
+    pub backness: Option<Backness>,
+    pub long: bool,
+}
+
+impl Features {
+    pub const fn consonant(
+        place: Place,
+        manner: Manner,
+        laryngeal: Laryngeal,
+    ) -> Self {
+        Self {
+            place: Some(place),
+            manner,
+            laryngeal,
+            round: matches!(place, Place::LabioVelar),
+            height: None,
+            backness: None,
+            long: false,
+        }
+    }
+
+    pub const fn vowel(height: Height, backness: Backness, long: bool) -> Self {
+        Self {
+            place: None,
+            manner: Manner::Vowel,
+            laryngeal: Laryngeal::Voiced,
+            round: false,
+            height: Some(height),
+            backness: Some(backness),
+            long,
+        }
+    }
+
+    pub fn has(&self, feature: Feature) -> bool {
+        use Feature::*;
+
+        let place = |places: &[Place]| {
+            self.place.is_some_and(|place| places.contains(&place))
+        };
+
+        match feature {
+            Consonantal => self.manner != Manner::Vowel,
+            Labial => place(&[Place::Labial, Place::LabioVelar]),
+            Coronal => place(&[Place::Alveolar]),
+            Palatal => place(&[Place::Palatal]),
+            Dorsal => place(&[Place::Velar, Place::LabioVelar]),
+            Pharyngeal => place(&[Place::Pharyngeal]),
+            Stop => self.manner == Manner::Stop,
+            Nasal => self.manner == Manner::Nasal,
+            Fricative => self.manner == Manner::Fricative,
+            Approximant => self.manner == Manner::Approximant,
+            Ejective => self.laryngeal == Laryngeal::Ejective,
+            Aspirated => self.laryngeal == Laryngeal::Aspirated,
+            Voiced => self.laryngeal == Laryngeal::Voiced,
+            Round => self.round,
+            High => self.height == Some(Height::High),
+            Low => self.height == Some(Height::Low),
+            Front => self.backness == Some(Backness::Front),
+            Back => self.backness == Some(Backness::Back),
+            Long => self.long,
+        }
+    }
+}
+
+/// Binary view of the feature matrix, used to query natural classes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Feature {
+    Consonantal,
+    Labial,
+    Coronal,
+    Palatal,
+    Dorsal,
+    Pharyngeal,
+    Stop,
+    Nasal,
+    Fricative,
+    Approximant,
+    Ejective,
+    Aspirated,
+    Voiced,
+    Round,
+    High,
+    Low,
+    Front,
+    Back,
+    Long,
+}
+
+impl Feature {
+    pub const ALL: &'static [Self] = &[
+        Feature::Consonantal,
+        Feature::Labial,
+        Feature::Coronal,
+        Feature::Palatal,
+        Feature::Dorsal,
+        Feature::Pharyngeal,
+        Feature::Stop,
+        Feature::Nasal,
+        Feature::Fricative,
+        Feature::Approximant,
+        Feature::Ejective,
+        Feature::Aspirated,
+        Feature::Voiced,
+        Feature::Round,
+        Feature::High,
+        Feature::Low,
+        Feature::Front,
+        Feature::Back,
+        Feature::Long,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Feature::Consonantal => "consonantal",
+            Feature::Labial => "labial",
+            Feature::Coronal => "coronal",
+            Feature::Palatal => "palatal",
+            Feature::Dorsal => "dorsal",
+            Feature::Pharyngeal => "pharyngeal",
+            Feature::Stop => "stop",
+            Feature::Nasal => "nasal",
+            Feature::Fricative => "fricative",
+            Feature::Approximant => "approximant",
+            Feature::Ejective => "ejective",
+            Feature::Aspirated => "aspirated",
+            Feature::Voiced => "voiced",
+            Feature::Round => "round",
+            Feature::High => "high",
+            Feature::Low => "low",
+            Feature::Front => "front",
+            Feature::Back => "back",
+            Feature::Long => "long",
+        }
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.name())
+    }
+}
+
+impl FromStr for Feature {
+    type Err = UnknownFeature;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Feature::ALL
+            .iter()
+            .copied()
+            .find(|feature| feature.name() == name)
+            .ok_or_else(|| UnknownFeature { name: name.to_owned() })
+    }
+}
+
+/// A set of phonemes described by the features they have (`plus`) and the
+/// features they lack (`minus`), written e.g. `[+dorsal -round]`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NaturalClass {
+    pub plus: Vec<Feature>,
+    pub minus: Vec<Feature>,
+}
+
+impl NaturalClass {
+    pub fn parse<S>(class: S) -> anyhow::Result<Self>
+    where
+        S: AsRef<str>,
+    {
+        let class = class.as_ref();
+        let invalid = || InvalidNaturalClass { class: class.to_owned() };
+
+        let contents = class
+            .trim()
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .ok_or_else(invalid)?;
+
+        let mut this = Self::default();
+        let specs = contents
+            .split(|ch: char| ch.is_whitespace() || ch == ',')
+            .filter(|spec| !spec.is_empty());
+        for spec in specs {
+            if let Some(name) = spec.strip_prefix('+') {
+                this.plus.push(name.parse()?);
+            } else if let Some(name) = spec.strip_prefix('-') {
+                this.minus.push(name.parse()?);
+            } else {
+                Err(invalid())?;
+            }
+        }
+
+        Ok(this)
+    }
+
+    pub fn contains(&self, phoneme: Phoneme) -> bool {
+        let features = phoneme.features();
+        self.plus.iter().all(|&feature| features.has(feature))
+            && self.minus.iter().all(|&feature| !features.has(feature))
+    }
+
+    pub fn members(&self) -> Vec<Phoneme> {
+        Phoneme::ALL
+            .iter()
+            .copied()
+            .filter(|&phoneme| self.contains(phoneme))
+            .collect()
+    }
+}
+
+impl fmt::Display for NaturalClass {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "[")?;
+        let specs = self
+            .plus
+            .iter()
+            .map(|feature| ('+', feature))
+            .chain(self.minus.iter().map(|feature| ('-', feature)));
+        for (i, (sign, feature)) in specs.enumerate() {
+            if i > 0 {
+                write!(fmt, " ")?;
+            }
+            write!(fmt, "{}{}", sign, feature)?;
+        }
+        write!(fmt, "]")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn members(class: &str) -> Vec<Phoneme> {
+        NaturalClass::parse(class).unwrap().members()
+    }
+
+    #[test]
+    fn natural_classes() {
+        use Phoneme::*;
+
+        assert_eq!(
+            members("[+consonantal +dorsal -round]"),
+            vec![G, K, Ng, X]
+        );
+        assert_eq!(members("[+dorsal +round]"), vec![Gw, Kw, Mg, Xw, W]);
+        assert_eq!(members("[+palatal]"), vec![J, C, Nj, Y]);
+        assert_eq!(members("[+low]"), vec![A, Aa]);
+        assert_eq!(members("[-consonantal +long]"), vec![Ii, Ee, Aa]);
+        assert!(NaturalClass::parse("[+sonorant]").is_err());
+        assert!(NaturalClass::parse("+dorsal").is_err());
+    }
+
+    #[test]
+    fn classes_agree_with_features() {
+        use Phoneme::*;
+
+        let palatal = [C, J, Nj, Y];
+        let front = [C, J, Nj, Y, Rr, H];
+        let back = [K, G, Ng, X];
+        let back_rounded = [Kw, Gw, Mg, Xw, W];
+        for &phoneme in Phoneme::ALL {
+            assert_eq!(phoneme.is_palatal(), palatal.contains(&phoneme));
+            assert_eq!(phoneme.triggers_front(), front.contains(&phoneme));
+            assert_eq!(phoneme.triggers_back(), back.contains(&phoneme));
+            assert_eq!(
+                phoneme.triggers_back_rounded(),
+                back_rounded.contains(&phoneme)
+            );
+            assert_eq!(
+                phoneme.can_be_palatalized_regress(),
+                [S, X, Xw].contains(&phoneme)
+            );
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use super::{
+    balance_cluster,
+    prosody::Stress,
+    sound_change::{self, EARLY},
+    Coda,
+    Onset,
+    Phoneme,
+    Syllable,
+    Word,
+};
+use wfts_lang::sound_change::{Derivation, Segment};
+
+/// Words spoken together. Clusters at word boundaries are balanced as they
+/// would be inside a word, and allophony looks at the neighbouring words, so
+/// a consonant may move to the next word and palatalization and retraction
+/// cross word boundaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phrase {
+    words: Vec<Vec<Syllable>>,
+    stresses: Vec<Vec<Stress>>,
+}
+
+impl Phrase {
+    pub fn new(words: &[Word]) -> Self {
+        let mut syllables =
+            words.iter().map(|word| word.syllables.clone()).collect::<Vec<_>>();
+
+        for i in 1 .. syllables.len() {
+            let (init, rest) = syllables.split_at_mut(i);
+            let last = init[i - 1].last_mut().unwrap();
+            let first = rest[0].first_mut().unwrap();
+            Self::balance_boundary(last, first);
+        }
+
+        Self {
+            words: syllables,
+            stresses: words.iter().map(Word::stresses).collect(),
+        }
+    }
+
+    /// Balances the cluster between two words, keeping the original one if
+    /// the balanced cluster is not valid.
+    fn balance_boundary(last: &mut Syllable, first: &mut Syllable) {
+        let mut coda = last.coda;
+        let mut onset = first.onset;
+        balance_cluster(&mut coda, &mut onset);
+
+        let coda = Coda::new(coda.inner, coda.outer);
+        let onset = Onset::new(onset.outer, onset.medial, onset.inner);
+        if let (Ok(coda), Ok(onset)) = (coda, onset) {
+            let balanced_last = Syllable::new(last.onset, last.nucleus, coda);
+            let balanced_first =
+                Syllable::new(onset, first.nucleus, first.coda);
+            if let (Ok(balanced_last), Ok(balanced_first)) =
+                (balanced_last, balanced_first)
+            {
+                *last = balanced_last;
+                *first = balanced_first;
+            }
+        }
+    }
+
+    /// Syllables of each word after the clusters were balanced.
+    pub fn words(&self) -> &[Vec<Syllable>] {
+        &self.words
+    }
+
+    pub fn phonemes(&self) -> impl Iterator<Item = Phoneme> + '_ {
+        self.words.iter().flatten().flat_map(Syllable::phonemes)
+    }
+
+    /// Each phoneme along with the word boundary and stress mark written
+    /// before it, if any.
+    fn segments(&self) -> Vec<(String, Phoneme)> {
+        let mut segments = Vec::new();
+
+        for (i, word) in self.words.iter().enumerate() {
+            for (j, syllable) in word.iter().enumerate() {
+                let stress = self.stresses[i][j];
+                let mut mark = match (i, j) {
+                    (0, 0) => String::new(),
+                    (_, 0) => String::from(" "),
+                    _ => String::new(),
+                };
+                if j == 0 {
+                    mark.push_str(stress.initial_mark());
+                } else {
+                    mark.push_str(stress.mark());
+                }
+
+                for phoneme in syllable.phonemes() {
+                    segments.push((mark, phoneme));
+                    mark = String::new();
+                }
+            }
+        }
+
+        segments
+    }
+
+    /// Derivation of the pronunciation of the whole phrase, so that the
+    /// rules see across word boundaries.
+    pub fn derivation(&self) -> Derivation {
+        sound_change::derive(self.phonemes())
+    }
+
+    /// Writes a derived form with the word boundaries and stress marks.
+    fn transcribe(&self, form: &[Segment]) -> String {
+        let segments = self.segments();
+        assert_eq!(segments.len(), form.len(), "segments added or deleted");
+        segments
+            .iter()
+            .zip(form)
+            .map(|((mark, _), segment)| format!("{}{}", mark, segment))
+            .collect()
+    }
+
+    pub fn to_early_narrow_ipa(&self) -> String {
+        let derivation = self.derivation();
+        let early = derivation.after_stage(EARLY).unwrap_or(&derivation.input);
+        self.transcribe(early)
+    }
+
+    pub fn to_late_narrow_ipa(&self) -> String {
+        self.transcribe(self.derivation().output())
+    }
+
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::phonology::Parse;
+
+    fn phrase(texts: &[&str]) -> Phrase {
+        let words = texts
+            .iter()
+            .map(|text| Word::parse_str(text).unwrap())
+            .collect::<Vec<_>>();
+        Phrase::new(&words)
+    }
+
+    #[test]
+    fn transcriptions() {
+        let cases: &[(&[&str], &str, &str)] = &[
+            (&["saŋ"], "ˈsäŋ", "ˈsäŋ"),
+            (&["dseńix"], "tʼsəˈɲix", "tʼsəˈɲiç"),
+            (&["wiyabi"], "ˈwu.jæ.pʼɨ", "ˈwu.jæ.pʼɨ"),
+            (&["éyxám"], "ˈəːj.χɑːm", "ˈəːj.çæːm"),
+            (&["kas", "eta"], "ˈqʰɑ ˈsə.tʰä", "ˈqʰɑ ˈsə.tʰä"),
+            (&["kas", "jí"], "ˈqʰɑs ˈcʼiː", "ˈqʰɑɕ ˈcʼiː"),
+            (
+                &["saŋé", "wiyíbi", "pa", "dsen"],
+                "säˈŋɤ̞ə̯ wuˈjiː.pʼɨ ˈpʰä ˈtʼsən",
+                "säˈŋɤ̞ə̯ wuˈjiː.pʼɨ ˈpʰä ˈtʼsən",
+            ),
+        ];
+        for &(texts, early, late) in cases {
+            let phrase = phrase(texts);
+            assert_eq!(phrase.to_early_narrow_ipa(), early, "{:?}", texts);
+            assert_eq!(phrase.to_late_narrow_ipa(), late, "{:?}", texts);
+        }
+    }
+
+    #[test]
+    fn coda_moves_to_next_word() {
+        let phrase = phrase(&["kas", "eta"]);
+        let first_word = Word::new(phrase.words()[0].clone()).unwrap();
+        assert_eq!(first_word.to_text(), "ka");
+        assert_eq!(phrase.to_early_narrow_ipa(), "ˈqʰɑ ˈsə.tʰä");
+    }
+
+    #[test]
+    fn allophony_crosses_word_boundaries() {
+        let palatalized = phrase(&["kas", "jí"]);
+        assert_eq!(palatalized.to_early_narrow_ipa(), "ˈqʰɑs ˈcʼiː");
+        assert_eq!(palatalized.to_late_narrow_ipa(), "ˈqʰɑɕ ˈcʼiː");
+        let retracted = phrase(&["saŋ", "a"]);
+        assert_eq!(retracted.to_early_narrow_ipa(), "ˈsä ˈɴɑ");
+    }
+}
@@ -0,0 +1,203 @@
+use super::{phrase::Phrase, Syllable, Word};
+use std::fmt;
+
+/// Weight of a syllable. A syllable is heavy if its nucleus is long or if it
+/// has a coda.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weight {
+    Light,
+    Heavy,
+}
+
+impl fmt::Display for Weight {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Weight::Light => "light",
+            Weight::Heavy => "heavy",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Stress {
+    Unstressed,
+    Secondary,
+    Primary,
+}
+
+impl Stress {
+    /// Mark written before the syllable, which also works as the syllable
+    /// boundary. Unstressed syllables are separated by `.`.
+    pub fn mark(self) -> &'static str {
+        match self {
+            Stress::Unstressed => ".",
+            Stress::Secondary => "ˌ",
+            Stress::Primary => "ˈ",
+        }
+    }
+
+    /// Mark written before the first syllable of a word, empty if it is
+    /// unstressed.
+    pub fn initial_mark(self) -> &'static str {
+        match self {
+            Stress::Unstressed => "",
+            _ => self.mark(),
+        }
+    }
+}
+
+impl fmt::Display for Stress {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Stress::Unstressed => "unstressed",
+            Stress::Secondary => "secondary",
+            Stress::Primary => "primary",
+        })
+    }
+}
+
+/// Assigns stress to the syllables of a word. The primary stress falls on
+/// the first syllable, unless it is light and the second is heavy, in which
+/// case the second one is stressed. Secondary stress falls on every other
+/// syllable after the primary, except on the last syllable of the word.
+pub fn stress(syllables: &[Syllable]) -> Vec<Stress> {
+    let mut stresses = vec![Stress::Unstressed; syllables.len()];
+    let primary = match syllables {
+        [first, second, ..]
+            if first.weight() == Weight::Light
+                && second.weight() == Weight::Heavy =>
+        {
+            1
+        },
+        _ => 0,
+    };
+
+    if let Some(stress) = stresses.get_mut(primary) {
+        *stress = Stress::Primary;
+    }
+    let last = syllables.len().saturating_sub(1);
+    for i in (primary + 2 .. last).step_by(2) {
+        stresses[i] = Stress::Secondary;
+    }
+
+    stresses
+}
+
+/// Phrase-level pitch contour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Intonation {
+    /// Global fall at the end of the phrase.
+    Declarative,
+    /// Global rise at the end of the phrase.
+    Interrogative,
+    /// Upstep at the start of the phrase, then a global fall.
+    Imperative,
+}
+
+impl Intonation {
+    pub const ALL: &'static [Self] = &[
+        Intonation::Declarative,
+        Intonation::Interrogative,
+        Intonation::Imperative,
+    ];
+
+    /// Mark written before the phrase, if any.
+    pub fn initial_mark(self) -> &'static str {
+        match self {
+            Intonation::Imperative => "ꜛ",
+            _ => "",
+        }
+    }
+
+    /// Mark written after the phrase.
+    pub fn final_mark(self) -> &'static str {
+        match self {
+            Intonation::Interrogative => "↗",
+            _ => "↘",
+        }
+    }
+}
+
+impl fmt::Display for Intonation {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(match self {
+            Intonation::Declarative => "declarative",
+            Intonation::Interrogative => "interrogative",
+            Intonation::Imperative => "imperative",
+        })
+    }
+}
+
+/// A phrase with its intonation, e.g. the line of an interlinear example.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Utterance {
+    pub words: Vec<Word>,
+    pub intonation: Intonation,
+}
+
+impl Utterance {
+    pub fn to_broad_ipa(&self) -> String {
+        let words = self.words.iter().map(Word::to_broad_ipa);
+        self.with_intonation(words.collect::<Vec<_>>().join(" "))
+    }
+
+    /// Narrow transcription of the early accent, with connected-speech
+    /// sandhi applied across the words.
+    pub fn to_early_narrow_ipa(&self) -> String {
+        let phrase = Phrase::new(&self.words);
+        self.with_intonation(phrase.to_early_narrow_ipa())
+    }
+
+    /// Narrow transcription of the late accent, with connected-speech sandhi
+    /// applied across the words.
+    pub fn to_late_narrow_ipa(&self) -> String {
+        let phrase = Phrase::new(&self.words);
+        self.with_intonation(phrase.to_late_narrow_ipa())
+    }
+
+    fn with_intonation(&self, ipa: String) -> String {
+        format!(
+            "{}{}{}",
+            self.intonation.initial_mark(),
+            ipa,
+            self.intonation.final_mark(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::phonology::Parse;
+
+    fn word(text: &str) -> Word {
+        Word::parse_str(text).unwrap()
+    }
+
+    #[test]
+    fn weight_sensitive_stress() {
+        use Stress::*;
+
+        assert_eq!(word("saŋ").stresses(), [Primary]);
+        assert_eq!(word("maci").stresses(), [Primary, Unstressed]);
+        assert_eq!(word("mací").stresses(), [Unstressed, Primary]);
+        assert_eq!(
+            word("wiyaka").stresses(),
+            [Primary, Unstressed, Unstressed]
+        );
+        assert_eq!(
+            word("wiyakapa").stresses(),
+            [Primary, Unstressed, Secondary, Unstressed]
+        );
+        assert_eq!(word("mací").to_broad_ipa(), "maˈcʰiː");
+    }
+
+    #[test]
+    fn intonation_marks() {
+        let utterance = Utterance {
+            words: vec![word("saŋ"), word("wiya")],
+            intonation: Intonation::Interrogative,
+        };
+        assert_eq!(utterance.to_broad_ipa(), "ˈsaŋ ˈwi.ja↗");
+    }
+}
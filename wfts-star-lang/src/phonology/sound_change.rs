@@ -0,0 +1,278 @@
+use super::{Phoneme, Word};
+use std::{iter, sync::OnceLock};
+use wfts_lang::sound_change::{
+    Derivation,
+    Inventory,
+    Segment,
+    SoundChanges,
+    Stage,
+};
+
+pub const EARLY: &str = "Early CSL";
+pub const SPREADING: &str = "Late CSL palatal spreading";
+pub const LATE: &str = "Late CSL";
+
+/// Marks not handled by the rules.
+pub const SUPRASEGMENTALS: &[char] = &['ˈ', 'ˌ', '.'];
+
+/// Allophony of the early accent, applied to the broad transcription.
+const EARLY_RULES: &[&str] = &[
+    "{iː, i, eː, e, a, aː} > {ɨː, ɨ, əː, ə, ä, äː}",
+    "{ɨː, ɨ, əː, ə, ä, äː} > {iː, i, e̞ː, e̞, æ, æː} / {[+palatal], \
+     [+pharyngeal]}_",
+    "{ɨː, ɨ, əː, ə, ä, äː} > {ɯə̯, ɯ, ɤ̞ə̯, ɤ̞, ɑ, ɑː} / [+velar -round]_",
+    "{ɨː, ɨ, əː, ə, ä, äː} > {uː, u, o̞ː, o̞, ɒ, ɒɔ̯} / [+velar +round]_",
+    "{kʷʼ, kʼ, kʷʰ, kʰ, ŋʷ, ŋ, xʷ, w, x} > {qʷʼ, qʼ, qʷʰ, qʰ, ɴ͡mʷ, ɴ, χʷ, w̠, \
+     χ} / _[+low]",
+    "ŋʷ > ŋ͡mʷ",
+];
+
+/// Spreading of palatalization in the late accent. Palatal consonants
+/// palatalize following fricatives and vowels, except at the start of the
+/// word, and fricatives are also palatalized before palatalized sounds.
+const SPREADING_RULES: &[&str] = &[
+    "[+palatal] > [+palatalized] / []_",
+    "{s, x, χ, xʷ, χʷ} > [+palatalized] / [+palatalized]_",
+    "[+vowel] > [+palatalized] / [-vowel +palatalized]_",
+    "{s, x, χ, xʷ, χʷ} > [+palatalized] / _[+palatalized] (rtl)",
+];
+
+/// Realization of palatalized sounds in the late accent.
+const LATE_RULES: &[&str] = &[
+    "[+palatalized +fricative +coronal] > ɕ",
+    "[+palatalized +fricative +dorsal -round] > ç",
+    "[+palatalized +fricative +dorsal +round] > çʷ",
+    "[+palatalized +high +long] > iː",
+    "[+palatalized +high -long] > i",
+    "[+palatalized +mid +long] > e̞ː",
+    "[+palatalized +mid -long] > e̞",
+    "[+palatalized +low +long] > æː",
+    "[+palatalized +low -long] > æ",
+];
+
+fn inventory() -> Inventory {
+    let mut inventory = Inventory::new();
+
+    let consonants: &[(&str, &[&str])] = &[
+        ("pʼ", &["stop", "ejective", "labial"]),
+        ("kʷʼ", &["stop", "ejective", "velar", "dorsal", "round"]),
+        ("qʷʼ", &["stop", "ejective", "uvular", "dorsal", "round"]),
+        ("tʼ", &["stop", "ejective", "coronal"]),
+        ("cʼ", &["stop", "ejective", "palatal"]),
+        ("kʼ", &["stop", "ejective", "velar", "dorsal"]),
+        ("qʼ", &["stop", "ejective", "uvular", "dorsal"]),
+        ("pʰ", &["stop", "aspirated", "labial"]),
+        ("kʷʰ", &["stop", "aspirated", "velar", "dorsal", "round"]),
+        ("qʷʰ", &["stop", "aspirated", "uvular", "dorsal", "round"]),
+        ("tʰ", &["stop", "aspirated", "coronal"]),
+        ("cʰ", &["stop", "aspirated", "palatal"]),
+        ("kʰ", &["stop", "aspirated", "velar", "dorsal"]),
+        ("qʰ", &["stop", "aspirated", "uvular", "dorsal"]),
+        ("m", &["nasal", "labial"]),
+        ("ŋʷ", &["nasal", "velar", "dorsal", "round"]),
+        ("ŋ͡mʷ", &["nasal", "labial", "velar", "dorsal", "round"]),
+        ("ɴ͡mʷ", &["nasal", "labial", "uvular", "dorsal", "round"]),
+        ("n", &["nasal", "coronal"]),
+        ("ɲ", &["nasal", "palatal"]),
+        ("ŋ", &["nasal", "velar", "dorsal"]),
+        ("ɴ", &["nasal", "uvular", "dorsal"]),
+        ("ɸ", &["fricative", "labial"]),
+        ("xʷ", &["fricative", "velar", "dorsal", "round"]),
+        ("χʷ", &["fricative", "uvular", "dorsal", "round"]),
+        ("çʷ", &["fricative", "palatal", "round"]),
+        ("s", &["fricative", "coronal"]),
+        ("ɕ", &["fricative", "coronal", "palatal"]),
+        ("x", &["fricative", "velar", "dorsal"]),
+        ("χ", &["fricative", "uvular", "dorsal"]),
+        ("ç", &["fricative", "palatal"]),
+        ("ħ", &["fricative", "pharyngeal"]),
+        ("w", &["approximant", "labial", "velar", "dorsal", "round"]),
+        ("w̠", &["approximant", "labial", "uvular", "dorsal", "round"]),
+        ("ɹ", &["approximant", "coronal"]),
+        ("j", &["approximant", "palatal"]),
+        ("ʕ", &["approximant", "pharyngeal"]),
+    ];
+
+    let vowels: &[(&str, &[&str])] = &[
+        ("iː", &["high", "front", "long"]),
+        ("i", &["high", "front"]),
+        ("ɨː", &["high", "central", "long"]),
+        ("ɨ", &["high", "central"]),
+        ("ɯə̯", &["high", "back", "long"]),
+        ("ɯ", &["high", "back"]),
+        ("uː", &["high", "back", "round", "long"]),
+        ("u", &["high", "back", "round"]),
+        ("eː", &["mid", "front", "long"]),
+        ("e", &["mid", "front"]),
+        ("e̞ː", &["mid", "front", "long"]),
+        ("e̞", &["mid", "front"]),
+        ("əː", &["mid", "central", "long"]),
+        ("ə", &["mid", "central"]),
+        ("ɤ̞ə̯", &["mid", "back", "long"]),
+        ("ɤ̞", &["mid", "back"]),
+        ("o̞ː", &["mid", "back", "round", "long"]),
+        ("o̞", &["mid", "back", "round"]),
+        ("aː", &["low", "central", "long"]),
+        ("a", &["low", "central"]),
+        ("æː", &["low", "front", "long"]),
+        ("æ", &["low", "front"]),
+        ("äː", &["low", "central", "long"]),
+        ("ä", &["low", "central"]),
+        ("ɑː", &["low", "back", "long"]),
+        ("ɑ", &["low", "back"]),
+        ("ɒɔ̯", &["low", "back", "round", "long"]),
+        ("ɒ", &["low", "back", "round"]),
+    ];
+
+    for &(symbol, features) in consonants {
+        let mut segment = Segment::new(symbol, features);
+        segment.features.insert("consonant".to_owned());
+        inventory.add(segment);
+    }
+    for &(symbol, features) in vowels {
+        let mut segment = Segment::new(symbol, features);
+        segment.features.insert("vowel".to_owned());
+        inventory.add(segment);
+    }
+
+    inventory.add_unwritten_feature("palatalized");
+    inventory
+}
+
+/// Sound changes from the phonemic form to the early and then the late
+/// accent of the Classical Star Language.
+pub fn sound_changes() -> &'static SoundChanges {
+    static CHANGES: OnceLock<SoundChanges> = OnceLock::new();
+
+    CHANGES.get_or_init(|| {
+        let inventory = inventory();
+        let stages = vec![
+            Stage::parse(EARLY, false, EARLY_RULES.iter().copied(), &inventory),
+            Stage::parse(
+                SPREADING,
+                true,
+                SPREADING_RULES.iter().copied(),
+                &inventory,
+            ),
+            Stage::parse(LATE, false, LATE_RULES.iter().copied(), &inventory),
+        ];
+        let stages = stages.into_iter().collect::<anyhow::Result<_>>().unwrap();
+        SoundChanges { inventory, stages }
+    })
+}
+
+/// Derivation of the early and late pronunciations of a sequence of
+/// phonemes, one segment per phoneme.
+pub fn derive<I>(phonemes: I) -> Derivation
+where
+    I: IntoIterator<Item = Phoneme>,
+{
+    let changes = sound_changes();
+    let input = phonemes
+        .into_iter()
+        .map(|phoneme| {
+            changes.inventory.get(phoneme.to_broad_ipa()).unwrap().clone()
+        })
+        .collect();
+    changes.derive(input)
+}
+
+/// Realization of a phoneme between the given neighbours in the early
+/// accent or, if `palatalized` is set, in the late accent with the palatal
+/// spreading reaching it, whatever its neighbours are.
+pub fn realize(
+    phoneme: Phoneme,
+    prev: Option<Phoneme>,
+    next: Option<Phoneme>,
+    palatalized: bool,
+) -> String {
+    let changes = sound_changes();
+    let index = usize::from(prev.is_some());
+    let context = prev.into_iter().chain(iter::once(phoneme)).chain(next);
+    let derivation = derive(context);
+    let mut form = derivation
+        .after_stage(EARLY)
+        .unwrap_or(&derivation.input)
+        .to_vec();
+
+    if palatalized {
+        form[index].features.insert("palatalized".to_owned());
+        let late = changes.stages.iter().filter(|stage| stage.name == LATE);
+        for rule in late.flat_map(|stage| &stage.rules) {
+            rule.apply(&mut form, &changes.inventory);
+        }
+    }
+
+    form[index].symbol.clone()
+}
+
+impl Word {
+    /// Derivation of the early and late pronunciations from the broad
+    /// transcription.
+    pub fn derivation(&self) -> Derivation {
+        derive(self.phonemes())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::phonology::{phrase::Phrase, Parse};
+
+    fn phrase(text: &str) -> Phrase {
+        let words = text
+            .split(' ')
+            .map(|word| Word::parse_str(word).unwrap())
+            .collect::<Vec<_>>();
+        Phrase::new(&words)
+    }
+
+    #[test]
+    fn derivations() {
+        let cases = [
+            ("éyxám", "ˈəːj.χɑːm", "ˈəːj.çæːm"),
+            ("kasepe", "ˈqʰɑ.sə.pʰə", "ˈqʰɑ.sə.pʰə"),
+            ("wiyake", "ˈwu.jæ.kʰɤ̞", "ˈwu.jæ.kʰɤ̞"),
+            ("xím", "ˈxɯə̯m", "ˈxɯə̯m"),
+            ("ḱem", "ˈkʷʰo̞m", "ˈkʷʰo̞m"),
+            ("etiŕ", "əˈtʰɨʕ", "əˈtʰɨʕ"),
+            ("r", "ˈɹ", "ˈɹ"),
+            (
+                "fwi eta kef wiyíbi pa saysé",
+                "ˈɸwu ˈə.tʰä ˈkʰɤ̞ɸ wuˈjiː.pʼɨ ˈpʰä ˈsäj.səː",
+                "ˈɸwu ˈə.tʰä ˈkʰɤ̞ɸ wuˈjiː.pʼɨ ˈpʰä ˈsäj.ɕe̞ː",
+            ),
+            (
+                "kef jí wiyafa pa keys",
+                "ˈkʰɤ̞ɸ ˈcʼiː ˈwu.jæ.ɸä ˈpʰä ˈkʰɤ̞js",
+                "ˈkʰɤ̞ɸ ˈcʼiː ˈwu.jæ.ɸä ˈpʰä ˈkʰɤ̞jɕ",
+            ),
+            (
+                "nawa fwi nawa kef",
+                "ˈnä.w̠ɒɸ ˈwu ˈnä.w̠ɒ ˈkʰɤ̞ɸ",
+                "ˈnä.w̠ɒɸ ˈwu ˈnä.w̠ɒ ˈkʰɤ̞ɸ",
+            ),
+        ];
+        for (text, early, late) in cases {
+            let phrase = phrase(text);
+            assert_eq!(phrase.to_early_narrow_ipa(), early, "{}", text);
+            assert_eq!(phrase.to_late_narrow_ipa(), late, "{}", text);
+        }
+    }
+
+    #[test]
+    fn realizations() {
+        use Phoneme::*;
+
+        assert_eq!(realize(K, None, None, false), "kʰ");
+        assert_eq!(realize(K, None, Some(A), false), "qʰ");
+        assert_eq!(realize(Mg, None, None, false), "ŋ͡mʷ");
+        assert_eq!(realize(A, Some(Y), None, false), "æ");
+        assert_eq!(realize(A, Some(W), None, false), "ɒ");
+        assert_eq!(realize(Aa, Some(W), None, false), "ɒɔ̯");
+        assert_eq!(realize(A, Some(T), None, true), "æ");
+        assert_eq!(realize(S, None, None, true), "ɕ");
+        assert_eq!(realize(Xw, None, None, true), "çʷ");
+        assert_eq!(realize(S, None, None, false), "s");
+    }
+}
@@ -0,0 +1,479 @@
+//! Formant synthesis of the narrow transcriptions. Each segment is turned
+//! into a few phases with formant, voicing and noise targets, and the
+//! parameters glide from one phase to the next, so transitions between
+//! consonants and vowels come out of the smoothing.
+
+use super::{
+    prosody::Stress,
+    sound_change::{sound_changes, SUPRASEGMENTALS},
+    Word,
+};
+use crate::audio::Accent;
+use std::{
+    f64::consts::PI,
+    io::{self, Write},
+};
+use wfts_lang::{random::Rng, sound_change::Segment};
+
+pub const SAMPLE_RATE: u32 = 16000;
+
+/// Bandwidths of the three formants, in hertz.
+const BANDWIDTHS: [f64; 3] = [80.0, 110.0, 160.0];
+/// Bandwidths of the formants of nasals, which are more damped.
+const NASAL_BANDWIDTHS: [f64; 3] = [160.0, 220.0, 280.0];
+
+/// Time constants of the gliding parameters, in seconds.
+const FORMANT_GLIDE: f64 = 0.012;
+const AMPLITUDE_GLIDE: f64 = 0.004;
+const PITCH_GLIDE: f64 = 0.03;
+/// Time constant of the low-pass filter giving the glottal pulses their
+/// spectral tilt.
+const TILT: f64 = 0.0004;
+
+/// Number of samples between updates of the resonator coefficients.
+const RETUNE_INTERVAL: usize = 32;
+
+/// Loudness of the fricative noise relative to the voiced sounds.
+const FRICATION_GAIN: f64 = 0.9;
+
+/// Pitch at the start and at the end of the clip, in hertz. The fall is the
+/// declarative intonation.
+const PITCH_START: f64 = 125.0;
+const PITCH_END: f64 = 95.0;
+
+/// Targets of one stretch of a clip.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Phase {
+    /// Duration in seconds.
+    duration: f64,
+    formants: [f64; 3],
+    bandwidths: [f64; 3],
+    /// Amplitude of the glottal source.
+    voicing: f64,
+    /// Amplitude of noise filtered by the formants, as in aspiration and
+    /// pharyngeal fricatives.
+    aspiration: f64,
+    /// Amplitude of noise filtered by the fricative resonator.
+    frication: f64,
+    /// Center frequency and bandwidth of the fricative resonator.
+    noise: (f64, f64),
+    /// Raise of the pitch over the declination line, in hertz.
+    accent: f64,
+}
+
+impl Phase {
+    fn silence(duration: f64, formants: [f64; 3]) -> Self {
+        Self {
+            duration,
+            formants,
+            bandwidths: BANDWIDTHS,
+            voicing: 0.0,
+            aspiration: 0.0,
+            frication: 0.0,
+            noise: (3000.0, 1000.0),
+            accent: 0.0,
+        }
+    }
+}
+
+/// Formants of a vowel, from its height, backness and rounding.
+fn vowel_formants(segment: &Segment) -> [f64; 3] {
+    let f1 = if segment.has("high") {
+        300.0
+    } else if segment.has("mid") {
+        480.0
+    } else {
+        720.0
+    };
+    let mut f2 = if segment.has("front") {
+        2200.0
+    } else if segment.has("central") {
+        1500.0
+    } else {
+        1100.0
+    };
+    let mut f3 = 2600.0;
+    if segment.has("round") {
+        f2 -= 300.0;
+        f3 -= 200.0;
+    }
+    [f1, f2, f3]
+}
+
+/// Formants at the place of articulation of a consonant, toward which the
+/// neighbouring vowels glide.
+fn locus(segment: &Segment) -> [f64; 3] {
+    let mut formants = if segment.has("pharyngeal") {
+        [800.0, 1150.0, 2500.0]
+    } else if segment.has("uvular") {
+        [550.0, 1000.0, 2400.0]
+    } else if segment.has("velar") {
+        [300.0, 1500.0, 2300.0]
+    } else if segment.has("palatal") {
+        [280.0, 2250.0, 2900.0]
+    } else if segment.has("coronal") {
+        [300.0, 1750.0, 2600.0]
+    } else {
+        [300.0, 900.0, 2300.0]
+    };
+    if segment.has("round") {
+        formants[1] -= 300.0;
+    }
+    if segment.symbol == "ɹ" {
+        formants[2] = 1700.0;
+    }
+    formants
+}
+
+/// Center frequency and bandwidth of the noise of a fricative or burst.
+fn noise(segment: &Segment) -> (f64, f64) {
+    let (center, bandwidth) = if segment.has("pharyngeal") {
+        (1100.0, 800.0)
+    } else if segment.has("uvular") {
+        (1200.0, 600.0)
+    } else if segment.has("velar") {
+        (1800.0, 800.0)
+    } else if segment.has("coronal") && segment.has("palatal") {
+        (3800.0, 1500.0)
+    } else if segment.has("palatal") {
+        (3300.0, 1000.0)
+    } else if segment.has("coronal") {
+        (5500.0, 1500.0)
+    } else {
+        (1500.0, 3000.0)
+    };
+    if segment.has("round") {
+        (center * 0.85, bandwidth)
+    } else {
+        (center, bandwidth)
+    }
+}
+
+/// Pitch accent of a stressed vowel.
+fn accent(stress: Stress) -> f64 {
+    match stress {
+        Stress::Primary => 25.0,
+        Stress::Secondary => 8.0,
+        Stress::Unstressed => 0.0,
+    }
+}
+
+/// Splits a narrow transcription into segments, each with the stress of its
+/// syllable.
+fn segments(ipa: &str) -> anyhow::Result<Vec<(Stress, Segment)>> {
+    let inventory = &sound_changes().inventory;
+    let mut segments = Vec::new();
+    let mut syllable = String::new();
+    let mut stress = Stress::Unstressed;
+
+    for ch in ipa.chars().chain(Some(' ')) {
+        let next = match ch {
+            'ˈ' => Stress::Primary,
+            'ˌ' => Stress::Secondary,
+            ch if ch == ' ' || SUPRASEGMENTALS.contains(&ch) => {
+                Stress::Unstressed
+            },
+            _ => {
+                syllable.push(ch);
+                continue;
+            },
+        };
+        for segment in inventory.tokenize(&syllable, SUPRASEGMENTALS)? {
+            segments.push((stress, segment));
+        }
+        syllable.clear();
+        stress = next;
+    }
+
+    Ok(segments)
+}
+
+/// Phases of the segments. Stops are a closure and a burst, followed by
+/// aspiration if aspirated or by a glottal hold if ejective.
+fn phases(segments: &[(Stress, Segment)]) -> Vec<Phase> {
+    let mut phases = Vec::new();
+
+    for (i, (stress, segment)) in segments.iter().enumerate() {
+        let next = segments.get(i + 1).map(|(_, next)| next);
+        let next_formants = match next {
+            Some(next) if next.has("vowel") => vowel_formants(next),
+            Some(next) => locus(next),
+            None => locus(segment),
+        };
+
+        if segment.has("vowel") {
+            let mut duration = 0.11;
+            if segment.has("long") {
+                duration *= 1.8;
+            }
+            if *stress == Stress::Primary {
+                duration *= 1.3;
+            }
+            phases.push(Phase {
+                duration,
+                voicing: 1.0,
+                accent: accent(*stress),
+                ..Phase::silence(duration, vowel_formants(segment))
+            });
+        } else if segment.has("stop") {
+            let formants = locus(segment);
+            phases.push(Phase::silence(0.07, formants));
+            let ejective = segment.has("ejective");
+            phases.push(Phase {
+                frication: if ejective { 1.6 } else { 1.0 },
+                noise: noise(segment),
+                ..Phase::silence(0.01, formants)
+            });
+            if ejective {
+                phases.push(Phase::silence(0.045, next_formants));
+            } else {
+                phases.push(Phase {
+                    aspiration: 0.5,
+                    ..Phase::silence(0.05, next_formants)
+                });
+            }
+        } else if segment.has("nasal") {
+            phases.push(Phase {
+                formants: [250.0, locus(segment)[1], 2500.0],
+                bandwidths: NASAL_BANDWIDTHS,
+                voicing: 0.5,
+                ..Phase::silence(0.07, locus(segment))
+            });
+        } else if segment.has("pharyngeal") && segment.has("fricative") {
+            phases.push(Phase {
+                aspiration: 0.15,
+                ..Phase::silence(0.1, locus(segment))
+            });
+        } else if segment.has("fricative") {
+            let frication = if segment.has("labial") { 0.3 } else { 0.8 };
+            phases.push(Phase {
+                frication,
+                noise: noise(segment),
+                ..Phase::silence(0.1, locus(segment))
+            });
+        } else {
+            phases.push(Phase {
+                voicing: 0.7,
+                ..Phase::silence(0.06, locus(segment))
+            });
+        }
+    }
+
+    // Lets the voice fade out instead of stopping with a click.
+    if let Some(last) = phases.last() {
+        phases.push(Phase::silence(0.03, last.formants));
+    }
+
+    phases
+}
+
+/// Second order resonator, as in Klatt's synthesizer.
+#[derive(Debug, Clone, Copy, Default)]
+struct Resonator {
+    a: f64,
+    b: f64,
+    c: f64,
+    last: f64,
+    before_last: f64,
+}
+
+impl Resonator {
+    fn tune(&mut self, frequency: f64, bandwidth: f64) {
+        let period = 1.0 / f64::from(SAMPLE_RATE);
+        self.c = -(-2.0 * PI * bandwidth * period).exp();
+        self.b = 2.0
+            * (-PI * bandwidth * period).exp()
+            * (2.0 * PI * frequency * period).cos();
+        self.a = 1.0 - self.b - self.c;
+    }
+
+    /// Gain at the given frequency, used to give the fricatives the same
+    /// loudness wherever their noise is centered.
+    fn gain(&self, frequency: f64) -> f64 {
+        let omega = 2.0 * PI * frequency / f64::from(SAMPLE_RATE);
+        let real = 1.0 - self.b * omega.cos() - self.c * (2.0 * omega).cos();
+        let imaginary = self.b * omega.sin() + self.c * (2.0 * omega).sin();
+        self.a.abs() / real.hypot(imaginary)
+    }
+
+    fn filter(&mut self, input: f64) -> f64 {
+        let output =
+            self.a * input + self.b * self.last + self.c * self.before_last;
+        self.before_last = self.last;
+        self.last = output;
+        output
+    }
+}
+
+/// Fraction of the distance to the target covered in each sample by a
+/// parameter gliding with the given time constant.
+fn glide_rate(time: f64) -> f64 {
+    1.0 - (-1.0 / (time * f64::from(SAMPLE_RATE))).exp()
+}
+
+fn glide(value: &mut f64, target: f64, rate: f64) {
+    *value += (target - *value) * rate;
+}
+
+fn render(phases: &[Phase]) -> Vec<i16> {
+    let total = phases.iter().map(|phase| phase.duration).sum::<f64>();
+    let formant_rate = glide_rate(FORMANT_GLIDE);
+    let amplitude_rate = glide_rate(AMPLITUDE_GLIDE);
+    let pitch_rate = glide_rate(PITCH_GLIDE);
+    let tilt_rate = glide_rate(TILT);
+    let mut rng = Rng::new(0x5354_4152);
+    let mut output = Vec::<f64>::new();
+
+    let mut current = match phases.first() {
+        Some(&first) => Phase { voicing: 0.0, ..first },
+        None => return Vec::new(),
+    };
+    let mut pitch = PITCH_START;
+    let mut glottis = 0.0;
+    let mut tilt = 0.0;
+    let mut cascade = [Resonator::default(); 3];
+    let mut fricative = Resonator::default();
+    let mut fricative_gain = 1.0;
+
+    for target in phases {
+        let samples = (target.duration * f64::from(SAMPLE_RATE)) as usize;
+        for _ in 0 .. samples {
+            if output.len().is_multiple_of(RETUNE_INTERVAL) {
+                for (i, resonator) in cascade.iter_mut().enumerate() {
+                    resonator.tune(current.formants[i], current.bandwidths[i]);
+                }
+                let (center, bandwidth) = current.noise;
+                fricative.tune(center, bandwidth);
+                fricative_gain = fricative.gain(center);
+            }
+
+            for i in 0 .. 3 {
+                glide(
+                    &mut current.formants[i],
+                    target.formants[i],
+                    formant_rate,
+                );
+                glide(
+                    &mut current.bandwidths[i],
+                    target.bandwidths[i],
+                    formant_rate,
+                );
+            }
+            glide(&mut current.voicing, target.voicing, amplitude_rate);
+            glide(&mut current.aspiration, target.aspiration, amplitude_rate);
+            glide(&mut current.frication, target.frication, amplitude_rate);
+            current.noise = target.noise;
+            let elapsed = output.len() as f64 / f64::from(SAMPLE_RATE);
+            let declination =
+                PITCH_START + (PITCH_END - PITCH_START) * elapsed / total;
+            glide(&mut pitch, declination + target.accent, pitch_rate);
+
+            // Sawtooth pulses with a spectral tilt.
+            glottis = (glottis + pitch / f64::from(SAMPLE_RATE)).fract();
+            glide(&mut tilt, 1.0 - 2.0 * glottis, tilt_rate);
+            let noise = (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+            let noise = 2.0 * noise - 1.0;
+
+            let mut voiced =
+                tilt * current.voicing + noise * current.aspiration;
+            for resonator in &mut cascade {
+                voiced = resonator.filter(voiced);
+            }
+            let fricated = fricative.filter(noise) / fricative_gain
+                * current.frication
+                * FRICATION_GAIN;
+            output.push(voiced + fricated);
+        }
+    }
+
+    let peak = output.iter().fold(0.0, |peak: f64, &sample| {
+        peak.max(sample.abs())
+    });
+    let scale = if peak > 0.0 { 0.8 / peak } else { 0.0 };
+    output
+        .iter()
+        .map(|sample| (sample * scale * f64::from(i16::MAX)) as i16)
+        .collect()
+}
+
+/// Synthesizes a narrow transcription as 16-bit mono samples at
+/// `SAMPLE_RATE`.
+pub fn synthesize(ipa: &str) -> anyhow::Result<Vec<i16>> {
+    Ok(render(&phases(&segments(ipa)?)))
+}
+
+/// Writes the samples as a WAV file.
+pub fn write_wav<W>(samples: &[i16], mut output: W) -> io::Result<()>
+where
+    W: Write,
+{
+    let data_size = samples.len() as u32 * 2;
+    output.write_all(b"RIFF")?;
+    output.write_all(&(36 + data_size).to_le_bytes())?;
+    output.write_all(b"WAVEfmt ")?;
+    output.write_all(&16u32.to_le_bytes())?;
+    // PCM, one channel.
+    output.write_all(&1u16.to_le_bytes())?;
+    output.write_all(&1u16.to_le_bytes())?;
+    output.write_all(&SAMPLE_RATE.to_le_bytes())?;
+    output.write_all(&(SAMPLE_RATE * 2).to_le_bytes())?;
+    output.write_all(&2u16.to_le_bytes())?;
+    output.write_all(&16u16.to_le_bytes())?;
+    output.write_all(b"data")?;
+    output.write_all(&data_size.to_le_bytes())?;
+    for sample in samples {
+        output.write_all(&sample.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+impl Word {
+    /// Synthesized pronunciation in the given accent.
+    pub fn synthesize(&self, accent: Accent) -> anyhow::Result<Vec<i16>> {
+        match accent {
+            Accent::Early => synthesize(&self.to_early_narrow_ipa()),
+            Accent::Late => synthesize(&self.to_late_narrow_ipa()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::phonology::Parse;
+
+    fn clip(text: &str) -> Vec<i16> {
+        Word::parse_str(text).unwrap().synthesize(Accent::Early).unwrap()
+    }
+
+    #[test]
+    fn long_vowels_are_longer() {
+        assert!(clip("pá").len() > clip("pa").len());
+    }
+
+    #[test]
+    fn ejectives_hold_the_glottis() {
+        // The ejective is followed by silence, the aspirate by noise. The
+        // window starts a little after the burst, which takes a few
+        // milliseconds to die out.
+        let release = (0.09 * f64::from(SAMPLE_RATE)) as usize;
+        let hold = (0.025 * f64::from(SAMPLE_RATE)) as usize;
+        let energy = |samples: &[i16]| {
+            samples[release .. release + hold]
+                .iter()
+                .map(|&sample| i64::from(sample).abs())
+                .sum::<i64>()
+        };
+        assert!(energy(&clip("ba")) * 5 < energy(&clip("pa")));
+    }
+
+    #[test]
+    fn writes_wav_header() {
+        let samples = clip("saŋ");
+        let mut wav = Vec::new();
+        write_wav(&samples, &mut wav).unwrap();
+        assert_eq!(&wav[.. 4], b"RIFF");
+        assert_eq!(&wav[8 .. 16], b"WAVEfmt ");
+        assert_eq!(wav.len(), 44 + samples.len() * 2);
+    }
+}
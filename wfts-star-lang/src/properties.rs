@@ -0,0 +1,144 @@
+//! Property tests over randomly generated valid words.
+
+use crate::{
+    audio::Accent,
+    generator::WordGenerator,
+    grammar::Paradigm,
+    morphology::{Morpheme, Template},
+    phonology::{Parse, Word},
+    romanization::Scheme,
+};
+use std::{cmp::Ordering, panic};
+use wfts_lang::random::Rng;
+
+const SEED: u64 = 0x5354_4152;
+const SAMPLES: usize = 2000;
+
+fn words() -> Vec<Word> {
+    let generator = WordGenerator::default();
+    let mut rng = Rng::new(SEED);
+    let mut words = Vec::new();
+    while words.len() < SAMPLES {
+        words.extend(generator.word(&mut rng));
+    }
+    words
+}
+
+#[test]
+fn parse_inverts_to_text() {
+    for word in words() {
+        let text = word.to_text();
+        let parsed = Word::parse_str(&text).unwrap();
+        assert_eq!(parsed, word, "{}", text);
+    }
+}
+
+#[test]
+fn romanizations_round_trip() {
+    for word in words() {
+        for &scheme in Scheme::ALL {
+            let text = scheme.render(word.phonemes());
+            let parsed = Word::parse(&scheme.parse(&text).unwrap()).unwrap();
+            assert_eq!(parsed, word, "{} {}", scheme, text);
+        }
+    }
+}
+
+#[test]
+fn every_word_can_be_synthesized() {
+    // Synthesis is slow in debug builds, so only some words are tried.
+    for word in words().iter().step_by(20) {
+        for &accent in Accent::ALL {
+            let samples = word.synthesize(accent).unwrap();
+            assert!(!samples.is_empty(), "{} {}", word, accent);
+        }
+    }
+}
+
+fn assert_ord_agrees_with_eq(left: &Word, right: &Word) {
+    assert_eq!(
+        left.cmp(right) == Ordering::Equal,
+        left == right,
+        "{} {}",
+        left,
+        right
+    );
+    assert_eq!(
+        left.cmp(right),
+        right.cmp(left).reverse(),
+        "{} {}",
+        left,
+        right
+    );
+}
+
+#[test]
+fn ord_agrees_with_eq() {
+    let words = words();
+    let sample = &words[.. 200];
+    for left in sample {
+        for right in sample {
+            assert_ord_agrees_with_eq(left, right);
+        }
+    }
+
+    // Neighbours after sorting are the pairs most likely to compare equal.
+    let mut sorted = words;
+    sorted.sort();
+    for pair in sorted.windows(2) {
+        assert_ord_agrees_with_eq(&pair[0], &pair[1]);
+    }
+}
+
+#[test]
+fn inflections_do_not_panic() {
+    for word in words() {
+        for &paradigm in Paradigm::ALL {
+            if paradigm.check(word.clone()).is_err() {
+                continue;
+            }
+            let result = panic::catch_unwind(|| {
+                paradigm.inflections(word.clone()).unwrap()
+            });
+            let inflections = result.unwrap_or_else(|_| {
+                panic!("{} of {} panicked", paradigm, word)
+            });
+            for (grammemes, morpheme) in inflections {
+                if let Morpheme::Word(inflected) = morpheme {
+                    let syllables = inflected.syllables().to_vec();
+                    assert!(
+                        Word::new(syllables).is_ok(),
+                        "{} {} of {}",
+                        paradigm,
+                        grammemes,
+                        word
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn filled_template_agrees_with_parse() {
+    let mut rng = Rng::new(SEED);
+    for word in words() {
+        let phonemes = word.phonemes().collect::<Vec<_>>();
+        let start = rng.below(phonemes.len() as u64 + 1) as usize;
+        let end = start
+            + rng.below((phonemes.len() - start) as u64 + 1) as usize;
+
+        let mut outside = phonemes[.. start].to_vec();
+        outside.extend_from_slice(&phonemes[end ..]);
+        let mut template = Template::new(outside, vec![start]).unwrap();
+        template.fill(start, &phonemes[start .. end]).unwrap();
+
+        assert_eq!(template.phonemes(), &phonemes[..], "{}", word);
+        assert_eq!(
+            template.into_word().ok(),
+            Word::parse(&phonemes).ok(),
+            "{}",
+            word
+        );
+    }
+}
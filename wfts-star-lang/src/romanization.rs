@@ -0,0 +1,276 @@
+use crate::phonology::{Parse, Phoneme};
+use std::fmt;
+use thiserror::Error;
+
+/// Written between two phonemes whose romanizations would otherwise read as
+/// a different sequence of phonemes, e.g. `n'y` (/nj/) versus `ny` (/ɲ/).
+pub const SEPARATOR: char = '\'';
+
+#[derive(Debug, Clone, Error)]
+#[error("Unknown romanization scheme {name:?}")]
+pub struct UnknownScheme {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Invalid {scheme} romanization {text:?} at byte {position}")]
+pub struct InvalidRomanization {
+    pub scheme: Scheme,
+    pub text: String,
+    pub position: usize,
+}
+
+/// A way of writing the Star Language in the Latin script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Scheme {
+    /// The standard romanization, one letter per phoneme, using diacritics
+    /// (ǵ ḱ ḿ ń ŋ ẋ í é ŕ á). This is what `Phoneme::to_text` produces.
+    #[default]
+    Diacritic,
+    /// ASCII letters only, with digraphs in place of letters with diacritics.
+    Ascii,
+    /// Based on the X-SAMPA rendering of the broad IPA transcription.
+    XSampa,
+}
+
+impl Scheme {
+    pub const ALL: &'static [Self] =
+        &[Scheme::Diacritic, Scheme::Ascii, Scheme::XSampa];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Scheme::Diacritic => "diacritic",
+            Scheme::Ascii => "ascii",
+            Scheme::XSampa => "x-sampa",
+        }
+    }
+
+    pub fn from_name(name: &str) -> anyhow::Result<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|scheme| scheme.name() == name)
+            .ok_or_else(|| UnknownScheme { name: name.to_owned() }.into())
+    }
+
+    pub fn render_phoneme(self, phoneme: Phoneme) -> &'static str {
+        use Phoneme::*;
+
+        match self {
+            Scheme::Diacritic => phoneme.to_text(),
+            Scheme::Ascii => match phoneme {
+                Gw => "gw",
+                Kw => "kw",
+                Mg => "mw",
+                Nj => "ny",
+                Ng => "ng",
+                Xw => "xw",
+                Ii => "ii",
+                Ee => "ee",
+                Rr => "rr",
+                Aa => "aa",
+                _ => phoneme.to_text(),
+            },
+            Scheme::XSampa => match phoneme {
+                B => "p_>",
+                Gw => "k_w_>",
+                D => "t_>",
+                J => "c_>",
+                G => "k_>",
+                P => "p_h",
+                Kw => "k_w_h",
+                T => "t_h",
+                C => "c_h",
+                K => "k_h",
+                M => "m",
+                Mg => "N_w",
+                N => "n",
+                Nj => "J",
+                Ng => "N",
+                F => "p\\",
+                Xw => "x_w",
+                W => "w",
+                S => "s",
+                R => "r\\",
+                Y => "j",
+                Ii => "i:",
+                X => "x",
+                I => "i",
+                Ee => "e:",
+                H => "X\\",
+                E => "e",
+                Rr => "?\\",
+                A => "a",
+                Aa => "a:",
+            },
+        }
+    }
+
+    /// Renders the phonemes, inserting a separator wherever reading the
+    /// romanization back would give other phonemes.
+    pub fn render<I>(self, phonemes: I) -> String
+    where
+        I: IntoIterator<Item = Phoneme>,
+    {
+        let tokens = phonemes
+            .into_iter()
+            .map(|phoneme| self.render_phoneme(phoneme))
+            .collect::<Vec<_>>();
+        let mut output = String::new();
+
+        for (i, token) in tokens.iter().enumerate() {
+            output.push_str(token);
+            if i + 1 < tokens.len() {
+                let rest = tokens[i ..].concat();
+                let longest = self.longest_match(&rest).map(|(_, len)| len);
+                if longest != Some(token.len()) {
+                    output.push(SEPARATOR);
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Converts text in the standard romanization to this scheme. Characters
+    /// which are not phonemes, such as hyphens and spaces, are kept as they
+    /// are.
+    pub fn transliterate(self, text: &str) -> String {
+        let mut output = String::new();
+        let mut phonemes = Vec::new();
+
+        for ch in text.chars() {
+            match Phoneme::new(ch) {
+                Ok(phoneme) => phonemes.push(phoneme),
+                Err(_) => {
+                    output.push_str(&self.render(phonemes.drain(..)));
+                    output.push(ch);
+                },
+            }
+        }
+        output.push_str(&self.render(phonemes));
+
+        output
+    }
+
+    /// The phoneme whose romanization is the longest prefix of the text, and
+    /// the length of that romanization.
+    fn longest_match(self, text: &str) -> Option<(Phoneme, usize)> {
+        Phoneme::ALL
+            .iter()
+            .map(|&phoneme| (phoneme, self.render_phoneme(phoneme)))
+            .filter(|(_, token)| self.starts_with(text, token))
+            .max_by_key(|(_, token)| token.len())
+            .map(|(phoneme, token)| (phoneme, token.len()))
+    }
+
+    fn starts_with(self, text: &str, token: &str) -> bool {
+        match self {
+            Scheme::XSampa => text.starts_with(token),
+            _ => text
+                .chars()
+                .flat_map(char::to_lowercase)
+                .take(token.chars().count())
+                .eq(token.chars()),
+        }
+    }
+
+    /// Reads text written in this scheme, taking the longest romanization at
+    /// each position.
+    pub fn parse(self, text: &str) -> anyhow::Result<Vec<Phoneme>> {
+        let mut phonemes = Vec::new();
+        let mut position = 0;
+
+        while position < text.len() {
+            let rest = &text[position ..];
+            if rest.starts_with(SEPARATOR) {
+                position += SEPARATOR.len_utf8();
+                continue;
+            }
+            let (phoneme, _) = self.longest_match(rest).ok_or_else(|| {
+                InvalidRomanization {
+                    scheme: self,
+                    text: text.to_owned(),
+                    position,
+                }
+            })?;
+            // Case folding may change the byte length of the matched text.
+            let chars = self.render_phoneme(phoneme).chars().count();
+            position += rest
+                .char_indices()
+                .nth(chars)
+                .map_or(rest.len(), |(end, _)| end);
+            phonemes.push(phoneme);
+        }
+
+        Ok(phonemes)
+    }
+
+    /// Guesses the scheme of the text. Letters with diacritics mean the
+    /// standard scheme, X-SAMPA marks (`_ : \ ?`) mean X-SAMPA, and anything
+    /// else is read as ASCII, which agrees with the standard scheme on plain
+    /// letters. X-SAMPA without any mark, such as `saN`, is therefore read as
+    /// ASCII.
+    pub fn detect(text: &str) -> Self {
+        if !text.is_ascii() {
+            Scheme::Diacritic
+        } else if text.contains(['_', ':', '\\', '?']) {
+            Scheme::XSampa
+        } else {
+            Scheme::Ascii
+        }
+    }
+
+    /// Parses the text in whatever scheme it is written.
+    pub fn parse_detected<T>(text: &str) -> anyhow::Result<T>
+    where
+        T: Parse,
+    {
+        let phonemes = Self::detect(text).parse(text)?;
+        T::parse(&phonemes)
+    }
+}
+
+impl fmt::Display for Scheme {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.pad(self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::phonology::Word;
+
+    #[test]
+    fn schemes_round_trip() {
+        for text in &["saŋ", "dseńix", "ḿaŕí", "éyxám", "ḱen", "wiyabi"] {
+            let word = Word::parse_str(text).unwrap();
+            let phonemes = word.phonemes().collect::<Vec<_>>();
+            for &scheme in Scheme::ALL {
+                let rendered = scheme.render(word.phonemes());
+                let parsed = scheme.parse(&rendered).unwrap();
+                assert_eq!(parsed, phonemes, "{} {}", scheme, rendered);
+            }
+        }
+    }
+
+    #[test]
+    fn detects_scheme() {
+        assert_eq!(Scheme::detect("dseńix"), Scheme::Diacritic);
+        assert_eq!(Scheme::detect("dsenyix"), Scheme::Ascii);
+        assert_eq!(Scheme::detect("t_>senix"), Scheme::XSampa);
+        for text in &["dseńix", "dsenyix", "Dsenyix", "t_>seJix"] {
+            let word = Scheme::parse_detected::<Word>(text).unwrap();
+            assert_eq!(word.to_text(), "dseńix", "{}", text);
+        }
+    }
+
+    #[test]
+    fn separator_only_when_ambiguous() {
+        assert_eq!(Scheme::Ascii.transliterate("dseńix"), "dsenyix");
+        assert_eq!(Scheme::Ascii.transliterate("kwa"), "k'wa");
+        assert_eq!(Scheme::Ascii.transliterate("aáa"), "a'aaa");
+        assert_eq!(Scheme::XSampa.transliterate("kas"), "k_has");
+    }
+}
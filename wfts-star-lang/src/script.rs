@@ -0,0 +1,230 @@
+//! The Star Folk Alphabet. Each letter is encoded in a Private Use Area
+//! block, in alphabetic order, and can be drawn as SVG from the outlines of
+//! the font source, so star-script text does not depend on the webfont.
+
+use crate::phonology::Phoneme;
+use std::{collections::HashMap, fmt::Write, sync::OnceLock};
+use thiserror::Error;
+
+/// Codepoint of the first letter. The letters follow in the order of
+/// `Phoneme::ALL`.
+pub const PUA_START: u32 = 0xE100;
+
+/// Units per em of the font.
+pub const EM: u32 = 1024;
+
+const ASCENT: f64 = 819.0;
+
+const FONT_SOURCE: &str =
+    include_str!("../../wfts-pedia/assets/fonts/Star Folk Alphabet.sfd");
+
+#[derive(Debug, Clone, Error)]
+#[error("The Star Folk Alphabet has no glyph for {ch:?}")]
+pub struct MissingGlyph {
+    pub ch: char,
+}
+
+#[derive(Debug, Clone, Error)]
+#[error("Invalid font source at line {line}: {reason}")]
+pub struct InvalidFontSource {
+    pub line: usize,
+    pub reason: &'static str,
+}
+
+impl Phoneme {
+    /// Codepoint of the letter of this phoneme.
+    pub fn to_script(self) -> char {
+        let index = Phoneme::ALL.iter().position(|&other| other == self);
+        char::from_u32(PUA_START + index.unwrap() as u32).unwrap()
+    }
+
+    pub fn from_script(ch: char) -> Option<Self> {
+        let index = u32::from(ch).checked_sub(PUA_START)?;
+        Phoneme::ALL.get(index as usize).copied()
+    }
+}
+
+/// Converts text in the standard romanization to the star script. Other
+/// characters, such as hyphens and spaces, are kept as they are.
+pub fn to_script(text: &str) -> String {
+    text.chars()
+        .map(|ch| Phoneme::new(ch).map_or(ch, Phoneme::to_script))
+        .collect()
+}
+
+/// Converts star-script text back to the standard romanization.
+pub fn from_script(text: &str) -> String {
+    text.chars()
+        .map(|ch| match Phoneme::from_script(ch) {
+            Some(phoneme) => phoneme.to_text().to_owned(),
+            None => ch.to_string(),
+        })
+        .collect()
+}
+
+/// A glyph of the font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyph {
+    pub advance: u32,
+    /// SVG path data, with the baseline at the ascent and y growing
+    /// downwards.
+    pub path: String,
+}
+
+/// Glyphs of the Star Folk Alphabet, indexed by the character they are
+/// encoded as in the font source.
+#[derive(Debug, Clone, Default)]
+pub struct Font {
+    glyphs: HashMap<char, Glyph>,
+}
+
+impl Font {
+    /// The font shipped with the site assets.
+    pub fn get() -> &'static Self {
+        static FONT: OnceLock<Font> = OnceLock::new();
+
+        FONT.get_or_init(|| Self::parse(FONT_SOURCE).unwrap())
+    }
+
+    /// Reads the glyphs of a FontForge `.sfd` source. Only the foreground
+    /// splines are read.
+    pub fn parse(source: &str) -> anyhow::Result<Self> {
+        let mut font = Self::default();
+        let mut in_char = false;
+        let mut encoding = None;
+        let mut advance = 0;
+        let mut path = None::<String>;
+
+        for (i, line) in source.lines().enumerate() {
+            let invalid = |reason| InvalidFontSource { line: i + 1, reason };
+            if line.starts_with("StartChar: ") {
+                in_char = true;
+            } else if !in_char {
+                continue;
+            } else if let Some(rest) = line.strip_prefix("Encoding: ") {
+                let number = rest.split_whitespace().next().unwrap_or("");
+                let number = number
+                    .parse::<u32>()
+                    .map_err(|_| invalid("bad encoding"))?;
+                encoding = char::from_u32(number);
+            } else if let Some(rest) = line.strip_prefix("Width: ") {
+                advance = rest.parse().map_err(|_| invalid("bad width"))?;
+            } else if line == "SplineSet" {
+                path = Some(String::new());
+            } else if line == "EndSplineSet" {
+                let mut path = path.take().ok_or(invalid("unexpected end"))?;
+                if !path.is_empty() {
+                    path.push('Z');
+                }
+                if let Some(ch) = encoding {
+                    font.glyphs.insert(ch, Glyph { advance, path });
+                }
+            } else if line == "EndChar" {
+                in_char = false;
+                encoding = None;
+                advance = 0;
+            } else if let Some(path) = &mut path {
+                Self::parse_spline(line, path)
+                    .ok_or(invalid("bad spline"))?;
+            }
+        }
+
+        Ok(font)
+    }
+
+    /// Appends a spline command such as `10 20 m 1` or
+    /// `1 2 3 4 5 6 c 0` to the path.
+    fn parse_spline(line: &str, path: &mut String) -> Option<()> {
+        let tokens = line.split_whitespace().collect::<Vec<_>>();
+        let op = tokens.iter().position(|&token| {
+            matches!(token, "m" | "l" | "c")
+        })?;
+        let points = tokens[.. op]
+            .iter()
+            .map(|token| token.parse::<f64>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let (command, arity) = match tokens[op] {
+            "m" => ('M', 2),
+            "l" => ('L', 2),
+            _ => ('C', 6),
+        };
+        if points.len() != arity {
+            None?;
+        }
+        if command == 'M' && !path.is_empty() {
+            path.push_str("Z ");
+        }
+        path.push(command);
+        for pair in points.chunks(2) {
+            write!(path, " {} {}", pair[0], ASCENT - pair[1]).unwrap();
+        }
+        path.push(' ');
+        Some(())
+    }
+
+    /// Glyph of a character, either a star-script letter, a letter of the
+    /// standard romanization or another character of the font.
+    pub fn glyph(&self, ch: char) -> Option<&Glyph> {
+        let ch = match Phoneme::from_script(ch) {
+            Some(phoneme) => phoneme.to_text().chars().next()?,
+            None => ch,
+        };
+        self.glyphs.get(&ch)
+    }
+
+    /// Draws the text as an SVG image one em tall. The text may be written
+    /// either in the standard romanization or in the star script.
+    pub fn to_svg(&self, text: &str) -> anyhow::Result<String> {
+        let mut paths = String::new();
+        let mut x = 0;
+
+        for ch in text.chars() {
+            let glyph = self.glyph(ch).ok_or(MissingGlyph { ch })?;
+            if !glyph.path.is_empty() {
+                write!(
+                    paths,
+                    "<path transform=\"translate({} 0)\" d=\"{}\"/>",
+                    x,
+                    glyph.path.trim_end()
+                )?;
+            }
+            x += glyph.advance;
+        }
+
+        Ok(format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" \
+             fill=\"currentColor\">{}</svg>",
+            x, EM, paths
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn script_round_trips() {
+        let text = "dseńix-ŕá";
+        let script = to_script(text);
+        assert_eq!(script.chars().next(), Some('\u{E102}'));
+        assert_eq!(script.chars().nth(6), Some('-'));
+        assert_eq!(from_script(&script), text);
+    }
+
+    #[test]
+    fn every_letter_has_a_glyph() {
+        for &phoneme in Phoneme::ALL {
+            let glyph = Font::get().glyph(phoneme.to_script()).unwrap();
+            assert!(glyph.path.starts_with('M'), "{}", phoneme);
+        }
+    }
+
+    #[test]
+    fn svg_advances_by_glyph_width() {
+        let svg = Font::get().to_svg("ts").unwrap();
+        assert!(svg.contains("viewBox=\"0 0 1873 1024\""), "{}", svg);
+        assert!(svg.contains("translate(849 0)"));
+        assert!(Font::get().to_svg("q").is_err());
+    }
+}